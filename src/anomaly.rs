@@ -0,0 +1,92 @@
+//! # 분반 이상 감지
+//!
+//! 추출 과정의 중복이나 잘못된 병합을 찾기 위해 과목 코드별 분반 구성을 휴리스틱으로 검사한다.
+//!
+//! * 같은 과목 키(`subjtCd-diclNo`)가 두 번 이상 기록된 경우 (추출 중복)
+//! * 한 과목의 분반 수가 기준보다 많은 경우
+//! * 분반이 여러 개인데 모두 같은 교수로 기록된 경우
+use std::collections::{BTreeMap, HashSet};
+
+use serde_json::Value;
+
+use crate::output::subjects;
+use crate::subject_key;
+
+/// 분반 수 검사의 기본 기준
+pub const DEFAULT_MAX_SECTIONS: usize = 20;
+/// 모든 분반이 같은 교수인지 검사하기 위한 최소 분반 수
+pub const SAME_PROFESSOR_MIN_SECTIONS: usize = 4;
+
+/// 과목 코드별 분반 구성을 검사하여 이상이 의심되는 항목의 경고 메세지 목록을 만드는 메서드
+///
+/// ## Arguments
+///
+/// * `db` - 검사할 DB의 내용
+/// * `max_sections` - 과목 하나에 허용되는 분반 수
+///
+/// ## Examples
+/// ```
+/// use serde_json::json;
+/// use suwon_mate_admin_tool::anomaly::section_anomalies;
+/// let db = json!({"estbLectDtaiList": {"경영학부": [
+///     {"subjtCd": "1", "diclNo": "001", "subjtNm": "회계원리", "ltrPrfsNm": "홍길동"},
+///     {"subjtCd": "1", "diclNo": "001", "subjtNm": "회계원리", "ltrPrfsNm": "홍길동"},
+///     {"subjtCd": "2", "diclNo": "001", "ltrPrfsNm": "김철수"},
+///     {"subjtCd": "2", "diclNo": "002", "ltrPrfsNm": "김철수"},
+///     {"subjtCd": "2", "diclNo": "003", "ltrPrfsNm": "김철수"},
+///     {"subjtCd": "2", "diclNo": "004", "ltrPrfsNm": "김철수"}
+/// ]}});
+/// let anomalies = section_anomalies(&db, 3);
+/// assert_eq!(anomalies.len(), 3);
+/// assert!(anomalies[0].contains("1-001"));
+/// assert!(section_anomalies(&db, 20).len() == 2);
+/// ```
+pub fn section_anomalies(db: &Value, max_sections: usize) -> Vec<String> {
+    let mut anomalies = vec![];
+    let mut seen = HashSet::new();
+    let mut courses: BTreeMap<&str, Vec<&Value>> = BTreeMap::new();
+    for subject in subjects(db) {
+        let key = subject_key(subject);
+        if !seen.insert(key.clone()) {
+            anomalies.push(format!(
+                "주의: 같은 과목 키가 두 번 이상 기록되었습니다. 추출 중복이 의심됩니다. ({})",
+                key
+            ));
+        }
+        if let Some(code) = subject["subjtCd"].as_str() {
+            courses.entry(code).or_default().push(subject);
+        }
+    }
+    for (code, sections) in courses {
+        let numbers: HashSet<&str> = sections
+            .iter()
+            .filter_map(|section| section["diclNo"].as_str())
+            .collect();
+        let name = sections[0]["subjtNm"].as_str().unwrap_or_default();
+        if numbers.len() > max_sections {
+            anomalies.push(format!(
+                "주의: 분반 수가 {}개로 기준({}개)보다 많습니다. ({} {})",
+                numbers.len(),
+                max_sections,
+                code,
+                name
+            ));
+        }
+        let professors: HashSet<Option<&str>> = sections
+            .iter()
+            .map(|section| section["ltrPrfsNm"].as_str())
+            .collect();
+        if numbers.len() >= SAME_PROFESSOR_MIN_SECTIONS && professors.len() == 1 {
+            if let Some(Some(professor)) = professors.into_iter().next() {
+                anomalies.push(format!(
+                    "주의: {}개 분반이 모두 같은 교수({})로 기록되었습니다. ({} {})",
+                    numbers.len(),
+                    professor,
+                    code,
+                    name
+                ));
+            }
+        }
+    }
+    anomalies
+}
@@ -0,0 +1,212 @@
+//! # 개인정보 제거
+//!
+//! 이미 만들어진 DB에서 교수 연락처(이메일 주소, 휴대전화 번호)를 제거하거나 마스킹하여
+//! 외부에 공유할 수 있는 공개용 사본을 만드는 기능을 제공한다.
+//!
+//! 외부 개발자에게 공유할 입력 파일 샘플을 위해, 입력 파일의 교수명과 연락처를 가짜 값으로 바꾸는 기능도 함께 제공한다.
+use std::collections::HashMap;
+
+use clap::ValueEnum;
+use serde_json::Value;
+
+/// 개인정보를 처리하는 방식
+#[derive(Copy, Clone, PartialEq, Eq, Debug, ValueEnum)]
+pub enum AnonymizeMode {
+    /// 일부 글자만 `*`로 가린다.
+    Mask,
+    /// 값을 `null`로 바꾼다.
+    Remove,
+}
+
+/// 이메일 주소를 마스킹하는 메서드
+///
+/// 아이디의 첫 글자와 도메인만 남기고 나머지는 `*`로 가린다.
+///
+/// ## Arguments
+///
+/// * `email` - 스트링 슬라이스 형태의 이메일 주소
+///
+/// ## Examples
+/// ```
+/// use suwon_mate_admin_tool::anonymize::mask_email;
+/// assert_eq!(mask_email("test@suwon.ac.kr"), "t***@suwon.ac.kr");
+/// assert_eq!(mask_email("invalid"), "*******");
+/// ```
+pub fn mask_email(email: &str) -> String {
+    match email.split_once('@') {
+        Some((id, domain)) => {
+            let mut chars = id.chars();
+            let first = chars.next().map(String::from).unwrap_or_default();
+            format!("{}{}@{}", first, "*".repeat(chars.count()), domain)
+        }
+        None => "*".repeat(email.chars().count()),
+    }
+}
+
+/// 휴대전화 번호를 마스킹하는 메서드
+///
+/// 앞 3자리와 뒤 4자리 숫자만 남기고 나머지 숫자는 `*`로 가린다. 숫자가 아닌 문자(`-` 등)는 그대로 유지된다.
+///
+/// ## Arguments
+///
+/// * `phone` - 스트링 슬라이스 형태의 휴대전화 번호
+///
+/// ## Examples
+/// ```
+/// use suwon_mate_admin_tool::anonymize::mask_phone;
+/// assert_eq!(mask_phone("010-1234-5678"), "010-****-5678");
+/// assert_eq!(mask_phone("01012345678"), "010****5678");
+/// ```
+pub fn mask_phone(phone: &str) -> String {
+    let digit_count = phone.chars().filter(char::is_ascii_digit).count();
+    let mut digit_index = 0;
+    phone
+        .chars()
+        .map(|character| {
+            if !character.is_ascii_digit() {
+                return character;
+            }
+            digit_index += 1;
+            if digit_index <= 3 || digit_index + 4 > digit_count {
+                character
+            } else {
+                '*'
+            }
+        })
+        .collect()
+}
+
+/// DB의 연락처 정보에서 개인정보를 제거하는 메서드
+///
+/// `db`의 `contacts` 항목에 포함된 모든 이메일 주소(`email`)와 휴대전화 번호(`mpno`)를 `mode`에 따라 처리한다.
+/// 값이 `null`인 항목은 그대로 유지된다.
+///
+/// ## Arguments
+///
+/// * `db` - 이미 만들어진 DB의 내용
+/// * `mode` - 개인정보 처리 방식
+///
+/// ## Examples
+/// ```
+/// use serde_json::json;
+/// use suwon_mate_admin_tool::anonymize::{anonymize_db, AnonymizeMode};
+/// let mut db = json!({"contacts": {"경영학부": {"홍길동": {"email": "test@suwon.ac.kr", "mpno": "010-1234-5678"}}}});
+/// anonymize_db(&mut db, AnonymizeMode::Mask);
+/// assert_eq!(db["contacts"]["경영학부"]["홍길동"]["mpno"], "010-****-5678");
+/// anonymize_db(&mut db, AnonymizeMode::Remove);
+/// assert!(db["contacts"]["경영학부"]["홍길동"]["email"].is_null());
+/// ```
+pub fn anonymize_db(db: &mut Value, mode: AnonymizeMode) {
+    let Some(departments) = db["contacts"].as_object_mut() else {
+        return;
+    };
+    for professors in departments.values_mut() {
+        let Some(professors) = professors.as_object_mut() else {
+            continue;
+        };
+        for contact in professors.values_mut() {
+            for (key, mask) in [
+                ("email", mask_email as fn(&str) -> String),
+                ("mpno", mask_phone),
+            ] {
+                let masked = match (mode, contact[key].as_str()) {
+                    (_, None) => continue,
+                    (AnonymizeMode::Mask, Some(value)) => Value::String(mask(value)),
+                    (AnonymizeMode::Remove, Some(_)) => Value::Null,
+                };
+                contact[key] = masked;
+            }
+        }
+    }
+}
+
+/// 입력 파일에서 가짜 값으로 바꿀 필드 목록
+pub const INPUT_PERSONAL_FIELDS: [&str; 3] = ["ltrPrfsNm", "email", "mpno"];
+
+/// 입력 파일의 개인정보를 가짜 값으로 바꾸는 구조체
+///
+/// 같은 원래 값은 항상 같은 가짜 값으로 바뀌므로, 여러 입력 파일을 같은 구조체로 처리하면 파일 사이의 교수명 대응 관계가 유지된다.
+/// 과목 코드나 분반 등 개인정보가 아닌 값은 바꾸지 않으므로 입력 파일의 구조와 과목 매칭 관계도 그대로 유지된다.
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct InputAnonymizer {
+    /// 필드별로 원래 값과 가짜 값을 대응시킨 표
+    replacements: HashMap<&'static str, HashMap<String, String>>,
+}
+
+impl InputAnonymizer {
+    /// `field`의 원래 값 `value`에 대응하는 가짜 값을 가져오는 메서드
+    ///
+    /// 처음 보는 값이면 필드별 일련번호로 새 가짜 값을 만든다.
+    ///
+    /// ## Examples
+    /// ```
+    /// use suwon_mate_admin_tool::anonymize::InputAnonymizer;
+    /// let mut anonymizer = InputAnonymizer::default();
+    /// assert_eq!(anonymizer.fake_value("ltrPrfsNm", "홍길동"), "교수001");
+    /// assert_eq!(anonymizer.fake_value("ltrPrfsNm", "김철수"), "교수002");
+    /// assert_eq!(anonymizer.fake_value("ltrPrfsNm", "홍길동"), "교수001");
+    /// assert_eq!(anonymizer.fake_value("email", "test@suwon.ac.kr"), "user001@example.com");
+    /// assert_eq!(anonymizer.fake_value("mpno", "010-1234-5678"), "010-0000-0001");
+    /// ```
+    pub fn fake_value(&mut self, field: &'static str, value: &str) -> String {
+        let replacements = self.replacements.entry(field).or_default();
+        let number = replacements.len() + 1;
+        replacements
+            .entry(value.to_string())
+            .or_insert_with(|| match field {
+                "email" => format!("user{:03}@example.com", number),
+                "mpno" => format!("010-0000-{:04}", number),
+                "ltrPrfsNm" => format!("교수{:03}", number),
+                _ => format!("{}{:03}", field, number),
+            })
+            .clone()
+    }
+
+    /// 입력 파일의 내용에 포함된 [INPUT_PERSONAL_FIELDS]의 문자열 값을 모두 가짜 값으로 바꾸는 메서드
+    ///
+    /// 바꾼 값의 수를 반환한다. 값이 `null`이거나 빈 문자열인 경우 그대로 유지된다.
+    ///
+    /// ## Examples
+    /// ```
+    /// use serde_json::json;
+    /// use suwon_mate_admin_tool::anonymize::InputAnonymizer;
+    /// let mut anonymizer = InputAnonymizer::default();
+    /// let mut open_class = json!({"estbLectDtaiList": [
+    ///     {"subjtCd": "1", "diclNo": "001", "ltrPrfsNm": "홍길동"},
+    ///     {"subjtCd": "2", "diclNo": "001", "ltrPrfsNm": "김철수"}
+    /// ]});
+    /// let mut class_todo = json!({"estbLectDtaiList": [
+    ///     {"subjtCd": "2", "diclNo": "001", "ltrPrfsNm": "김철수", "email": "kim@suwon.ac.kr", "mpno": null}
+    /// ]});
+    /// assert_eq!(anonymizer.anonymize_input(&mut open_class), 2);
+    /// assert_eq!(anonymizer.anonymize_input(&mut class_todo), 2);
+    /// assert_eq!(class_todo["estbLectDtaiList"][0]["ltrPrfsNm"], open_class["estbLectDtaiList"][1]["ltrPrfsNm"]);
+    /// assert_eq!(class_todo["estbLectDtaiList"][0]["subjtCd"], "2");
+    /// assert!(class_todo["estbLectDtaiList"][0]["mpno"].is_null());
+    /// ```
+    pub fn anonymize_input(&mut self, input: &mut Value) -> usize {
+        match input {
+            Value::Array(values) => values
+                .iter_mut()
+                .map(|value| self.anonymize_input(value))
+                .sum(),
+            Value::Object(values) => {
+                let mut count = 0;
+                for (key, value) in values.iter_mut() {
+                    let field = INPUT_PERSONAL_FIELDS
+                        .into_iter()
+                        .find(|field| *field == key.as_str());
+                    match (field, value.as_str()) {
+                        (Some(field), Some(text)) if !text.is_empty() => {
+                            *value = Value::String(self.fake_value(field, text));
+                            count += 1;
+                        }
+                        _ => count += self.anonymize_input(value),
+                    }
+                }
+                count
+            }
+            _ => 0,
+        }
+    }
+}
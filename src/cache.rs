@@ -0,0 +1,198 @@
+//! # 수집 데이터 캐시
+//!
+//! `fetch` 명령이나 URL 입력으로 받은 원본을 캐시 디렉토리(`~/.cache/suwon_mate_admin_tool/`)에 학기와 날짜별로 저장한다.
+//! 네트워크를 사용할 수 없는 경우 `--offline` 옵션으로 캐시에 저장된 가장 최근의 원본만으로 빌드할 수 있다.
+//!
+//! 원본은 `<캐시 디렉토리>/<학기>/<YYYYMMDD>/<원본 이름>.json`에 저장된다. 원본 이름은 URL과 요청 인자의 해시로 정하므로
+//! 같은 요청으로 받은 원본은 항상 같은 이름을 가진다. 학기를 알 수 없는 원본은 [UNKNOWN_SEMESTER] 디렉토리에 저장된다.
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+
+use crate::signature::to_hex;
+use crate::template::{semester, today};
+
+/// 캐시 디렉토리 안에서 사용하는 디렉토리 이름
+pub const CACHE_DIR_NAME: &str = "suwon_mate_admin_tool";
+/// 학기를 알 수 없는 원본을 저장하는 디렉토리 이름
+pub const UNKNOWN_SEMESTER: &str = "unknown";
+
+/// 기본 캐시 디렉토리를 구하는 메서드
+///
+/// `XDG_CACHE_HOME`이 지정되어 있으면 그 아래를, 그렇지 않으면 홈 디렉토리의 `.cache` 아래를 사용한다.
+///
+/// ## Errors
+/// 홈 디렉토리를 찾을 수 없는 경우 오류가 발생한다.
+pub fn default_cache_dir() -> Result<PathBuf, Box<dyn Error>> {
+    let base = match std::env::var_os("XDG_CACHE_HOME") {
+        Some(directory) if !directory.is_empty() => PathBuf::from(directory),
+        _ => std::env::var_os("HOME")
+            .or_else(|| std::env::var_os("USERPROFILE"))
+            .map(|home| PathBuf::from(home).join(".cache"))
+            .ok_or("홈 디렉토리를 찾을 수 없어 캐시 디렉토리를 정할 수 없습니다.")?,
+    };
+    Ok(base.join(CACHE_DIR_NAME))
+}
+
+/// 원본의 출처(URL과 요청 인자)로부터 캐시에 저장할 파일 이름을 만드는 메서드
+///
+/// ## Examples
+/// ```
+/// use suwon_mate_admin_tool::cache::cache_file_name;
+/// let name = cache_file_name("https://example.com/api?year=2024");
+/// assert_eq!(name.len(), "0123456789abcdef.json".len());
+/// assert_eq!(name, cache_file_name("https://example.com/api?year=2024"));
+/// assert_ne!(name, cache_file_name("https://example.com/api?year=2023"));
+/// ```
+pub fn cache_file_name(source: &str) -> String {
+    format!("{}.json", &to_hex(&Sha256::digest(source.as_bytes()))[..16])
+}
+
+/// 수집 데이터 캐시
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Cache {
+    /// 캐시 디렉토리
+    pub directory: PathBuf,
+}
+
+impl Cache {
+    /// `directory`를 사용하는 [Cache]를 생성한다.
+    pub fn new(directory: &Path) -> Self {
+        Self {
+            directory: directory.to_path_buf(),
+        }
+    }
+
+    /// 원본을 `semester` 학기의 오늘 날짜 디렉토리에 저장하는 메서드
+    ///
+    /// `semester`가 없으면 원본의 내용으로부터 학기를 구하며, 구할 수 없으면 [UNKNOWN_SEMESTER]를 사용한다.
+    /// 저장한 파일의 경로를 반환한다.
+    ///
+    /// ## Errors
+    /// 디렉토리를 만들거나 파일을 기록할 수 없는 경우 오류가 발생한다.
+    pub fn store(
+        &self,
+        source: &str,
+        semester: Option<&str>,
+        content: &[u8],
+    ) -> Result<PathBuf, Box<dyn Error>> {
+        self.store_at(source, semester, &today(), content)
+    }
+
+    /// 원본을 `semester` 학기의 `date` 날짜 디렉토리에 저장하는 메서드
+    ///
+    /// ## Examples
+    /// ```
+    /// use suwon_mate_admin_tool::cache::Cache;
+    /// let directory = std::env::temp_dir().join("suwon_mate_cache_doctest");
+    /// let _ = std::fs::remove_dir_all(&directory);
+    /// let cache = Cache::new(&directory);
+    /// let content = r#"{"estbLectDtaiList": [{"subjtEstbYear": "2024", "subjtEstbSmrNm": "1학기"}]}"#.as_bytes();
+    /// let path = cache.store_at("https://example.com/api", None, "20240301", content).unwrap();
+    /// assert!(path.starts_with(directory.join("2024-1학기").join("20240301")));
+    /// cache.store_at("https://example.com/api", None, "20240215", b"{}").unwrap();
+    /// assert_eq!(cache.latest("https://example.com/api", None), Some(path.clone()));
+    /// assert_eq!(cache.latest("https://example.com/api", Some("2024-1학기")), Some(path));
+    /// assert_eq!(cache.latest("https://example.com/api", Some("2023-2학기")), None);
+    /// assert_eq!(cache.latest("https://example.com/other", None), None);
+    /// ```
+    ///
+    /// ## Errors
+    /// 디렉토리를 만들거나 파일을 기록할 수 없는 경우 오류가 발생한다.
+    pub fn store_at(
+        &self,
+        source: &str,
+        semester: Option<&str>,
+        date: &str,
+        content: &[u8],
+    ) -> Result<PathBuf, Box<dyn Error>> {
+        let semester = match semester {
+            Some(semester) => semester.to_string(),
+            None => serde_json::from_slice::<Value>(content)
+                .ok()
+                .and_then(|content| self::semester(&content))
+                .unwrap_or_else(|| UNKNOWN_SEMESTER.to_string()),
+        };
+        let directory = self.directory.join(semester).join(date);
+        fs::create_dir_all(&directory)?;
+        let path = directory.join(cache_file_name(source));
+        fs::write(&path, content)?;
+        Ok(path)
+    }
+
+    /// 캐시에 저장된 원본 중 가장 최근 날짜의 원본을 찾는 메서드
+    ///
+    /// `semester`가 없으면 모든 학기에서 찾는다. 날짜가 같으면 학기 이름이 뒤인 원본을 사용한다.
+    pub fn latest(&self, source: &str, semester: Option<&str>) -> Option<PathBuf> {
+        let file_name = cache_file_name(source);
+        let semesters: Vec<PathBuf> = match semester {
+            Some(semester) => vec![self.directory.join(semester)],
+            None => fs::read_dir(&self.directory)
+                .ok()?
+                .flatten()
+                .map(|entry| entry.path())
+                .collect(),
+        };
+        semesters
+            .iter()
+            .filter_map(|semester| fs::read_dir(semester).ok())
+            .flat_map(|dates| dates.flatten().map(|entry| entry.path()))
+            .map(|date| date.join(&file_name))
+            .filter(|path| path.is_file())
+            .max_by(|left, right| {
+                let date = |path: &PathBuf| {
+                    path.parent()
+                        .and_then(Path::file_name)
+                        .map(ToOwned::to_owned)
+                };
+                date(left).cmp(&date(right)).then_with(|| left.cmp(right))
+            })
+    }
+
+    /// 캐시를 거쳐 원본을 가져오는 메서드
+    ///
+    /// `offline`이면 `fetch`를 호출하지 않고 캐시에 저장된 가장 최근의 원본을 읽는다.
+    /// 그렇지 않으면 `fetch`로 원본을 가져와 캐시에 저장한 뒤 반환한다. 캐시에 저장하지 못하더라도 원본은 반환한다.
+    ///
+    /// ## Examples
+    /// ```
+    /// use suwon_mate_admin_tool::cache::Cache;
+    /// let directory = std::env::temp_dir().join("suwon_mate_cache_fetch_doctest");
+    /// let _ = std::fs::remove_dir_all(&directory);
+    /// let cache = Cache::new(&directory);
+    /// assert!(cache.fetch("https://example.com/api", None, true, || Ok(b"{}".to_vec())).is_err());
+    /// assert_eq!(cache.fetch("https://example.com/api", None, false, || Ok(b"{}".to_vec())).unwrap(), b"{}");
+    /// assert_eq!(cache.fetch("https://example.com/api", None, true, || Err("네트워크 오류".into())).unwrap(), b"{}");
+    /// ```
+    ///
+    /// ## Errors
+    /// * `offline`인데 캐시에 원본이 없는 경우
+    /// * `fetch`에서 오류가 발생한 경우
+    pub fn fetch<F>(
+        &self,
+        source: &str,
+        semester: Option<&str>,
+        offline: bool,
+        fetch: F,
+    ) -> Result<Vec<u8>, Box<dyn Error>>
+    where
+        F: FnOnce() -> Result<Vec<u8>, Box<dyn Error>>,
+    {
+        if offline {
+            let path = self.latest(source, semester).ok_or_else(|| {
+                format!("오프라인 모드이지만 캐시에 {}의 원본이 없습니다.", source)
+            })?;
+            notice!("캐시에 저장된 {}의 원본을 사용합니다.", path.display());
+            return Ok(fs::read(path)?);
+        }
+        let content = fetch()?;
+        match self.store(source, semester, &content) {
+            Ok(path) => notice!("원본을 {}에 캐시하였습니다.", path.display()),
+            Err(error) => notice!("주의: 원본을 캐시에 저장하지 못했습니다. ({})", error),
+        }
+        Ok(content)
+    }
+}
@@ -0,0 +1,96 @@
+//! # 학사일정
+//!
+//! 별도로 관리하는 학사일정 JSON 파일을 검사하여 DB의 `academic_calendar` 섹션으로 기록한다.
+//!
+//! 학사일정 파일은 아래와 같은 일정의 배열이다. `title`과 `start`는 필수이며, `end`와 `holiday`는 생략할 수 있다.
+//!
+//! ```json
+//! [{"title": "개교기념일", "start": "2024-05-01", "holiday": true},
+//!  {"title": "중간고사", "start": "2024-04-22", "end": "2024-04-26"}]
+//! ```
+use std::error::Error;
+
+use serde_json::{json, Value};
+
+/// `YYYY-MM-DD` 형태의 날짜인지 확인하는 메서드
+///
+/// ## Examples
+/// ```
+/// use suwon_mate_admin_tool::calendar::is_date;
+/// assert!(is_date("2024-05-01"));
+/// assert!(!is_date("2024-13-01"));
+/// assert!(!is_date("2024-5-1"));
+/// ```
+pub fn is_date(text: &str) -> bool {
+    let parts: Vec<&str> = text.split('-').collect();
+    let [year, month, day] = parts.as_slice() else {
+        return false;
+    };
+    let number = |part: &str, length: usize| {
+        (part.len() == length && part.bytes().all(|byte| byte.is_ascii_digit()))
+            .then(|| part.parse::<u32>().ok())
+            .flatten()
+    };
+    matches!(
+        (number(year, 4), number(month, 2), number(day, 2)),
+        (Some(_), Some(1..=12), Some(1..=31))
+    )
+}
+
+/// 학사일정 파일의 내용을 검사하여 DB에 기록할 일정 목록을 만드는 메서드
+///
+/// 일정은 시작일 순으로 정렬되며, `end`가 없는 일정은 시작일과 같은 날 끝나는 것으로 기록된다.
+/// `holiday`가 없는 일정은 공휴일이 아닌 것으로 기록된다.
+///
+/// ## Examples
+/// ```
+/// use serde_json::json;
+/// use suwon_mate_admin_tool::calendar::academic_calendar;
+/// let calendar = academic_calendar(&json!([
+///     {"title": "개교기념일", "start": "2024-05-01", "holiday": true},
+///     {"title": "중간고사", "start": "2024-04-22", "end": "2024-04-26"}
+/// ])).unwrap();
+/// assert_eq!(calendar[0]["title"], "중간고사");
+/// assert_eq!(calendar[1]["end"], "2024-05-01");
+/// assert!(academic_calendar(&json!([{"title": "종강"}])).is_err());
+/// ```
+///
+/// ## Errors
+/// * 내용이 배열이 아닌 경우
+/// * 일정에 `title` 또는 `start`가 없거나 날짜가 `YYYY-MM-DD` 형태가 아닌 경우
+/// * `end`가 `start`보다 앞서는 경우
+pub fn academic_calendar(content: &Value) -> Result<Value, Box<dyn Error>> {
+    let events = content
+        .as_array()
+        .ok_or("학사일정 파일은 일정의 배열이어야 합니다.")?;
+    let mut calendar = vec![];
+    for (index, event) in events.iter().enumerate() {
+        let invalid =
+            |reason: &str| format!("학사일정 {}번째 일정 {}: {}", index + 1, reason, event);
+        let title = event["title"]
+            .as_str()
+            .ok_or_else(|| invalid("제목(title)이 없습니다"))?;
+        let start = event["start"]
+            .as_str()
+            .filter(|start| is_date(start))
+            .ok_or_else(|| invalid("시작일(start)이 YYYY-MM-DD 형태가 아닙니다"))?;
+        let end = match &event["end"] {
+            Value::Null => start,
+            end => end
+                .as_str()
+                .filter(|end| is_date(end))
+                .ok_or_else(|| invalid("종료일(end)이 YYYY-MM-DD 형태가 아닙니다"))?,
+        };
+        if end < start {
+            return Err(invalid("종료일(end)이 시작일(start)보다 앞섭니다").into());
+        }
+        calendar.push(json!({
+            "title": title,
+            "start": start,
+            "end": end,
+            "holiday": event["holiday"].as_bool().unwrap_or(false),
+        }));
+    }
+    calendar.sort_by(|left, right| left["start"].as_str().cmp(&right["start"].as_str()));
+    Ok(Value::Array(calendar))
+}
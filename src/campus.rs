@@ -0,0 +1,126 @@
+//! # 복수 캠퍼스 입력
+//!
+//! 캠퍼스별로 받은 개설 강좌 조회 DB 파일의 과목에 `campus` 필드를 붙여 하나의 입력으로 합치고,
+//! 빌드된 DB에서 캠퍼스별 학부·학과 목록을 만든다.
+//!
+//! 통합 학부·학과 목록(`departments`)은 그대로 유지하므로, 캠퍼스를 구분하지 않는 앱도 같은 DB를 사용할 수 있다.
+use std::collections::{BTreeMap, BTreeSet};
+use std::error::Error;
+use std::path::PathBuf;
+
+use serde_json::{json, Value};
+
+use crate::input::{merge_inputs, read_inputs, InputOptions, SUBJECT_LIST_KEY};
+
+/// 과목 목록의 모든 과목에 `campus` 필드를 기록하는 메서드
+///
+/// 기록한 과목의 수를 반환한다.
+///
+/// ## Examples
+/// ```
+/// use serde_json::json;
+/// use suwon_mate_admin_tool::campus::tag_campus;
+/// let mut input = json!({"estbLectDtaiList": [{"subjtCd": "1"}, {"subjtCd": "2"}]});
+/// assert_eq!(tag_campus(&mut input, "와우"), 2);
+/// assert_eq!(input["estbLectDtaiList"][1]["campus"], "와우");
+/// ```
+pub fn tag_campus(input: &mut Value, campus: &str) -> usize {
+    let Some(subjects) = input[SUBJECT_LIST_KEY].as_array_mut() else {
+        return 0;
+    };
+    let mut count = 0;
+    for subject in subjects.iter_mut().filter_map(Value::as_object_mut) {
+        subject.insert(String::from("campus"), json!(campus));
+        count += 1;
+    }
+    count
+}
+
+/// 캠퍼스별 개설 강좌 조회 DB 파일을 읽어 `campus` 필드를 붙인 뒤 하나의 내용으로 합치는 메서드
+///
+/// `patterns`와 `campuses`는 같은 순서로 짝지어지며, 각 패턴은 [read_inputs]로 읽는다.
+/// 캠퍼스가 둘 이상이면 [merge_inputs]로 합치므로 과목 키(`subjtCd-diclNo`)가 같은 과목은 먼저 지정한 캠퍼스의 과목만 남는다.
+///
+/// ## Errors
+/// * `patterns`와 `campuses`의 개수가 다른 경우
+/// * 입력 파일을 읽거나 JSON해독이 불가능한 경우
+pub fn read_campus_inputs(
+    patterns: &[PathBuf],
+    campuses: &[String],
+    options: &InputOptions,
+) -> Result<Value, Box<dyn Error>> {
+    if patterns.len() != campuses.len() {
+        return Err(format!(
+            "캠퍼스({}개)는 개설 강좌 조회 DB 파일({}개)마다 하나씩 지정해야 합니다.",
+            campuses.len(),
+            patterns.len()
+        )
+        .into());
+    }
+    let mut contents = vec![];
+    for (pattern, campus) in patterns.iter().zip(campuses) {
+        let mut input = read_inputs(std::slice::from_ref(pattern), options)?;
+        let count = tag_campus(&mut input, campus);
+        notice!("{} 캠퍼스의 과목 {}개를 읽었습니다.", campus, count);
+        contents.push(input);
+    }
+    if contents.len() == 1 {
+        return Ok(contents.remove(0));
+    }
+    let (merged, duplicates) = merge_inputs(contents)?;
+    if duplicates > 0 {
+        notice!(
+            "주의: 여러 캠퍼스에 같은 과목 키의 과목 {}개가 있어 먼저 지정한 캠퍼스의 과목만 남겼습니다.",
+            duplicates
+        );
+    }
+    Ok(merged)
+}
+
+/// DB의 과목 정보로부터 캠퍼스별 학부·학과 목록을 만드는 메서드
+///
+/// `campus` 필드가 없는 과목은 제외하며, 학과가 없는 과목의 학부는 학과 없이 기록된다.
+/// 빠른 모드로 제작된 DB는 `estbLectDtaiList_quick` 항목을 사용한다.
+///
+/// ## Examples
+/// ```
+/// use serde_json::json;
+/// use suwon_mate_admin_tool::campus::departments_by_campus;
+/// let db = json!({"estbLectDtaiList": {
+///     "경영학부": [
+///         {"campus": "본교", "estbDpmjNm": "경영학부", "estbMjorNm": "경영학과"},
+///         {"campus": "와우", "estbDpmjNm": "경영학부", "estbMjorNm": null},
+///     ],
+///     "공과대학": [{"estbDpmjNm": "공과대학", "estbMjorNm": "건축공학"}],
+/// }});
+/// assert_eq!(
+///     departments_by_campus(&db),
+///     json!({"본교": {"경영학부": ["경영학과"]}, "와우": {"경영학부": []}})
+/// );
+/// ```
+pub fn departments_by_campus(db: &Value) -> Value {
+    let subjects = db[SUBJECT_LIST_KEY]
+        .as_object()
+        .or_else(|| db["estbLectDtaiList_quick"].as_object());
+    let mut campuses: BTreeMap<&str, BTreeMap<&str, BTreeSet<&str>>> = BTreeMap::new();
+    for subject in subjects
+        .into_iter()
+        .flatten()
+        .flat_map(|(_, subjects)| subjects.as_array().into_iter().flatten())
+    {
+        let (Some(campus), Some(department)) =
+            (subject["campus"].as_str(), subject["estbDpmjNm"].as_str())
+        else {
+            continue;
+        };
+        let majors = campuses
+            .entry(campus)
+            .or_default()
+            .entry(department)
+            .or_default();
+        if let Some(major) = subject["estbMjorNm"].as_str() {
+            majors.insert(major);
+        }
+    }
+    json!(campuses)
+}
@@ -0,0 +1,107 @@
+//! # canonical JSON
+//!
+//! 서명과 해시 비교에 사용할 수 있도록 [RFC 8785](https://www.rfc-editor.org/rfc/rfc8785)(JSON Canonicalization Scheme)에 따라
+//! 키 순서와 숫자 표현이 항상 같은 JSON을 만든다.
+//!
+//! * 객체의 키는 UTF-16 코드 단위 순서로 정렬한다.
+//! * 숫자는 ECMAScript의 `Number.prototype.toString`과 같이 표현한다. (`3.0` → `3`, `1e21` → `1e+21`)
+//! * 문자열은 `"`, `\`, 제어 문자만 이스케이프한다.
+//! * 공백은 넣지 않는다.
+use std::cmp::Ordering;
+
+use serde_json::{Number, Value};
+
+/// 두 문자열을 UTF-16 코드 단위 순서로 비교하는 메서드
+fn compare_utf16(left: &str, right: &str) -> Ordering {
+    left.encode_utf16().cmp(right.encode_utf16())
+}
+
+/// 숫자를 ECMAScript의 `Number.prototype.toString`과 같은 형태로 바꾸는 메서드
+///
+/// 모든 숫자는 IEEE 754 배정밀도 부동소수점 수로 해석되므로, 2^53보다 큰 정수는 가장 가까운 배정밀도 값으로 표현된다.
+///
+/// ## Examples
+/// ```
+/// use serde_json::{json, Value};
+/// use suwon_mate_admin_tool::canonical::canonical_number;
+/// let number = |value: Value| match value {
+///     Value::Number(number) => canonical_number(&number),
+///     _ => unreachable!(),
+/// };
+/// assert_eq!(number(json!(3.0)), "3");
+/// assert_eq!(number(json!(-0.0)), "0");
+/// assert_eq!(number(json!(4.5)), "4.5");
+/// assert_eq!(number(json!(0.000001)), "0.000001");
+/// assert_eq!(number(json!(1e-7)), "1e-7");
+/// assert_eq!(number(json!(1e21)), "1e+21");
+/// assert_eq!(number(json!(123456789012345680000.0)), "123456789012345680000");
+/// assert_eq!(number(json!(9007199254740993u64)), "9007199254740992");
+/// ```
+pub fn canonical_number(number: &Number) -> String {
+    let value = number.as_f64().unwrap_or_default();
+    if value == 0.0 {
+        return String::from("0");
+    }
+    let magnitude = value.abs();
+    if (1e-6..1e21).contains(&magnitude) {
+        return format!("{}", value);
+    }
+    let exponential = format!("{:e}", value);
+    match exponential.split_once('e') {
+        Some((mantissa, exponent)) if !exponent.starts_with('-') => {
+            format!("{}e+{}", mantissa, exponent)
+        }
+        _ => exponential,
+    }
+}
+
+/// 값을 canonical JSON 문자열로 바꾸는 메서드
+///
+/// ## Examples
+/// ```
+/// use serde_json::json;
+/// use suwon_mate_admin_tool::canonical::canonical_json;
+/// let value = json!({"b": [1.0, "\u{1f}\n"], "a": {"\u{e000}": 1, "\u{1f600}": 2}, "c": null});
+/// assert_eq!(
+///     canonical_json(&value),
+///     "{\"a\":{\"\u{1f600}\":2,\"\u{e000}\":1},\"b\":[1,\"\\u001f\\n\"],\"c\":null}"
+/// );
+/// ```
+pub fn canonical_json(value: &Value) -> String {
+    let mut result = String::new();
+    write_canonical(value, &mut result);
+    result
+}
+
+/// 값을 canonical JSON 형태로 `result` 뒤에 붙이는 메서드
+fn write_canonical(value: &Value, result: &mut String) {
+    match value {
+        Value::Number(number) => result.push_str(&canonical_number(number)),
+        Value::Array(values) => {
+            result.push('[');
+            for (index, value) in values.iter().enumerate() {
+                if index > 0 {
+                    result.push(',');
+                }
+                write_canonical(value, result);
+            }
+            result.push(']');
+        }
+        Value::Object(map) => {
+            let mut entries: Vec<(&String, &Value)> = map.iter().collect();
+            entries.sort_by(|(left, _), (right, _)| compare_utf16(left, right));
+            result.push('{');
+            for (index, (key, value)) in entries.into_iter().enumerate() {
+                if index > 0 {
+                    result.push(',');
+                }
+                result.push_str(&Value::String(key.clone()).to_string());
+                result.push(':');
+                write_canonical(value, result);
+            }
+            result.push('}');
+        }
+        // serde_json의 문자열 이스케이프 규칙은 RFC 8785와 같다.
+        value => result.push_str(&value.to_string()),
+    }
+}
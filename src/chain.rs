@@ -0,0 +1,81 @@
+//! # 버전 체인
+//!
+//! 새 DB의 `version.previous` 항목에 직전 버전 DB의 버전과 해시를 기록하여 버전 간의 체인을 만든다.
+//! 버전 순서대로 나열된 DB 파일의 체인을 확인하면 중간 버전의 누락이나 변조를 찾을 수 있다.
+//!
+//! 해시는 [db_digest]와 같은 방식으로 계산하므로 서명 여부와 관계없이 같은 값을 가진다.
+use serde_json::{json, Value};
+
+use crate::signature::{db_digest, to_hex};
+
+/// 직전 버전 DB의 정보를 `version.previous` 항목에 기록하는 메서드
+///
+/// ## Arguments
+///
+/// * `db` - 새로 만든 DB의 내용
+/// * `previous` - 직전 버전 DB의 내용
+///
+/// ## Examples
+/// ```
+/// use serde_json::json;
+/// use suwon_mate_admin_tool::chain::link_previous;
+/// let previous = json!({"version": {"db_ver": "1.0"}});
+/// let mut db = json!({"version": {"db_ver": "1.1"}});
+/// link_previous(&mut db, &previous);
+/// assert_eq!(db["version"]["previous"]["db_ver"], "1.0");
+/// assert_eq!(db["version"]["previous"]["sha256"].as_str().unwrap().len(), 64);
+/// ```
+pub fn link_previous(db: &mut Value, previous: &Value) {
+    db["version"]["previous"] = json!({
+        "db_ver": previous["version"]["db_ver"],
+        "sha256": to_hex(&db_digest(previous)),
+    });
+}
+
+/// 버전 순서대로 나열된 DB들의 체인이 이어지는지 확인하는 메서드
+///
+/// 이웃한 두 DB마다 뒤쪽 DB의 `version.previous` 항목이 앞쪽 DB와 일치하는지 확인하고,
+/// 일치하지 않는 경우 그 사유를 반환한다. 반환된 목록이 비어 있으면 체인이 온전한 것이다.
+///
+/// ## Arguments
+///
+/// * `dbs` - 오래된 버전부터 나열한 (이름, DB 내용) 목록
+///
+/// ## Examples
+/// ```
+/// use serde_json::json;
+/// use suwon_mate_admin_tool::chain::{chain_failures, link_previous};
+/// let first = json!({"version": {"db_ver": "1.0"}});
+/// let mut second = json!({"version": {"db_ver": "1.1"}});
+/// link_previous(&mut second, &first);
+/// let mut third = json!({"version": {"db_ver": "1.2"}});
+/// link_previous(&mut third, &second);
+/// assert!(chain_failures(&[("1.0", first.clone()), ("1.1", second.clone()), ("1.2", third.clone())]).is_empty());
+/// assert_eq!(chain_failures(&[("1.0", first), ("1.2", third)]).len(), 1);
+/// ```
+pub fn chain_failures<S: AsRef<str>>(dbs: &[(S, Value)]) -> Vec<String> {
+    let mut failures = vec![];
+    for pair in dbs.windows(2) {
+        let [(previous_name, previous), (name, db)] = pair else {
+            continue;
+        };
+        let (previous_name, name) = (previous_name.as_ref(), name.as_ref());
+        let link = &db["version"]["previous"];
+        let Some(sha256) = link["sha256"].as_str() else {
+            failures.push(format!(
+                "{}에 직전 버전 정보가 기록되어 있지 않습니다.",
+                name
+            ));
+            continue;
+        };
+        if sha256 != to_hex(&db_digest(previous)) {
+            failures.push(format!(
+                "{}에 기록된 직전 버전({})의 해시가 {} 파일의 해시와 다릅니다. 중간 버전이 누락되었거나 변조되었습니다.",
+                name,
+                link["db_ver"].as_str().unwrap_or("알 수 없음"),
+                previous_name
+            ));
+        }
+    }
+    failures
+}
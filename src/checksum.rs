@@ -0,0 +1,89 @@
+//! # 체크섬 사이드카 파일
+//!
+//! 수동 배포 과정에서 파일 손상 여부를 확인할 수 있도록 결과 파일 옆에 `sha256sum -c`로 검사할 수 있는
+//! `result_x.json.sha256` 파일을 만들고, DB의 `version` 항목에도 내용의 해시를 기록한다.
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+
+use crate::signature::{db_digest, to_hex};
+
+/// 사이드카 파일의 확장자
+pub const SIDECAR_EXTENSION: &str = "sha256";
+
+/// DB 내용의 SHA-256 해시를 구하는 메서드
+///
+/// `version.content_sha256` 항목 자신과 서명 정보(`version.signature`)는 해시 계산에서 제외되므로,
+/// 기록된 DB를 다시 읽어 같은 메서드로 계산한 값과 비교하여 내용의 손상 여부를 확인할 수 있다.
+/// 해시는 두 항목을 제외한 DB를 RFC 8785에 따라 직렬화한 내용([crate::canonical::canonical_json])으로 계산하므로,
+/// 결과 파일의 키 순서나 공백(`--canonical` 지정 여부)과 관계없이 같은 값이 된다.
+///
+/// ## Examples
+/// ```
+/// use serde_json::json;
+/// use suwon_mate_admin_tool::checksum::content_checksum;
+/// let mut db = json!({"version": {"db_ver": "1.0"}, "a": 1});
+/// let checksum = content_checksum(&db);
+/// db["version"]["content_sha256"] = json!(checksum);
+/// assert_eq!(content_checksum(&db), checksum);
+/// assert_eq!(content_checksum(&json!({"a": 1, "version": {"db_ver": "1.0"}})), checksum);
+/// db["version"]["db_ver"] = json!("2.0");
+/// assert_ne!(content_checksum(&db), checksum);
+/// ```
+pub fn content_checksum(db: &Value) -> String {
+    let mut payload = db.clone();
+    if let Some(version) = payload["version"].as_object_mut() {
+        version.remove("content_sha256");
+    }
+    to_hex(&db_digest(&payload))
+}
+
+/// 결과 파일의 사이드카 파일 경로를 구하는 메서드
+///
+/// ## Examples
+/// ```
+/// use std::path::{Path, PathBuf};
+/// use suwon_mate_admin_tool::checksum::sidecar_path;
+/// assert_eq!(sidecar_path(Path::new("out/result_1.0.json.gz")), PathBuf::from("out/result_1.0.json.gz.sha256"));
+/// ```
+pub fn sidecar_path(result_path: &Path) -> PathBuf {
+    let mut path = result_path.as_os_str().to_os_string();
+    path.push(".");
+    path.push(SIDECAR_EXTENSION);
+    PathBuf::from(path)
+}
+
+/// 결과 파일의 SHA-256 해시를 `sha256sum` 형식으로 사이드카 파일에 기록하는 메서드
+///
+/// 압축된 경우 압축된 파일을 기준으로 하며, 사이드카 파일이 기록된 경로를 반환한다.
+///
+/// ## Examples
+/// ```
+/// use suwon_mate_admin_tool::checksum::write_sidecar;
+/// let path = std::env::temp_dir().join("suwon_mate_checksum_doctest.json");
+/// std::fs::write(&path, "abc").unwrap();
+/// let sidecar = write_sidecar(&path).unwrap();
+/// assert_eq!(
+///     std::fs::read_to_string(sidecar).unwrap(),
+///     "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad  suwon_mate_checksum_doctest.json\n"
+/// );
+/// ```
+///
+/// ## Errors
+/// 결과 파일을 읽거나 사이드카 파일을 쓸 수 없는 경우 오류가 발생한다.
+pub fn write_sidecar(result_path: &Path) -> Result<PathBuf, Box<dyn Error>> {
+    let content = fs::read(result_path)?;
+    let file_name = result_path
+        .file_name()
+        .map(|name| name.to_string_lossy())
+        .unwrap_or_default();
+    let sidecar = sidecar_path(result_path);
+    fs::write(
+        &sidecar,
+        format!("{}  {}\n", to_hex(&Sha256::digest(&content)), file_name),
+    )?;
+    Ok(sidecar)
+}
@@ -0,0 +1,117 @@
+//! # 학과 코드 매핑
+//!
+//! 타 시스템과 조인할 수 있도록 학교의 공식 학부·학과 코드를 DB에 기록한다.
+//!
+//! 매핑 파일은 학부명 또는 학과명을 키로, 코드를 값으로 하는 JSON 객체이다. 코드는 문자열 또는 0 이상의 정수로 적으며,
+//! 앞자리 0이 사라지지 않도록 DB에는 항상 문자열로 기록된다.
+//!
+//! ```json
+//! {"경영학부": "1200", "경영학과": "1201", "회계학과": "1202"}
+//! ```
+use std::collections::{BTreeSet, HashMap};
+use std::error::Error;
+
+use serde_json::{json, Map, Value};
+
+use crate::output::{department_lists, subjects_mut};
+
+/// 매핑 파일의 내용을 검사하여 이름별 코드를 만드는 메서드
+///
+/// ## Examples
+/// ```
+/// use serde_json::json;
+/// use suwon_mate_admin_tool::codes::parse_code_map;
+/// let codes = parse_code_map(&json!({"경영학부": "0120", "경영학과": 121})).unwrap();
+/// assert_eq!(codes["경영학부"], "0120");
+/// assert_eq!(codes["경영학과"], "121");
+/// assert!(parse_code_map(&json!({"경영학부": ""})).is_err());
+/// assert!(parse_code_map(&json!({"경영학부": -1})).is_err());
+/// assert!(parse_code_map(&json!(["경영학부"])).is_err());
+/// ```
+///
+/// ## Errors
+/// * 내용이 객체가 아닌 경우
+/// * 코드가 빈 문자열이거나 문자열 또는 0 이상의 정수가 아닌 경우
+pub fn parse_code_map(content: &Value) -> Result<HashMap<String, String>, Box<dyn Error>> {
+    let entries = content
+        .as_object()
+        .ok_or("학과 코드 매핑 파일은 이름을 키로 하는 객체여야 합니다.")?;
+    entries
+        .iter()
+        .map(|(name, code)| {
+            let code = match code {
+                Value::String(code) if !code.trim().is_empty() => code.trim().to_string(),
+                Value::Number(code) if code.is_u64() => code.to_string(),
+                _ => {
+                    return Err(format!(
+                        "{}의 학과 코드가 올바르지 않습니다: {}",
+                        name, code
+                    ))
+                }
+            };
+            Ok((name.clone(), code))
+        })
+        .collect::<Result<_, _>>()
+        .map_err(Into::into)
+}
+
+/// 과목 정보와 학부 목록에 학과 코드를 기록하는 메서드
+///
+/// 각 과목에는 개설 학과(`estbMjorNm`)의 코드를, 학과가 없거나 매핑되지 않은 경우 개설 학부(`estbDpmjNm`)의 코드를
+/// `dept_code` 항목으로 기록한다. `departments`의 학과 목록은 이름의 배열이므로 코드는 학부명과 학과명(학부 자체는 학부명)을
+/// 키로 하는 `department_codes` 섹션에 따로 기록한다. 코드를 찾지 못한 경우 `null`로 기록된다.
+///
+/// 코드를 찾지 못한 학부와 학과의 이름 목록을 반환한다.
+///
+/// ## Examples
+/// ```
+/// use std::collections::HashMap;
+/// use serde_json::json;
+/// use suwon_mate_admin_tool::codes::apply_dept_codes;
+/// let mut db = json!({
+///     "departments": {"경영학부": ["경영학과", "회계학과"]},
+///     "estbLectDtaiList": {"경영학부": [
+///         {"estbDpmjNm": "경영학부", "estbMjorNm": "경영학과"},
+///         {"estbDpmjNm": "경영학부", "estbMjorNm": null},
+///         {"estbDpmjNm": "경영학부", "estbMjorNm": "회계학과"}
+///     ]}
+/// });
+/// let codes = HashMap::from([
+///     (String::from("경영학부"), String::from("1200")),
+///     (String::from("경영학과"), String::from("1201")),
+/// ]);
+/// let unmapped = apply_dept_codes(&mut db, &codes);
+/// assert_eq!(db["estbLectDtaiList"]["경영학부"][0]["dept_code"], "1201");
+/// assert_eq!(db["estbLectDtaiList"]["경영학부"][1]["dept_code"], "1200");
+/// assert_eq!(db["estbLectDtaiList"]["경영학부"][2]["dept_code"], "1200");
+/// assert_eq!(db["department_codes"]["경영학부"], json!({"경영학부": "1200", "경영학과": "1201", "회계학과": null}));
+/// assert_eq!(unmapped, vec!["회계학과"]);
+/// ```
+pub fn apply_dept_codes(db: &mut Value, codes: &HashMap<String, String>) -> Vec<String> {
+    let mut unmapped = BTreeSet::new();
+    let mut department_codes = Map::new();
+    for (department, majors) in department_lists(db).into_iter().flatten() {
+        let names = majors
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(Value::as_str);
+        let mut entry = Map::new();
+        for name in std::iter::once(department.as_str()).chain(names) {
+            let code = codes.get(name);
+            if code.is_none() {
+                unmapped.insert(name.to_string());
+            }
+            entry.insert(name.to_string(), json!(code));
+        }
+        department_codes.insert(department.clone(), Value::Object(entry));
+    }
+    for subject in subjects_mut(db) {
+        let code = ["estbMjorNm", "estbDpmjNm"]
+            .iter()
+            .find_map(|field| subject[field].as_str().and_then(|name| codes.get(name)));
+        subject["dept_code"] = json!(code);
+    }
+    db["department_codes"] = Value::Object(department_codes);
+    unmapped.into_iter().collect()
+}
@@ -0,0 +1,138 @@
+//! # 앱 버전 호환성 매트릭스
+//!
+//! 선언형 설정 파일에서 DB 버전별로 필요한 최소 앱 버전을 읽어 DB의 `version` 항목에 기록한다.
+//!
+//! 설정 파일은 아래와 같은 YAML 목록이며, 각 항목은 "`db_ver` 이상의 DB는 `min_app_ver` 이상의 앱이 필요함"을 뜻한다.
+//! 현재 DB에는 `db_ver`가 현재 DB 버전 이하인 항목 중 가장 높은 버전의 항목이 적용된다.
+//!
+//! ```yaml
+//! - db_ver: "2.0"
+//!   min_app_ver: "1.2"
+//! - db_ver: "2.3"
+//!   min_app_ver: "1.4"
+//! ```
+use std::cmp::Ordering;
+use std::error::Error;
+
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+/// 호환성 매트릭스의 항목
+#[derive(Clone, PartialEq, Eq, Debug, Deserialize)]
+pub struct CompatibilityRule {
+    /// 항목이 적용되기 시작하는 DB 버전
+    pub db_ver: String,
+    /// 필요한 최소 앱 버전
+    pub min_app_ver: String,
+}
+
+/// `.`으로 구분된 버전 문자열을 비교하는 메서드
+///
+/// 각 부분이 모두 숫자인 경우 숫자로, 그렇지 않은 경우 문자열로 비교하며 없는 부분은 `0`으로 취급한다.
+///
+/// ## Examples
+/// ```
+/// use std::cmp::Ordering;
+/// use suwon_mate_admin_tool::compatibility::compare_versions;
+/// assert_eq!(compare_versions("1.10", "1.9"), Ordering::Greater);
+/// assert_eq!(compare_versions("2.0", "2"), Ordering::Equal);
+/// assert_eq!(compare_versions("1.4", "1.4.1"), Ordering::Less);
+/// ```
+pub fn compare_versions(left: &str, right: &str) -> Ordering {
+    let left: Vec<&str> = left.trim().split('.').collect();
+    let right: Vec<&str> = right.trim().split('.').collect();
+    for index in 0..left.len().max(right.len()) {
+        let left = left.get(index).copied().unwrap_or("0");
+        let right = right.get(index).copied().unwrap_or("0");
+        let ordering = match (left.parse::<u64>(), right.parse::<u64>()) {
+            (Ok(left), Ok(right)) => left.cmp(&right),
+            _ => left.cmp(right),
+        };
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+    }
+    Ordering::Equal
+}
+
+/// YAML 형식의 호환성 매트릭스 설정을 해석하는 메서드
+///
+/// 항목은 DB 버전 순으로 정렬된다.
+///
+/// ## Errors
+/// * YAML 해독이 불가능하거나 항목에 `db_ver`, `min_app_ver`가 없는 경우
+/// * 같은 DB 버전의 항목이 두 번 이상 기록된 경우
+pub fn parse_matrix(content: &str) -> Result<Vec<CompatibilityRule>, Box<dyn Error>> {
+    let mut rules: Vec<CompatibilityRule> = serde_yaml::from_str(content)?;
+    rules.sort_by(|left, right| compare_versions(&left.db_ver, &right.db_ver));
+    if let Some(pair) = rules
+        .windows(2)
+        .find(|pair| compare_versions(&pair[0].db_ver, &pair[1].db_ver) == Ordering::Equal)
+    {
+        return Err(format!(
+            "호환성 매트릭스에 같은 DB 버전({})의 항목이 두 번 이상 기록되었습니다.",
+            pair[1].db_ver
+        )
+        .into());
+    }
+    Ok(rules)
+}
+
+/// DB 버전에 적용되는 최소 앱 버전을 찾는 메서드
+///
+/// ## Examples
+/// ```
+/// use suwon_mate_admin_tool::compatibility::{min_app_version, parse_matrix};
+/// let rules = parse_matrix("- db_ver: \"2.3\"\n  min_app_ver: \"1.4\"\n- db_ver: \"2.0\"\n  min_app_ver: \"1.2\"\n").unwrap();
+/// assert_eq!(min_app_version(&rules, "2.4"), Some("1.4"));
+/// assert_eq!(min_app_version(&rules, "2.1"), Some("1.2"));
+/// assert_eq!(min_app_version(&rules, "1.9"), None);
+/// ```
+pub fn min_app_version<'a>(rules: &'a [CompatibilityRule], db_version: &str) -> Option<&'a str> {
+    rules
+        .iter()
+        .rev()
+        .find(|rule| compare_versions(&rule.db_ver, db_version) != Ordering::Greater)
+        .map(|rule| rule.min_app_ver.as_str())
+}
+
+/// 호환성 매트릭스를 DB의 `version` 항목에 기록하는 메서드
+///
+/// 매트릭스 전체는 `version.compatibility`에, 현재 DB 버전에 적용되는 최소 앱 버전은 `version.min_app_ver`에 기록된다.
+/// 최소 앱 버전이 DB에 기록된 최신 앱 버전(`app_ver`)보다 높은 경우 경고 메세지를 반환한다.
+///
+/// ## Examples
+/// ```
+/// use serde_json::json;
+/// use suwon_mate_admin_tool::compatibility::{apply_matrix, parse_matrix};
+/// let rules = parse_matrix("- db_ver: \"2.3\"\n  min_app_ver: \"1.4\"\n").unwrap();
+/// let mut db = json!({"version": {"app_ver": "1.3", "db_ver": "2.3"}});
+/// let warning = apply_matrix(&mut db, &rules);
+/// assert_eq!(db["version"]["min_app_ver"], "1.4");
+/// assert_eq!(db["version"]["compatibility"][0]["db_ver"], "2.3");
+/// assert!(warning.is_some());
+/// ```
+pub fn apply_matrix(db: &mut Value, rules: &[CompatibilityRule]) -> Option<String> {
+    let db_version = db["version"]["db_ver"]
+        .as_str()
+        .unwrap_or_default()
+        .to_string();
+    let app_version = db["version"]["app_ver"]
+        .as_str()
+        .unwrap_or_default()
+        .to_string();
+    let min_app_ver = min_app_version(rules, &db_version);
+    db["version"]["compatibility"] = rules
+        .iter()
+        .map(|rule| json!({"db_ver": rule.db_ver, "min_app_ver": rule.min_app_ver}))
+        .collect();
+    db["version"]["min_app_ver"] = json!(min_app_ver);
+    min_app_ver
+        .filter(|min_app_ver| compare_versions(min_app_ver, &app_version) == Ordering::Greater)
+        .map(|min_app_ver| {
+            format!(
+                "주의: DB 버전 {}에 필요한 최소 앱 버전({})이 최신 앱 버전({})보다 높습니다.",
+                db_version, min_app_ver, app_version
+            )
+        })
+}
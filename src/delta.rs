@@ -0,0 +1,150 @@
+//! # JSON Patch 델타
+//!
+//! 이전 DB와 새 DB를 비교하여 이전 DB를 새 DB로 바꾸는 JSON Patch([RFC 6902](https://www.rfc-editor.org/rfc/rfc6902)) 문서를 만든다.
+//! 앱이 전체 DB 대신 변경분만 내려받아 적용할 수 있도록 하기 위한 기능이다.
+//!
+//! 객체는 키 단위로, 배열은 위치 단위로 비교한다. 배열의 길이가 달라진 경우 뒤쪽 요소를 추가하거나 제거하는 연산이 만들어진다.
+use std::error::Error;
+
+use serde_json::{json, Value};
+
+/// 객체의 키를 JSON Pointer의 참조 토큰으로 바꾸는 메서드
+///
+/// ## Examples
+/// ```
+/// use suwon_mate_admin_tool::delta::pointer_token;
+/// assert_eq!(pointer_token("a/b~c"), "a~1b~0c");
+/// ```
+pub fn pointer_token(key: &str) -> String {
+    key.replace('~', "~0").replace('/', "~1")
+}
+
+/// 두 값을 비교하여 JSON Patch 연산을 `patch`에 추가하는 메서드
+fn diff_into(previous: &Value, current: &Value, path: &str, patch: &mut Vec<Value>) {
+    match (previous, current) {
+        (Value::Object(previous), Value::Object(current)) => {
+            for (key, value) in previous.iter() {
+                let path = format!("{}/{}", path, pointer_token(key));
+                match current.get(key) {
+                    Some(current) => diff_into(value, current, &path, patch),
+                    None => patch.push(json!({"op": "remove", "path": path})),
+                }
+            }
+            for (key, value) in current.iter() {
+                if !previous.contains_key(key) {
+                    let path = format!("{}/{}", path, pointer_token(key));
+                    patch.push(json!({"op": "add", "path": path, "value": value}));
+                }
+            }
+        }
+        (Value::Array(previous), Value::Array(current)) => {
+            for (index, (previous, current)) in previous.iter().zip(current).enumerate() {
+                diff_into(previous, current, &format!("{}/{}", path, index), patch);
+            }
+            for index in (current.len()..previous.len()).rev() {
+                patch.push(json!({"op": "remove", "path": format!("{}/{}", path, index)}));
+            }
+            for value in current.iter().skip(previous.len()) {
+                patch.push(json!({"op": "add", "path": format!("{}/-", path), "value": value}));
+            }
+        }
+        (previous, current) if previous != current => {
+            patch.push(json!({"op": "replace", "path": path, "value": current}));
+        }
+        _ => {}
+    }
+}
+
+/// 이전 DB를 새 DB로 바꾸는 JSON Patch 문서를 만드는 메서드
+///
+/// ## Arguments
+///
+/// * `previous` - 이전 DB의 내용
+/// * `current` - 새 DB의 내용
+///
+/// ## Examples
+/// ```
+/// use serde_json::json;
+/// use suwon_mate_admin_tool::delta::{apply_patch, json_patch};
+/// let previous = json!({"estbLectDtaiList": {"경영학부": [{"subjtCd": "1"}, {"subjtCd": "2"}]}, "version": {"db_ver": "1.0"}});
+/// let current = json!({"estbLectDtaiList": {"경영학부": [{"subjtCd": "1", "point": 3}]}, "version": {"db_ver": "1.1"}});
+/// let patch = json_patch(&previous, &current);
+/// assert_eq!(patch[0], json!({"op": "add", "path": "/estbLectDtaiList/경영학부/0/point", "value": 3}));
+/// assert_eq!(apply_patch(&previous, &patch).unwrap(), current);
+/// assert_eq!(json_patch(&current, &current), json!([]));
+/// ```
+pub fn json_patch(previous: &Value, current: &Value) -> Value {
+    let mut patch = vec![];
+    diff_into(previous, current, "", &mut patch);
+    Value::Array(patch)
+}
+
+/// JSON Pointer가 가리키는 값의 부모와 마지막 참조 토큰을 찾는 메서드
+fn parent_mut<'a>(
+    document: &'a mut Value,
+    path: &str,
+) -> Result<(&'a mut Value, String), Box<dyn Error>> {
+    let (parent, token) = path
+        .rsplit_once('/')
+        .ok_or_else(|| format!("올바르지 않은 경로입니다: {}", path))?;
+    let parent = document
+        .pointer_mut(parent)
+        .ok_or_else(|| format!("경로가 존재하지 않습니다: {}", path))?;
+    Ok((parent, token.replace("~1", "/").replace("~0", "~")))
+}
+
+/// JSON Patch 문서를 적용하는 메서드
+///
+/// [json_patch]가 만드는 `add`, `remove`, `replace` 연산만 지원한다.
+///
+/// ## Errors
+/// * 패치가 연산의 배열이 아니거나 지원하지 않는 연산이 포함된 경우
+/// * 연산의 경로가 존재하지 않는 경우
+pub fn apply_patch(document: &Value, patch: &Value) -> Result<Value, Box<dyn Error>> {
+    let mut document = document.clone();
+    let operations = patch
+        .as_array()
+        .ok_or("JSON Patch 문서는 연산의 배열이어야 합니다.")?;
+    for operation in operations {
+        let path = operation["path"]
+            .as_str()
+            .ok_or_else(|| format!("연산에 경로(path)가 없습니다: {}", operation))?;
+        let value = operation["value"].clone();
+        match operation["op"].as_str() {
+            Some("replace") if path.is_empty() => document = value,
+            Some("replace") => {
+                *document
+                    .pointer_mut(path)
+                    .ok_or_else(|| format!("경로가 존재하지 않습니다: {}", path))? = value;
+            }
+            Some("add") => match parent_mut(&mut document, path)? {
+                (Value::Object(parent), token) => {
+                    parent.insert(token, value);
+                }
+                (Value::Array(parent), token) if token == "-" => parent.push(value),
+                (Value::Array(parent), token) => {
+                    let index: usize = token.parse()?;
+                    if index > parent.len() {
+                        return Err(format!("배열의 범위를 벗어난 경로입니다: {}", path).into());
+                    }
+                    parent.insert(index, value);
+                }
+                _ => return Err(format!("값을 추가할 수 없는 경로입니다: {}", path).into()),
+            },
+            Some("remove") => {
+                let removed = match parent_mut(&mut document, path)? {
+                    (Value::Object(parent), token) => parent.remove(&token),
+                    (Value::Array(parent), token) => token
+                        .parse::<usize>()
+                        .ok()
+                        .filter(|index| *index < parent.len())
+                        .map(|index| parent.remove(index)),
+                    _ => None,
+                };
+                removed.ok_or_else(|| format!("경로가 존재하지 않습니다: {}", path))?;
+            }
+            _ => return Err(format!("지원하지 않는 연산입니다: {}", operation).into()),
+        }
+    }
+    Ok(document)
+}
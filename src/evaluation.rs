@@ -0,0 +1,96 @@
+//! # 강의평가 점수 병합
+//!
+//! 별도로 수집한 강의평가 요약 파일의 평점을 DB의 과목 정보에 `rating` 항목으로 병합한다.
+//!
+//! 요약 파일은 아래와 같은 항목의 배열로 이루어진 JSON 파일이다. `ltrPrfsNm`과 `rating`은 필수이며,
+//! `estbDpmjNm`, `subjtNm`, `subjtCd`가 주어진 경우 해당 값까지 같은 과목에만 평점을 병합한다.
+//!
+//! ```json
+//! [{"ltrPrfsNm": "홍길동", "subjtNm": "회계원리", "rating": 4.5}]
+//! ```
+use std::error::Error;
+
+use serde_json::Value;
+
+use crate::output::subjects_mut;
+
+/// 평점 항목에서 과목을 찾을 때 함께 비교하는 선택 필드
+pub const EVALUATION_KEY_FIELDS: [&str; 3] = ["estbDpmjNm", "subjtNm", "subjtCd"];
+
+/// 평점 항목이 과목에 해당하는지 확인하는 메서드
+fn matches(evaluation: &Value, professor: &str, subject: &Value) -> bool {
+    subject["ltrPrfsNm"].as_str() == Some(professor)
+        && EVALUATION_KEY_FIELDS.iter().all(|field| {
+            evaluation
+                .get(field)
+                .is_none_or(|value| value == &subject[field])
+        })
+}
+
+/// 강의평가 요약의 평점을 DB의 과목 정보에 병합하는 메서드
+///
+/// 평점 항목마다 해당하는 모든 과목의 `rating` 항목에 평점을 기록한다.
+/// 한 과목에 여러 평점 항목이 해당하는 경우 나중에 나온 항목의 평점이 기록된다.
+///
+/// 해당하는 과목을 찾지 못했거나 필수 항목이 빠진 평점 항목은 병합하지 않고, 그 사유를 반환한다.
+///
+/// ## Arguments
+///
+/// * `db` - 평점을 병합할 DB의 내용
+/// * `evaluations` - 강의평가 요약 파일의 내용
+///
+/// ## Examples
+/// ```
+/// use serde_json::json;
+/// use suwon_mate_admin_tool::evaluation::merge_evaluations;
+/// let mut db = json!({"estbLectDtaiList": {"경영학부": [
+///     {"ltrPrfsNm": "홍길동", "subjtNm": "회계원리"},
+///     {"ltrPrfsNm": "홍길동", "subjtNm": "재무관리"}
+/// ]}});
+/// let evaluations = json!([
+///     {"ltrPrfsNm": "홍길동", "subjtNm": "회계원리", "rating": 4.5},
+///     {"ltrPrfsNm": "김철수", "rating": 3.0}
+/// ]);
+/// let failures = merge_evaluations(&mut db, &evaluations).unwrap();
+/// assert_eq!(db["estbLectDtaiList"]["경영학부"][0]["rating"], 4.5);
+/// assert!(db["estbLectDtaiList"]["경영학부"][1].get("rating").is_none());
+/// assert_eq!(failures.len(), 1);
+/// ```
+///
+/// ## Errors
+/// 강의평가 요약 파일의 내용이 배열이 아닌 경우 오류가 발생한다.
+pub fn merge_evaluations(
+    db: &mut Value,
+    evaluations: &Value,
+) -> Result<Vec<String>, Box<dyn Error>> {
+    let evaluations = evaluations
+        .as_array()
+        .ok_or("강의평가 요약 파일은 평점 항목의 배열이어야 합니다.")?;
+    let mut failures = vec![];
+    for (index, evaluation) in evaluations.iter().enumerate() {
+        let (Some(professor), Some(rating)) = (
+            evaluation["ltrPrfsNm"].as_str(),
+            evaluation.get("rating").filter(|rating| rating.is_number()),
+        ) else {
+            failures.push(format!(
+                "강의평가 {}번째 항목에 교수명(ltrPrfsNm) 또는 평점(rating)이 없습니다: {}",
+                index + 1,
+                evaluation
+            ));
+            continue;
+        };
+        let mut matched = false;
+        for subject in subjects_mut(db).filter(|subject| matches(evaluation, professor, subject)) {
+            subject["rating"] = rating.clone();
+            matched = true;
+        }
+        if !matched {
+            failures.push(format!(
+                "강의평가 {}번째 항목에 해당하는 과목을 찾지 못하였습니다: {}",
+                index + 1,
+                evaluation
+            ));
+        }
+    }
+    Ok(failures)
+}
@@ -0,0 +1,154 @@
+//! # DB 하위 집합 추출
+//!
+//! 버그 재현용 최소 데이터를 만들 수 있도록 완성된 DB에서 특정 학부나 특정 과목 코드의 과목만 뽑아 작은 DB를 만든다.
+//!
+//! 과목 목록 외에 과목이나 학부에 딸린 항목도 함께 줄어든다.
+//!
+//! * `departments` - 남은 학부의 학과 목록만 남긴다.
+//! * `contacts` - 남은 학부에서 남은 과목의 교수 연락처만 남긴다.
+//! * `resources` - 남은 과목의 자료만 남긴다.
+//!
+//! 그 외의 항목(`version` 등)은 그대로 유지된다.
+use std::collections::{HashMap, HashSet};
+
+use serde_json::{Map, Value};
+
+use crate::subject_key;
+
+/// 추출할 과목의 조건
+///
+/// 조건이 비어있는 항목은 모든 과목을 만족하는 것으로 본다.
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct ExtractFilter {
+    /// 추출할 학부명 목록
+    pub departments: Vec<String>,
+    /// 추출할 과목 코드(`subjtCd`) 또는 과목 키(`subjtCd-diclNo`) 목록
+    pub subject_codes: Vec<String>,
+}
+
+impl ExtractFilter {
+    /// 학부 `department`의 과목 `subject`가 조건을 만족하는지 확인하는 메서드
+    ///
+    /// ## Examples
+    /// ```
+    /// use serde_json::json;
+    /// use suwon_mate_admin_tool::extract::ExtractFilter;
+    /// let filter = ExtractFilter { subject_codes: vec!["1".into(), "2-002".into()], ..Default::default() };
+    /// assert!(filter.matches("경영학부", &json!({"subjtCd": "1", "diclNo": "003"})));
+    /// assert!(filter.matches("경영학부", &json!({"subjtCd": "2", "diclNo": "002"})));
+    /// assert!(!filter.matches("경영학부", &json!({"subjtCd": "2", "diclNo": "001"})));
+    /// ```
+    pub fn matches(&self, department: &str, subject: &Value) -> bool {
+        let department_matches =
+            self.departments.is_empty() || self.departments.iter().any(|name| name == department);
+        let code_matches = self.subject_codes.is_empty() || {
+            let code = subject["subjtCd"].as_str().unwrap_or("");
+            let key = subject_key(subject);
+            self.subject_codes
+                .iter()
+                .any(|subject_code| subject_code == code || *subject_code == key)
+        };
+        department_matches && code_matches
+    }
+}
+
+/// DB에서 `filter`를 만족하는 과목만 남긴 하위 집합 DB를 만드는 메서드
+///
+/// 과목이 하나도 남지 않은 학부는 과목 목록에서 제외된다. 추출된 DB와 남은 과목의 수를 반환한다.
+///
+/// ## Arguments
+///
+/// * `db` - 완성된 DB의 내용
+/// * `filter` - 추출할 과목의 조건
+///
+/// ## Examples
+/// ```
+/// use serde_json::json;
+/// use suwon_mate_admin_tool::extract::{extract_db, ExtractFilter};
+/// let db = json!({
+///     "departments": {"경영학부": ["경영학과"], "컴퓨터학부": ["컴퓨터학과"]},
+///     "estbLectDtaiList": {
+///         "경영학부": [
+///             {"subjtCd": "1", "diclNo": "001", "ltrPrfsNm": "홍길동"},
+///             {"subjtCd": "2", "diclNo": "001", "ltrPrfsNm": "김철수"}
+///         ],
+///         "컴퓨터학부": [{"subjtCd": "3", "diclNo": "001", "ltrPrfsNm": "이영희"}]
+///     },
+///     "contacts": {
+///         "경영학부": {"홍길동": {"email": null}, "김철수": {"email": null}},
+///         "컴퓨터학부": {"이영희": {"email": null}}
+///     },
+///     "resources": {"1-001": [], "3-001": []},
+///     "version": {"db_ver": "1.0"}
+/// });
+/// let filter = ExtractFilter { subject_codes: vec!["1".into()], ..Default::default() };
+/// let (extracted, count) = extract_db(&db, &filter);
+/// assert_eq!(count, 1);
+/// assert_eq!(extracted, json!({
+///     "departments": {"경영학부": ["경영학과"]},
+///     "estbLectDtaiList": {"경영학부": [{"subjtCd": "1", "diclNo": "001", "ltrPrfsNm": "홍길동"}]},
+///     "contacts": {"경영학부": {"홍길동": {"email": null}}},
+///     "resources": {"1-001": []},
+///     "version": {"db_ver": "1.0"}
+/// }));
+///
+/// let filter = ExtractFilter { departments: vec!["컴퓨터학부".into()], ..Default::default() };
+/// assert_eq!(extract_db(&db, &filter).1, 1);
+/// ```
+pub fn extract_db(db: &Value, filter: &ExtractFilter) -> (Value, usize) {
+    let mut extracted = db.clone();
+    let mut count = 0;
+    let mut professors: HashMap<String, HashSet<String>> = HashMap::new();
+    let mut keys = HashSet::new();
+    for list_key in ["estbLectDtaiList", "estbLectDtaiList_quick"] {
+        let Some(lists) = extracted.get_mut(list_key).and_then(Value::as_object_mut) else {
+            continue;
+        };
+        lists.retain(|department, subjects| {
+            let Some(subjects) = subjects.as_array_mut() else {
+                return false;
+            };
+            subjects.retain(|subject| filter.matches(department, subject));
+            for subject in subjects.iter() {
+                keys.insert(subject_key(subject));
+                if let Some(name) = subject["ltrPrfsNm"].as_str() {
+                    professors
+                        .entry(department.clone())
+                        .or_default()
+                        .insert(name.to_string());
+                }
+            }
+            count += subjects.len();
+            !subjects.is_empty()
+        });
+    }
+    let departments: HashSet<String> = ["estbLectDtaiList", "estbLectDtaiList_quick"]
+        .into_iter()
+        .filter_map(|list_key| extracted.get(list_key).and_then(Value::as_object))
+        .flat_map(Map::keys)
+        .cloned()
+        .collect();
+    for list_key in ["departments", "departments_quick"] {
+        if let Some(lists) = extracted.get_mut(list_key).and_then(Value::as_object_mut) {
+            lists.retain(|department, _| departments.contains(department));
+        }
+    }
+    if let Some(contacts) = extracted.get_mut("contacts").and_then(Value::as_object_mut) {
+        contacts.retain(|department, contacts| {
+            let Some(names) = professors.get(department) else {
+                return false;
+            };
+            if let Some(contacts) = contacts.as_object_mut() {
+                contacts.retain(|name, _| names.contains(name));
+            }
+            true
+        });
+    }
+    if let Some(resources) = extracted
+        .get_mut("resources")
+        .and_then(Value::as_object_mut)
+    {
+        resources.retain(|key, _| keys.contains(key));
+    }
+    (extracted, count)
+}
@@ -0,0 +1,49 @@
+//! # Firebase import 호환 출력
+//!
+//! `firebase database:set`에 바로 사용할 수 있도록 DB를 Realtime Database의 노드 경로로 감싼다.
+use std::error::Error;
+
+use serde_json::{json, Value};
+
+/// DB를 감쌀 기본 노드 경로
+pub const FIREBASE_NODE_PATH: &str = "/db";
+
+/// Realtime Database 키에 사용할 수 없는 문자
+const FORBIDDEN_CHARACTERS: [char; 5] = ['.', '$', '#', '[', ']'];
+
+/// DB를 `{"<노드 경로>": DB}` 형태로 감싸는 메서드
+///
+/// ## Arguments
+///
+/// * `db` - 감쌀 DB
+/// * `node_path` - DB를 기록할 노드 경로 (`/`로 시작)
+///
+/// ## Examples
+/// ```
+/// use serde_json::json;
+/// use suwon_mate_admin_tool::firebase::firebase_import;
+/// let db = json!({"version": {"db_ver": "1.0"}});
+/// assert_eq!(
+///     firebase_import(&db, "/db").unwrap(),
+///     json!({"/db": {"version": {"db_ver": "1.0"}}})
+/// );
+/// assert!(firebase_import(&db, "db").is_err());
+/// assert!(firebase_import(&db, "/db.v2").is_err());
+/// assert!(firebase_import(&db, "/db//v2").is_err());
+/// ```
+///
+/// ## Errors
+/// * 노드 경로가 `/`로 시작하지 않는 경우
+/// * 노드 경로에 빈 구간이 있거나 Realtime Database 키에 사용할 수 없는 문자(`.`, `$`, `#`, `[`, `]`)가 포함된 경우
+pub fn firebase_import(db: &Value, node_path: &str) -> Result<Value, Box<dyn Error>> {
+    let segments = node_path
+        .strip_prefix('/')
+        .ok_or_else(|| format!("노드 경로는 /로 시작해야 합니다: {}", node_path))?;
+    if segments
+        .split('/')
+        .any(|segment| segment.is_empty() || segment.contains(FORBIDDEN_CHARACTERS))
+    {
+        return Err(format!("올바르지 않은 노드 경로입니다: {}", node_path).into());
+    }
+    Ok(json!({ node_path: db }))
+}
@@ -0,0 +1,97 @@
+//! # 입력 파일 자동 교정
+//!
+//! 포털에서 내려받은 입력 파일의 문제 중 자동으로 고칠 수 있는 것을 고쳐 새 입력 파일을 만든다.
+//!
+//! * 파일 앞의 UTF-8 BOM 제거
+//! * 문자열 값 앞뒤의 공백 제거
+//! * `estbLectDtaiList`에서 내용이 완전히 같은 과목의 중복 제거
+//!
+//! 과목 키(`subjtCd-diclNo`)는 같지만 내용이 다른 과목은 어느 쪽이 맞는지 알 수 없으므로 고치지 않고 보고만 한다.
+use std::collections::HashMap;
+use std::error::Error;
+
+use serde_json::Value;
+
+use crate::subject_key;
+
+/// UTF-8 BOM
+const UTF8_BOM: &[u8] = b"\xEF\xBB\xBF";
+
+/// 자동 교정 결과
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct FixReport {
+    /// 파일 앞의 BOM을 제거했는지 여부
+    pub bom_removed: bool,
+    /// 앞뒤 공백을 제거한 문자열 값의 수
+    pub trimmed: usize,
+    /// 제거한 중복 과목의 수
+    pub duplicates_removed: usize,
+    /// 과목 키는 같지만 내용이 달라 고치지 못한 과목 키 목록
+    pub conflicts: Vec<String>,
+}
+
+/// 값에 포함된 모든 문자열의 앞뒤 공백을 제거하고 제거한 문자열의 수를 반환하는 메서드
+fn trim_strings(value: &mut Value) -> usize {
+    match value {
+        Value::String(text) if text.trim().len() != text.len() => {
+            *text = text.trim().to_string();
+            1
+        }
+        Value::Array(values) => values.iter_mut().map(trim_strings).sum(),
+        Value::Object(values) => values.values_mut().map(trim_strings).sum(),
+        _ => 0,
+    }
+}
+
+/// 입력 파일의 내용을 교정하는 메서드
+///
+/// ## Examples
+/// ```
+/// use serde_json::json;
+/// use suwon_mate_admin_tool::fix::fix_input;
+/// let content = "\u{feff}{\"estbLectDtaiList\": [
+///     {\"subjtCd\": \"1\", \"diclNo\": \"001\", \"subjtNm\": \" 회계원리 \"},
+///     {\"subjtCd\": \"1\", \"diclNo\": \"001\", \"subjtNm\": \"회계원리\"},
+///     {\"subjtCd\": \"2\", \"diclNo\": \"001\", \"subjtNm\": \"재무관리\"},
+///     {\"subjtCd\": \"2\", \"diclNo\": \"001\", \"subjtNm\": \"재무관리2\"}
+/// ]}";
+/// let (fixed, report) = fix_input(content.as_bytes()).unwrap();
+/// assert!(report.bom_removed);
+/// assert_eq!(report.trimmed, 1);
+/// assert_eq!(report.duplicates_removed, 1);
+/// assert_eq!(report.conflicts, vec!["2-001"]);
+/// assert_eq!(fixed["estbLectDtaiList"].as_array().unwrap().len(), 3);
+/// ```
+///
+/// ## Errors
+/// BOM을 제거한 내용의 JSON 해독이 불가능한 경우 오류가 발생한다.
+pub fn fix_input(content: &[u8]) -> Result<(Value, FixReport), Box<dyn Error>> {
+    let mut report = FixReport::default();
+    let content = match content.strip_prefix(UTF8_BOM) {
+        Some(content) => {
+            report.bom_removed = true;
+            content
+        }
+        None => content,
+    };
+    let mut input: Value = serde_json::from_slice(content)?;
+    report.trimmed = trim_strings(&mut input);
+    if let Some(subjects) = input["estbLectDtaiList"].as_array_mut() {
+        let mut seen: HashMap<String, Vec<Value>> = HashMap::new();
+        let before = subjects.len();
+        subjects.retain(|subject| {
+            let key = subject_key(subject);
+            let records = seen.entry(key.clone()).or_default();
+            if records.contains(subject) {
+                return false;
+            }
+            if !records.is_empty() && !report.conflicts.contains(&key) {
+                report.conflicts.push(key);
+            }
+            records.push(subject.clone());
+            true
+        });
+        report.duplicates_removed = before - subjects.len();
+    }
+    Ok((input, report))
+}
@@ -0,0 +1,68 @@
+//! # 학부명 퍼지 매칭
+//!
+//! 분류에 실패한 학부명이 기존 학부명과 한두 글자만 다른 경우 오타로 보고, 레벤슈타인 거리를 이용해 가장 비슷한 학부명을 찾는다.
+use std::collections::BTreeSet;
+
+/// 오타로 볼 수 있는 최대 편집 거리
+pub const MAX_FUZZY_DISTANCE: usize = 2;
+
+/// 두 문자열의 레벤슈타인 거리를 글자 단위로 구하는 메서드
+///
+/// ## Examples
+/// ```
+/// use suwon_mate_admin_tool::fuzzy::levenshtein;
+/// assert_eq!(levenshtein("경영학부", "경영학부"), 0);
+/// assert_eq!(levenshtein("경영학부", "경엉학부"), 1);
+/// assert_eq!(levenshtein("컴퓨터학부", "컴퓨터학"), 1);
+/// assert_eq!(levenshtein("", "학부"), 2);
+/// ```
+pub fn levenshtein(left: &str, right: &str) -> usize {
+    let right: Vec<char> = right.chars().collect();
+    let mut previous: Vec<usize> = (0..=right.len()).collect();
+    for (left_index, left_char) in left.chars().enumerate() {
+        let mut current = vec![left_index + 1];
+        for (right_index, right_char) in right.iter().enumerate() {
+            let substitution = previous[right_index] + usize::from(left_char != *right_char);
+            current.push(
+                substitution
+                    .min(previous[right_index + 1] + 1)
+                    .min(current[right_index] + 1),
+            );
+        }
+        previous = current;
+    }
+    previous[right.len()]
+}
+
+/// 기존 학부명 중 `name`의 오타로 볼 수 있는 가장 비슷한 학부명을 찾는 메서드
+///
+/// 편집 거리가 1 이상 [MAX_FUZZY_DISTANCE] 이하이면서 `name`의 글자 수보다 작은 학부명만 후보가 되며,
+/// 거리가 같은 후보가 여럿인 경우 사전순으로 앞선 학부명을 선택한다.
+///
+/// ## Arguments
+///
+/// * `name` - 분류에 실패한 학부명
+/// * `candidates` - 기존 학부명 목록
+///
+/// ## Examples
+/// ```
+/// use suwon_mate_admin_tool::fuzzy::closest_department;
+/// let departments = ["경영학부", "컴퓨터학부"];
+/// assert_eq!(closest_department("경엉학부", departments), Some("경영학부"));
+/// assert_eq!(closest_department("음악대학", departments), None);
+/// assert_eq!(closest_department("경영학부", departments), None);
+/// ```
+pub fn closest_department<'a>(
+    name: &str,
+    candidates: impl IntoIterator<Item = &'a str>,
+) -> Option<&'a str> {
+    let length = name.chars().count();
+    candidates
+        .into_iter()
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .map(|candidate| (levenshtein(name, candidate), candidate))
+        .filter(|(distance, _)| (1..=MAX_FUZZY_DISTANCE).contains(distance) && *distance < length)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
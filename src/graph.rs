@@ -0,0 +1,74 @@
+//! # 학부-학과 계층 그래프
+//!
+//! DB의 `departments` 항목을 바탕으로 학부 → 학과 관계를 Graphviz의 dot 형식으로 만든다.
+//! `dot -Tsvg departments.dot -o departments.svg`와 같이 이미지로 바꾸어 조직 개편 여부를 확인하는 데 사용한다.
+use std::collections::BTreeMap;
+
+use serde_json::Value;
+
+/// dot 문서의 따옴표로 감싼 식별자에 넣을 수 있도록 문자열을 변환하는 메서드
+///
+/// ## Examples
+/// ```
+/// use suwon_mate_admin_tool::graph::escape_id;
+/// assert_eq!(escape_id(r#"경영"학부"#), r#""경영\"학부""#);
+/// assert_eq!(escape_id(r"a\b"), r#""a\\b""#);
+/// ```
+pub fn escape_id(text: &str) -> String {
+    format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// DB로 학부 → 학과 관계를 나타내는 dot 문서를 만드는 메서드
+///
+/// 학부와 학과는 이름 순으로 정렬되며, 학과가 없는 학부는 연결선 없이 학부 노드만 기록된다.
+/// 빠른 모드로 제작된 DB는 `departments_quick` 항목을 사용한다.
+///
+/// ## Examples
+/// ```
+/// use serde_json::json;
+/// use suwon_mate_admin_tool::graph::departments_dot;
+/// let db = json!({
+///     "departments": {"경영학부": ["회계학과", "경영학과"], "자유전공학부": []},
+///     "version": {"db_ver": "1.0"}
+/// });
+/// let dot = departments_dot(&db);
+/// assert!(dot.starts_with("digraph departments {"));
+/// assert!(dot.contains("label=\"DB 버전 1.0\""));
+/// assert!(dot.contains("\"경영학부\" -> \"경영학과\";\n    \"경영학부\" -> \"회계학과\";"));
+/// assert!(dot.contains("\"자유전공학부\" [shape=folder];"));
+/// ```
+pub fn departments_dot(db: &Value) -> String {
+    let departments = db["departments"]
+        .as_object()
+        .or_else(|| db["departments_quick"].as_object());
+    let departments: BTreeMap<&str, Vec<&str>> = departments
+        .into_iter()
+        .flatten()
+        .map(|(department, majors)| {
+            let mut majors: Vec<&str> = majors
+                .as_array()
+                .into_iter()
+                .flatten()
+                .filter_map(Value::as_str)
+                .collect();
+            majors.sort_unstable();
+            (department.as_str(), majors)
+        })
+        .collect();
+    let mut dot = String::from("digraph departments {\n    rankdir=LR;\n    node [shape=box];\n");
+    if let Some(db_version) = db["version"]["db_ver"].as_str() {
+        dot.push_str(&format!(
+            "    label={};\n",
+            escape_id(&format!("DB 버전 {}", db_version))
+        ));
+    }
+    for (department, majors) in departments {
+        let department = escape_id(department);
+        dot.push_str(&format!("    {} [shape=folder];\n", department));
+        for major in majors {
+            dot.push_str(&format!("    {} -> {};\n", department, escape_id(major)));
+        }
+    }
+    dot.push_str("}\n");
+    dot
+}
@@ -0,0 +1,113 @@
+//! # 실행 기록
+//!
+//! 감사 및 사고 조사를 위해 언제 누가 어떤 인자로 DB를 제작했는지 JSON Lines 형식의 기록 파일에 한 줄씩 남긴다.
+//! 기록은 실행이 끝난 뒤 결과(성공 여부와 오류 메세지)와 함께 추가되며, 기존 기록은 수정하지 않는다.
+//!
+//! 기록 파일은 실행한 디렉토리와 관계없이 사용자 데이터 디렉토리([default_history_file])에 모인다.
+use std::error::Error;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde_json::{json, Value};
+
+use crate::template::{timestamp, KST_OFFSET_SECONDS};
+
+/// 사용자 데이터 디렉토리 안에서 사용하는 디렉토리 이름
+pub const DATA_DIR_NAME: &str = "suwon_mate_admin_tool";
+/// 실행 기록 파일 이름
+pub const HISTORY_FILE: &str = "history.jsonl";
+
+/// 기본 실행 기록 파일의 경로를 구하는 메서드
+///
+/// `XDG_DATA_HOME`이 지정되어 있으면 그 아래를, 그렇지 않으면 홈 디렉토리의 `.local/share` 아래를 사용한다.
+///
+/// ## Errors
+/// 홈 디렉토리를 찾을 수 없는 경우 오류가 발생한다.
+pub fn default_history_file() -> Result<PathBuf, Box<dyn Error>> {
+    let base = match std::env::var_os("XDG_DATA_HOME") {
+        Some(directory) if !directory.is_empty() => PathBuf::from(directory),
+        _ => std::env::var_os("HOME")
+            .or_else(|| std::env::var_os("USERPROFILE"))
+            .map(|home| PathBuf::from(home).join(".local").join("share"))
+            .ok_or("홈 디렉토리를 찾을 수 없어 실행 기록 파일의 위치를 정할 수 없습니다.")?,
+    };
+    Ok(base.join(DATA_DIR_NAME).join(HISTORY_FILE))
+}
+
+/// UNIX 시각을 한국 표준시 기준 RFC 3339 형태의 시각으로 바꾸는 메서드
+///
+/// ## Examples
+/// ```
+/// use suwon_mate_admin_tool::history::kst_timestamp;
+/// assert_eq!(kst_timestamp(0), "1970-01-01T09:00:00+09:00");
+/// assert_eq!(kst_timestamp(1_709_218_800), "2024-03-01T00:00:00+09:00");
+/// ```
+pub fn kst_timestamp(unix_seconds: i64) -> String {
+    timestamp(unix_seconds, KST_OFFSET_SECONDS)
+}
+
+/// 실행한 사용자의 이름을 구하는 메서드
+///
+/// `USER`(Unix 계열) 또는 `USERNAME`(Windows) 환경 변수를 사용하며, 둘 다 없는 경우 `None`을 반환한다.
+pub fn current_user() -> Option<String> {
+    std::env::var("USER")
+        .or_else(|_| std::env::var("USERNAME"))
+        .ok()
+}
+
+/// 실행 기록 한 줄의 내용을 만드는 메서드
+///
+/// ## Arguments
+///
+/// * `started_at` - 실행을 시작한 UNIX 시각
+/// * `user` - 실행한 사용자
+/// * `args` - 프로그램 이름을 제외한 명령줄 인자
+/// * `result` - 실행 결과 (실패한 경우 오류 메세지)
+///
+/// ## Examples
+/// ```
+/// use suwon_mate_admin_tool::history::history_entry;
+/// let entry = history_entry(0, Some("admin"), &["verify", "result_1.0.json"], Err("서명이 일치하지 않습니다."));
+/// assert_eq!(entry["user"], "admin");
+/// assert_eq!(entry["args"][0], "verify");
+/// assert_eq!(entry["success"], false);
+/// assert_eq!(entry["error"], "서명이 일치하지 않습니다.");
+/// ```
+pub fn history_entry<S: AsRef<str>>(
+    started_at: i64,
+    user: Option<&str>,
+    args: &[S],
+    result: Result<(), &str>,
+) -> Value {
+    let finished_at = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(started_at, |duration| duration.as_secs() as i64);
+    json!({
+        "started_at": kst_timestamp(started_at),
+        "finished_at": kst_timestamp(finished_at),
+        "user": user,
+        "args": args.iter().map(AsRef::as_ref).collect::<Vec<&str>>(),
+        "success": result.is_ok(),
+        "error": result.err(),
+    })
+}
+
+/// 실행 기록 파일에 한 줄을 추가하는 메서드
+///
+/// 파일이나 파일이 들어갈 디렉토리가 없으면 새로 만든다.
+///
+/// ## Errors
+/// 파일을 열거나 쓰는 과정에서 문제가 발생한 경우 오류가 발생한다.
+pub fn append_history(path: &Path, entry: &Value) -> Result<(), Box<dyn Error>> {
+    if let Some(directory) = path
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+    {
+        fs::create_dir_all(directory)?;
+    }
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    writeln!(file, "{}", entry)?;
+    Ok(())
+}
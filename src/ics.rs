@@ -0,0 +1,236 @@
+//! # 시간표 iCalendar 내보내기
+//!
+//! 과목 정보의 시간표 문자열을 해석하여 학과나 교수의 강의 일정을 주 단위 반복 일정의 ICS 파일로 만든다.
+//! 교시는 1교시가 9시에 시작하여 50분 동안 진행되고, 이후 교시는 한 시간씩 늦게 시작하는 것으로 계산한다.
+//! 이어지는 교시는 하나의 일정으로 합쳐지며, 모든 시각은 한국 표준시(`Asia/Seoul`) 기준이다.
+use std::error::Error;
+
+use serde_json::Value;
+
+use crate::calendar::is_date;
+use crate::output::subjects;
+use crate::subject_key;
+use crate::template::{civil_from_days, days_from_civil};
+use crate::timetable::{TimetableDictionary, Weekday};
+
+/// 1교시가 시작하는 시각
+pub const FIRST_PERIOD_HOUR: u32 = 9;
+/// 한 교시의 수업 시간(분)
+pub const PERIOD_MINUTES: u32 = 50;
+
+/// ICS 파일로 내보낼 일정의 범위
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct IcsOptions {
+    /// 개설 학부명 또는 학과명이 일치하는 과목만 포함 (`None`인 경우 모든 학부)
+    pub department: Option<String>,
+    /// 교수명이 일치하는 과목만 포함 (`None`인 경우 모든 교수)
+    pub professor: Option<String>,
+    /// 학기 시작일 (`YYYY-MM-DD`)
+    pub start: String,
+    /// 반복할 주의 수
+    pub weeks: u32,
+}
+
+/// iCalendar 문자열 값의 특수 문자를 이스케이프하는 메서드
+///
+/// ## Examples
+/// ```
+/// use suwon_mate_admin_tool::ics::escape_text;
+/// assert_eq!(escape_text("회계원리; 기초, 1\n2"), "회계원리\\; 기초\\, 1\\n2");
+/// ```
+pub fn escape_text(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace('\n', "\\n")
+}
+
+/// 한 줄이 75바이트를 넘지 않도록 접는 메서드
+fn fold_line(line: &str) -> String {
+    let mut folded = String::new();
+    let mut length = 0;
+    for character in line.chars() {
+        if length + character.len_utf8() > 75 {
+            folded.push_str("\r\n ");
+            length = 1;
+        }
+        folded.push(character);
+        length += character.len_utf8();
+    }
+    folded.push_str("\r\n");
+    folded
+}
+
+/// 교시 목록을 이어지는 교시끼리 묶는 메서드
+///
+/// ## Examples
+/// ```
+/// use suwon_mate_admin_tool::ics::period_runs;
+/// assert_eq!(period_runs(&[6, 1, 2, 3]), vec![(1, 3), (6, 6)]);
+/// ```
+pub fn period_runs(periods: &[u32]) -> Vec<(u32, u32)> {
+    let mut periods = periods.to_vec();
+    periods.sort_unstable();
+    periods.dedup();
+    let mut runs: Vec<(u32, u32)> = vec![];
+    for period in periods {
+        match runs.last_mut() {
+            Some((_, last)) if *last + 1 == period => *last = period,
+            _ => runs.push((period, period)),
+        }
+    }
+    runs
+}
+
+/// `YYYY-MM-DD` 형태의 날짜를 1970년 1월 1일로부터 지난 일수로 바꾸는 메서드
+fn parse_date(text: &str) -> Option<i64> {
+    if !is_date(text) {
+        return None;
+    }
+    Some(days_from_civil(
+        text[..4].parse().ok()?,
+        text[5..7].parse().ok()?,
+        text[8..].parse().ok()?,
+    ))
+}
+
+/// 1970년 1월 1일로부터 지난 일수를 `YYYYMMDD` 형태로 바꾸는 메서드
+fn format_date(days: i64) -> String {
+    let (year, month, day) = civil_from_days(days);
+    format!("{:04}{:02}{:02}", year, month, day)
+}
+
+/// 과목이 내보낼 범위에 포함되는지 확인하는 메서드
+fn is_selected(subject: &Value, options: &IcsOptions) -> bool {
+    let department = options.department.as_deref().is_none_or(|department| {
+        subject["estbDpmjNm"].as_str() == Some(department)
+            || subject["estbMjorNm"].as_str() == Some(department)
+    });
+    let professor = options
+        .professor
+        .as_deref()
+        .is_none_or(|professor| subject["ltrPrfsNm"].as_str() == Some(professor));
+    department && professor
+}
+
+/// DB의 시간표로 ICS 파일의 내용을 만드는 메서드
+///
+/// 일정으로 만들 수 없는 과목(시간표 해석 실패, `야간`처럼 교시가 없는 표기)은 건너뛰고, 그 사유를 함께 반환한다.
+///
+/// ## Arguments
+///
+/// * `db` - 시간표를 가져올 DB의 내용
+/// * `dictionary` - 시간표 문자열 해석에 사용할 사전
+/// * `options` - 내보낼 일정의 범위
+///
+/// ## Examples
+/// ```
+/// use serde_json::json;
+/// use suwon_mate_admin_tool::ics::{timetable_ics, IcsOptions};
+/// use suwon_mate_admin_tool::timetable::TimetableDictionary;
+/// let db = json!({"estbLectDtaiList": {"경영학부": [
+///     {"subjtCd": "1", "diclNo": "001", "subjtNm": "회계원리", "ltrPrfsNm": "홍길동", "estbDpmjNm": "경영학부", "timtSmryCn": "글경602(수1,2)"},
+///     {"subjtCd": "2", "diclNo": "001", "subjtNm": "재무관리", "ltrPrfsNm": "홍길동", "estbDpmjNm": "경영학부", "timtSmryCn": "토 야간"}
+/// ]}});
+/// let options = IcsOptions { department: None, professor: Some(String::from("홍길동")), start: String::from("2024-03-04"), weeks: 15 };
+/// let (ics, skipped) = timetable_ics(&db, &TimetableDictionary::default(), &options).unwrap();
+/// assert!(ics.contains("DTSTART;TZID=Asia/Seoul:20240306T090000\r\n"));
+/// assert!(ics.contains("DTEND;TZID=Asia/Seoul:20240306T105000\r\n"));
+/// assert!(ics.contains("RRULE:FREQ=WEEKLY;COUNT=15\r\n"));
+/// assert_eq!(skipped.len(), 1);
+/// ```
+///
+/// ## Errors
+/// 학기 시작일이 `YYYY-MM-DD` 형태가 아닌 경우 오류가 발생한다.
+pub fn timetable_ics(
+    db: &Value,
+    dictionary: &TimetableDictionary,
+    options: &IcsOptions,
+) -> Result<(String, Vec<String>), Box<dyn Error>> {
+    let start = parse_date(&options.start).ok_or("학기 시작일은 YYYY-MM-DD 형태여야 합니다.")?;
+    // 1970년 1월 1일은 목요일이다.
+    let start_weekday = (start + 3).rem_euclid(7);
+    let stamp = format!("{}T000000Z", crate::template::today());
+    let mut lines = vec![
+        String::from("BEGIN:VCALENDAR"),
+        String::from("VERSION:2.0"),
+        String::from("PRODID:-//suwon_mate_admin_tool//KO"),
+        String::from("CALSCALE:GREGORIAN"),
+        String::from("BEGIN:VTIMEZONE"),
+        String::from("TZID:Asia/Seoul"),
+        String::from("BEGIN:STANDARD"),
+        String::from("DTSTART:19700101T000000"),
+        String::from("TZOFFSETFROM:+0900"),
+        String::from("TZOFFSETTO:+0900"),
+        String::from("TZNAME:KST"),
+        String::from("END:STANDARD"),
+        String::from("END:VTIMEZONE"),
+    ];
+    let mut skipped = vec![];
+    for subject in subjects(db).filter(|subject| is_selected(subject, options)) {
+        let key = subject_key(subject);
+        let Some(text) = subject["timtSmryCn"].as_str() else {
+            skipped.push(format!("{}: 시간표가 없습니다.", key));
+            continue;
+        };
+        let times = match dictionary.parse(text) {
+            Ok(times) => times,
+            Err(error) => {
+                skipped.push(format!("{}: {}", key, error));
+                continue;
+            }
+        };
+        for time in times {
+            if time.periods.is_empty() {
+                skipped.push(format!(
+                    "{}: {}요일 {} 수업은 교시가 없어 일정으로 만들 수 없습니다.",
+                    key,
+                    time.day,
+                    time.named_slot.as_deref().unwrap_or_default()
+                ));
+                continue;
+            }
+            let weekday = Weekday::ALL
+                .iter()
+                .position(|day| *day == time.day)
+                .unwrap_or_default() as i64;
+            let date = format_date(start + (weekday - start_weekday).rem_euclid(7));
+            for (first, last) in period_runs(&time.periods) {
+                let begin = FIRST_PERIOD_HOUR + first - 1;
+                let end_minutes = (FIRST_PERIOD_HOUR + last - 1) * 60 + PERIOD_MINUTES;
+                let summary = match subject["ltrPrfsNm"].as_str() {
+                    Some(professor) => format!(
+                        "{} ({})",
+                        subject["subjtNm"].as_str().unwrap_or_default(),
+                        professor
+                    ),
+                    None => subject["subjtNm"].as_str().unwrap_or_default().to_string(),
+                };
+                lines.extend([
+                    String::from("BEGIN:VEVENT"),
+                    format!("UID:{}-{}-{}@suwon-mate", key, date, first),
+                    format!("DTSTAMP:{}", stamp),
+                    format!("DTSTART;TZID=Asia/Seoul:{}T{:02}0000", date, begin),
+                    format!(
+                        "DTEND;TZID=Asia/Seoul:{}T{:02}{:02}00",
+                        date,
+                        end_minutes / 60,
+                        end_minutes % 60
+                    ),
+                    format!("RRULE:FREQ=WEEKLY;COUNT={}", options.weeks),
+                    format!("SUMMARY:{}", escape_text(&summary)),
+                    format!(
+                        "DESCRIPTION:{}",
+                        escape_text(&format!("과목 키: {}, 시간표: {}", key, text))
+                    ),
+                ]);
+                if let Some(room) = &time.room {
+                    lines.push(format!("LOCATION:{}", escape_text(room)));
+                }
+                lines.push(String::from("END:VEVENT"));
+            }
+        }
+    }
+    lines.push(String::from("END:VCALENDAR"));
+    Ok((lines.iter().map(|line| fold_line(line)).collect(), skipped))
+}
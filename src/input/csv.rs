@@ -0,0 +1,44 @@
+//! # CSV 입력
+//!
+//! 학교 행정실에서 CSV로 받은 개설강좌 목록을 [ColumnMapping]에 따라 `estbLectDtaiList` 형태의 입력 내용으로 바꾼다.
+use std::error::Error;
+
+use serde_json::Value;
+
+use crate::input::mapping::ColumnMapping;
+
+/// CSV 내용을 `{"estbLectDtaiList": [...]}` 형태의 입력 내용으로 바꾸는 메서드
+///
+/// 첫 행은 헤더로 사용하며, 파일 앞의 UTF-8 BOM은 무시한다. 열 개수가 헤더와 다른 행도 읽을 수 있다.
+///
+/// ## Examples
+/// ```
+/// use serde_json::json;
+/// use suwon_mate_admin_tool::input::csv::csv_to_input;
+/// use suwon_mate_admin_tool::input::mapping::ColumnMapping;
+/// let mapping = ColumnMapping::from_yaml("columns:\n  subjtCd: 과목코드\n  subjtNm: 교과목명\n").unwrap();
+/// let content = "\u{feff}과목코드,교과목명,diclNo\n1,\"경영학원론, 1\",001\n";
+/// assert_eq!(
+///     csv_to_input(content, &mapping).unwrap(),
+///     json!({"estbLectDtaiList": [{"subjtCd": "1", "subjtNm": "경영학원론, 1", "diclNo": "001"}]})
+/// );
+/// assert!(csv_to_input("교과목명\n경영학원론\n", &mapping).is_err());
+/// ```
+///
+/// ## Errors
+/// CSV 해독이 불가능하거나 매핑에 적힌 헤더가 CSV에 없는 경우 오류가 발생한다.
+pub fn csv_to_input(content: &str, mapping: &ColumnMapping) -> Result<Value, Box<dyn Error>> {
+    let mut reader = ::csv::ReaderBuilder::new()
+        .flexible(true)
+        .from_reader(content.trim_start_matches('\u{feff}').as_bytes());
+    let headers: Vec<String> = reader
+        .headers()?
+        .iter()
+        .map(|header| header.trim().to_string())
+        .collect();
+    let rows = reader
+        .records()
+        .map(|record| Ok(record?.iter().map(str::to_string).collect()))
+        .collect::<Result<Vec<Vec<String>>, ::csv::Error>>()?;
+    mapping.to_subject_list(&headers, rows)
+}
@@ -0,0 +1,89 @@
+//! # 데이터 세트
+//!
+//! 학부, 대학원, 계절학기 개설강좌는 같은 포털에서 받더라도 과목 정보의 필드 구성이 일부 다르다.
+//! 데이터 세트마다 다른 필드의 값을 학부 개설강좌의 필드 이름(`estbDpmjNm` 등)으로 옮겨,
+//! 이후의 DB 제작은 데이터 세트와 관계없이 같은 경로로 진행되도록 한다.
+//!
+//! 학부 필드에 이미 값이 있으면 덮어쓰지 않는다.
+use clap::ValueEnum;
+use serde_json::Value;
+
+use crate::input::SUBJECT_LIST_KEY;
+
+/// 입력 파일의 데이터 세트
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default, ValueEnum)]
+pub enum Dataset {
+    /// 학부 개설강좌
+    #[default]
+    Undergrad,
+    /// 대학원 개설강좌 (대학원명을 학부로, 학위과정 전공을 학과로 사용)
+    Grad,
+    /// 계절학기 개설강좌 (개설 학부가 비어있으면 주관 학과를 학부로 사용)
+    Seasonal,
+}
+
+impl Dataset {
+    /// 데이터 세트의 이름
+    pub fn name(&self) -> &'static str {
+        match self {
+            Dataset::Undergrad => "학부",
+            Dataset::Grad => "대학원",
+            Dataset::Seasonal => "계절학기",
+        }
+    }
+
+    /// 학부 필드 이름과 그 값을 가져올 필드 이름 목록 (앞의 필드가 우선한다)
+    pub fn field_aliases(&self) -> &'static [(&'static str, &'static [&'static str])] {
+        match self {
+            Dataset::Undergrad => &[],
+            Dataset::Grad => &[
+                ("estbDpmjNm", &["estbGrscNm", "grscNm"]),
+                ("estbMjorNm", &["estbDgrMjorNm", "dgrMjorNm"]),
+                ("trgtGrdeCd", &["trgtSmstCd"]),
+            ],
+            Dataset::Seasonal => &[("estbDpmjNm", &["deptNm"]), ("timtSmryCn", &["lssnPrdCn"])],
+        }
+    }
+
+    /// 과목 목록의 각 과목에 [Dataset::field_aliases]에 따라 학부 필드를 채우는 메서드
+    ///
+    /// 값을 채운 필드의 수를 반환한다.
+    ///
+    /// ## Examples
+    /// ```
+    /// use serde_json::json;
+    /// use suwon_mate_admin_tool::input::dataset::Dataset;
+    /// let mut input = json!({"estbLectDtaiList": [
+    ///     {"subjtCd": "G1", "estbGrscNm": "일반대학원", "estbDgrMjorNm": "컴퓨터학과"},
+    ///     {"subjtCd": "G2", "estbDpmjNm": "교육대학원", "estbGrscNm": "일반대학원"},
+    /// ]});
+    /// assert_eq!(Dataset::Grad.apply(&mut input), 2);
+    /// assert_eq!(input["estbLectDtaiList"][0]["estbDpmjNm"], "일반대학원");
+    /// assert_eq!(input["estbLectDtaiList"][0]["estbMjorNm"], "컴퓨터학과");
+    /// assert_eq!(input["estbLectDtaiList"][1]["estbDpmjNm"], "교육대학원");
+    /// assert_eq!(Dataset::Undergrad.apply(&mut input), 0);
+    /// ```
+    pub fn apply(&self, input: &mut Value) -> usize {
+        let Some(subjects) = input[SUBJECT_LIST_KEY].as_array_mut() else {
+            return 0;
+        };
+        let mut filled = 0;
+        for subject in subjects.iter_mut().filter_map(Value::as_object_mut) {
+            for (field, aliases) in self.field_aliases() {
+                if subject.get(*field).is_some_and(|value| !value.is_null()) {
+                    continue;
+                }
+                let value = aliases
+                    .iter()
+                    .filter_map(|alias| subject.get(*alias))
+                    .find(|value| !value.is_null())
+                    .cloned();
+                if let Some(value) = value {
+                    subject.insert(field.to_string(), value);
+                    filled += 1;
+                }
+            }
+        }
+        filled
+    }
+}
@@ -0,0 +1,146 @@
+//! # 입력 매핑
+//!
+//! 입력 파일에서 과목 목록을 찾는 키와 CSV 등 표 형태의 입력 파일의 열 이름(헤더)을 `estbLectDtaiList` 과목 정보의 필드 이름으로 바꾸는 설정이다.
+//!
+//! 매핑 설정은 다음과 같은 YAML 파일로 지정한다. `columns`에는 `필드 이름: 헤더` 형태로 적으며,
+//! 매핑에 없는 헤더는 헤더 이름을 그대로 필드 이름으로 사용한다. `sheet`는 XLSX 입력에서 읽을 시트 이름이며,
+//! `record`는 XML 입력에서 과목 하나에 해당하는 요소의 이름이다.
+//! `root_key`는 JSON 입력에서 과목 목록이 들어있는 키로, 포털 API 종류에 따라 다른 키를 파일마다 지정하지 않아도 되도록 한다.
+//!
+//! ```yaml
+//! root_key: data.list
+//! sheet: 개설강좌
+//! columns:
+//!   subjtCd: 과목코드
+//!   diclNo: 분반
+//!   subjtNm: 교과목명
+//!   ltrPrfsNm: 담당교수
+//! ```
+use std::collections::BTreeMap;
+use std::error::Error;
+
+use serde::Deserialize;
+use serde_json::{Map, Value};
+
+use crate::input::SUBJECT_LIST_KEY;
+
+/// 표 형태 입력 파일의 열 매핑 설정
+#[derive(Deserialize, Clone, PartialEq, Eq, Debug, Default)]
+pub struct ColumnMapping {
+    /// JSON 입력에서 과목 목록이 들어있는 키 ([crate::input::normalize_root_key] 참고)
+    #[serde(default)]
+    pub root_key: Option<String>,
+    /// XLSX 입력에서 읽을 시트 이름 (없으면 첫 시트)
+    #[serde(default)]
+    pub sheet: Option<String>,
+    /// XML 입력에서 과목 하나에 해당하는 요소의 이름 (없으면 자동 탐색)
+    #[serde(default)]
+    pub record: Option<String>,
+    /// 필드 이름별로 값을 가져올 헤더
+    #[serde(default)]
+    pub columns: BTreeMap<String, String>,
+}
+
+impl ColumnMapping {
+    /// YAML 형식의 매핑 설정을 해독하는 메서드
+    ///
+    /// ## Examples
+    /// ```
+    /// use suwon_mate_admin_tool::input::mapping::ColumnMapping;
+    /// let mapping = ColumnMapping::from_yaml("root_key: data.list\ncolumns:\n  subjtCd: 과목코드\n").unwrap();
+    /// assert_eq!(mapping.root_key.as_deref(), Some("data.list"));
+    /// assert_eq!(mapping.columns["subjtCd"], "과목코드");
+    /// assert!(ColumnMapping::from_yaml("columns: [1, 2]").is_err());
+    /// ```
+    ///
+    /// ## Errors
+    /// YAML 해독이 불가능하거나 `columns`가 문자열 사이의 매핑이 아닌 경우 오류가 발생한다.
+    pub fn from_yaml(content: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(serde_yaml::from_str(content)?)
+    }
+
+    /// 헤더 목록의 각 열을 기록할 필드 이름 목록을 구하는 메서드
+    ///
+    /// ## Examples
+    /// ```
+    /// use suwon_mate_admin_tool::input::mapping::ColumnMapping;
+    /// let mapping = ColumnMapping::from_yaml("columns:\n  subjtCd: 과목코드\n").unwrap();
+    /// let headers = vec!["과목코드".to_string(), "diclNo".to_string()];
+    /// assert_eq!(mapping.fields(&headers).unwrap(), vec!["subjtCd", "diclNo"]);
+    /// assert!(mapping.fields(&["diclNo".to_string()]).is_err());
+    /// ```
+    ///
+    /// ## Errors
+    /// 매핑에 적힌 헤더가 헤더 목록에 없는 경우 오류가 발생한다.
+    pub fn fields(&self, headers: &[String]) -> Result<Vec<String>, Box<dyn Error>> {
+        if let Some(header) = self
+            .columns
+            .values()
+            .find(|header| !headers.contains(header))
+        {
+            return Err(format!("입력 파일에 '{}' 열이 없습니다.", header).into());
+        }
+        Ok(headers
+            .iter()
+            .map(|header| {
+                self.columns
+                    .iter()
+                    .find(|(_, column)| *column == header)
+                    .map_or(header, |(field, _)| field)
+                    .clone()
+            })
+            .collect())
+    }
+
+    /// 헤더와 행 목록을 `{"estbLectDtaiList": [...]}` 형태의 입력 내용으로 바꾸는 메서드
+    ///
+    /// 값은 앞뒤 공백을 제거한 문자열로 기록하며, 빈 칸은 `null`로 기록한다. 모든 칸이 빈 행은 건너뛴다.
+    ///
+    /// ## Examples
+    /// ```
+    /// use serde_json::json;
+    /// use suwon_mate_admin_tool::input::mapping::ColumnMapping;
+    /// let mapping = ColumnMapping::from_yaml("columns:\n  subjtCd: 과목코드\n").unwrap();
+    /// let headers = vec!["과목코드".to_string(), "ltrPrfsNm".to_string()];
+    /// let rows = vec![
+    ///     vec![" 1 ".to_string(), "".to_string()],
+    ///     vec!["".to_string(), "".to_string()],
+    /// ];
+    /// assert_eq!(
+    ///     mapping.to_subject_list(&headers, rows).unwrap(),
+    ///     json!({"estbLectDtaiList": [{"subjtCd": "1", "ltrPrfsNm": null}]})
+    /// );
+    /// ```
+    ///
+    /// ## Errors
+    /// 매핑에 적힌 헤더가 헤더 목록에 없는 경우 오류가 발생한다.
+    pub fn to_subject_list<I>(&self, headers: &[String], rows: I) -> Result<Value, Box<dyn Error>>
+    where
+        I: IntoIterator<Item = Vec<String>>,
+    {
+        let fields = self.fields(headers)?;
+        let subjects: Vec<Value> = rows
+            .into_iter()
+            .filter(|row| row.iter().any(|cell| !cell.trim().is_empty()))
+            .map(|row| {
+                let subject: Map<String, Value> = fields
+                    .iter()
+                    .zip(
+                        row.iter()
+                            .map(|cell| cell.trim())
+                            .chain(std::iter::repeat("")),
+                    )
+                    .map(|(field, cell)| {
+                        let value = match cell {
+                            "" => Value::Null,
+                            cell => Value::String(cell.to_string()),
+                        };
+                        (field.clone(), value)
+                    })
+                    .collect();
+                Value::Object(subject)
+            })
+            .collect();
+        Ok(serde_json::json!({ SUBJECT_LIST_KEY: subjects }))
+    }
+}
@@ -0,0 +1,997 @@
+//! # 입력 파일
+//!
+//! 개설 강좌 조회 DB 파일과 강의 계획서 DB 파일을 읽어 [crate::make_db_content]에 전달할 내용을 만드는 기능을 모아둔 모듈이다.
+use std::collections::HashSet;
+use std::error::Error;
+use std::fs::{self, File};
+use std::io::{BufRead, IsTerminal, Read, Seek, SeekFrom, Write};
+use std::ops::Deref;
+use std::path::{Path, PathBuf};
+
+use clap::ValueEnum;
+use memmap2::Mmap;
+use serde_json::Value;
+
+use crate::cache::Cache;
+use crate::input::csv::csv_to_input;
+use crate::input::dataset::Dataset;
+use crate::input::mapping::ColumnMapping;
+use crate::input::xlsx::xlsx_to_input;
+use crate::input::xml::xml_to_input;
+use crate::pull::fetch;
+use crate::subject_key;
+
+pub mod csv;
+pub mod dataset;
+pub mod mapping;
+pub mod stream;
+pub mod xlsx;
+pub mod xml;
+
+/// 과목 목록이 들어있는 최상위 키
+pub const SUBJECT_LIST_KEY: &str = "estbLectDtaiList";
+/// 표준입력을 나타내는 입력 파일 경로
+pub const STDIN_PATH: &str = "-";
+
+/// 입력 파일의 문자 인코딩
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default, ValueEnum)]
+pub enum InputEncoding {
+    /// UTF-8로 해독할 수 없으면 CP949로 해독
+    #[default]
+    Auto,
+    /// UTF-8
+    Utf8,
+    /// CP949 (EUC-KR 포함)
+    Cp949,
+}
+
+/// 읽어들이거나 메모리에 매핑한 입력 파일의 내용
+pub enum InputBytes {
+    /// 메모리로 읽어들인 내용
+    Owned(Vec<u8>),
+    /// 메모리에 매핑한 파일
+    Mapped(Mmap),
+}
+
+impl Deref for InputBytes {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        match self {
+            InputBytes::Owned(content) => content,
+            InputBytes::Mapped(map) => map,
+        }
+    }
+}
+
+/// 입력 파일을 메모리에 매핑하는 메서드
+///
+/// 파일 내용을 복사하지 않고 매핑한 영역을 그대로 해독하므로 수백 MB의 입력 파일도 파일 크기만큼의 메모리를 더 쓰지 않는다.
+/// 표준입력과 압축 파일(`.gz`, `.zip`)은 매핑할 수 없으므로 [read_file]로 읽는다.
+///
+/// ## Examples
+/// ```
+/// use suwon_mate_admin_tool::input::map_file;
+/// let path = std::env::temp_dir().join("suwon_mate_map_file_doctest.json");
+/// std::fs::write(&path, "{}").unwrap();
+/// assert_eq!(&*map_file(&path).unwrap(), b"{}");
+/// ```
+///
+/// ## Errors
+/// 파일이 존재하지 않거나 매핑할 수 없는 경우 오류가 발생한다.
+pub fn map_file(path: &Path) -> Result<InputBytes, Box<dyn Error>> {
+    let compressed = path
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .is_some_and(|extension| ["gz", "zip"].contains(&extension.to_ascii_lowercase().as_str()));
+    if path == Path::new(STDIN_PATH) || compressed {
+        return Ok(InputBytes::Owned(read_file(path)?));
+    }
+    let file = File::open(path)?;
+    // SAFETY: 매핑은 이 함수가 반환한 값이 살아있는 동안만 사용되며, 빌드 도중 입력 파일을 고치지 않는다는 전제로 사용한다.
+    // (--mmap-input 옵션 설명 참고)
+    let map = unsafe { Mmap::map(&file)? };
+    Ok(InputBytes::Mapped(map))
+}
+
+/// 입력 내용을 JSON으로 해독하는 메서드
+///
+/// [parse_json_bytes]와 같으며, 문자열의 버퍼를 그대로 넘기므로 내용을 복사하지 않는다.
+///
+/// ## Examples
+/// ```
+/// use suwon_mate_admin_tool::input::parse_json;
+/// let content = r#"{"estbLectDtaiList": [{"subjtCd": "11416", "point": 3}]}"#.to_string();
+/// let value = parse_json(content).unwrap();
+/// assert_eq!(value["estbLectDtaiList"][0]["point"], 3);
+/// assert!(parse_json("{".to_string()).is_err());
+/// ```
+///
+/// ## Errors
+/// JSON 해독이 불가능한 경우 오류가 발생한다.
+pub fn parse_json(content: String) -> Result<Value, Box<dyn Error>> {
+    parse_json_bytes(content.into_bytes())
+}
+
+/// 읽어들인 입력 파일의 내용을 JSON으로 해독하는 메서드
+///
+/// `simd-json` 기능을 켜고 빌드하면 SIMD 명령을 사용하는 simd-json으로 해독하며, 그렇지 않으면 serde_json으로 해독한다.
+/// simd-json은 해독하면서 내용을 고쳐 쓰므로 내용의 소유권을 받아 복사 없이 그 자리에서 해독한다.
+/// 두 경우 모두 같은 `Value`를 반환한다.
+///
+/// ## Examples
+/// ```
+/// use suwon_mate_admin_tool::input::parse_json_bytes;
+/// let value = parse_json_bytes(br#"{"subjtNm": "\uacbd\uc601\ud559", "point": 3.5}"#.to_vec()).unwrap();
+/// assert_eq!(value["subjtNm"], "경영학");
+/// assert_eq!(value["point"], 3.5);
+/// assert!(parse_json_bytes(b"{".to_vec()).is_err());
+/// ```
+///
+/// ## Errors
+/// JSON 해독이 불가능한 경우 오류가 발생한다.
+pub fn parse_json_bytes(content: Vec<u8>) -> Result<Value, Box<dyn Error>> {
+    #[cfg(feature = "simd-json")]
+    {
+        let mut content = content;
+        Ok(simd_json::serde::from_slice(&mut content)?)
+    }
+    #[cfg(not(feature = "simd-json"))]
+    {
+        Ok(serde_json::from_slice(&content)?)
+    }
+}
+
+/// 입력 파일의 내용을 읽는 메서드
+///
+/// 확장자가 `.gz`인 파일은 gzip 압축을 풀어 읽고, `.zip`인 파일은 압축 파일 안의 JSON 파일을 찾아 읽는다.
+/// zip 파일 안에 JSON 파일이 하나뿐이어야 하며, JSON 파일이 없으면 압축 파일 안의 유일한 파일을 읽는다.
+/// 경로가 [STDIN_PATH](`-`)인 경우 표준입력의 내용을 끝까지 읽는다.
+///
+/// ## Examples
+/// ```
+/// use std::io::Write;
+/// use suwon_mate_admin_tool::input::read_file;
+/// let path = std::env::temp_dir().join("suwon_mate_read_file_doctest.json.gz");
+/// let mut encoder = flate2::write::GzEncoder::new(std::fs::File::create(&path).unwrap(), flate2::Compression::default());
+/// encoder.write_all(b"{}").unwrap();
+/// encoder.finish().unwrap();
+/// assert_eq!(read_file(&path).unwrap(), b"{}");
+///
+/// let path = std::env::temp_dir().join("suwon_mate_read_file_doctest.zip");
+/// let mut archive = zip::ZipWriter::new(std::fs::File::create(&path).unwrap());
+/// let options = zip::write::SimpleFileOptions::default().compression_method(zip::CompressionMethod::Stored);
+/// archive.start_file("readme.txt", options).unwrap();
+/// archive.write_all(b"readme").unwrap();
+/// archive.start_file("open_class.json", options).unwrap();
+/// archive.write_all(b"[]").unwrap();
+/// archive.finish().unwrap();
+/// assert_eq!(read_file(&path).unwrap(), b"[]");
+/// ```
+///
+/// ## Errors
+/// * 파일이 존재하지 않거나 읽을 수 없는 경우
+/// * 압축을 풀 수 없거나 zip 파일 안에서 읽을 파일을 하나로 정할 수 없는 경우
+pub fn read_file(path: &Path) -> Result<Vec<u8>, Box<dyn Error>> {
+    if path == Path::new(STDIN_PATH) {
+        let mut content = vec![];
+        std::io::stdin().lock().read_to_end(&mut content)?;
+        return Ok(content);
+    }
+    let extension = path
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .map(str::to_ascii_lowercase);
+    let mut content = vec![];
+    match extension.as_deref() {
+        Some("gz") => {
+            flate2::read::GzDecoder::new(File::open(path)?).read_to_end(&mut content)?;
+        }
+        Some("zip") => {
+            let mut archive = zip::ZipArchive::new(File::open(path)?)?;
+            let files: Vec<String> = archive
+                .file_names()
+                .filter(|name| !name.ends_with('/'))
+                .map(String::from)
+                .collect();
+            let json_files: Vec<&String> = files
+                .iter()
+                .filter(|name| name.to_ascii_lowercase().ends_with(".json"))
+                .collect();
+            let file = match (json_files.as_slice(), files.as_slice()) {
+                ([file], _) => (*file).clone(),
+                ([], [file]) => file.clone(),
+                _ => {
+                    return Err(format!(
+                        "{} 안에서 읽을 JSON 파일을 하나로 정할 수 없습니다: {}",
+                        path.display(),
+                        files.join(", ")
+                    )
+                    .into())
+                }
+            };
+            archive.by_name(&file)?.read_to_end(&mut content)?;
+        }
+        _ => content = fs::read(path)?,
+    }
+    Ok(content)
+}
+
+/// 입력 파일을 읽기 전에 [read_file]로 읽을 내용의 크기를 구하는 메서드
+///
+/// `.gz` 파일은 파일 끝에 기록된 원본 크기를, `.zip` 파일은 압축 파일 안의 모든 파일의 원본 크기의 합을 사용하며,
+/// 그 외의 파일은 파일 크기를 사용한다. URL과 표준입력은 미리 크기를 알 수 없으므로 0으로 계산한다.
+///
+/// ## Examples
+/// ```
+/// use std::io::Write;
+/// use std::path::Path;
+/// use suwon_mate_admin_tool::input::input_size;
+/// let path = std::env::temp_dir().join("suwon_mate_input_size_doctest.json");
+/// std::fs::write(&path, "{}").unwrap();
+/// assert_eq!(input_size(&path).unwrap(), 2);
+///
+/// let path = std::env::temp_dir().join("suwon_mate_input_size_doctest.json.gz");
+/// let mut encoder = flate2::write::GzEncoder::new(std::fs::File::create(&path).unwrap(), flate2::Compression::default());
+/// encoder.write_all(&[b' '; 1000]).unwrap();
+/// encoder.finish().unwrap();
+/// assert_eq!(input_size(&path).unwrap(), 1000);
+/// assert_eq!(input_size(Path::new("-")).unwrap(), 0);
+/// ```
+///
+/// ## Errors
+/// 파일이 존재하지 않거나 압축 파일의 형식이 올바르지 않은 경우 오류가 발생한다.
+pub fn input_size(path: &Path) -> Result<u64, Box<dyn Error>> {
+    if is_url(path) || path == Path::new(STDIN_PATH) {
+        return Ok(0);
+    }
+    let extension = path
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .map(str::to_ascii_lowercase);
+    match extension.as_deref() {
+        Some("gz") => {
+            let mut file = File::open(path)?;
+            file.seek(SeekFrom::End(-4))?;
+            let mut size = [0; 4];
+            file.read_exact(&mut size)?;
+            Ok(u32::from_le_bytes(size) as u64)
+        }
+        Some("zip") => {
+            let mut archive = zip::ZipArchive::new(File::open(path)?)?;
+            (0..archive.len())
+                .map(|index| Ok(archive.by_index_raw(index)?.size()))
+                .sum()
+        }
+        _ => Ok(fs::metadata(path)?.len()),
+    }
+}
+
+/// 입력 파일의 내용을 `encoding`에 따라 UTF-8 문자열로 바꾸는 메서드
+///
+/// 학교에서 받은 일부 파일은 CP949(EUC-KR)로 인코딩되어 있으므로, `Auto`인 경우 UTF-8로 해독할 수 없으면 CP949로 해독한다.
+///
+/// ## Arguments
+///
+/// * `content` - 입력 파일의 내용
+/// * `encoding` - 입력 파일의 문자 인코딩
+///
+/// ## Examples
+/// ```
+/// use suwon_mate_admin_tool::input::{decode_input, InputEncoding};
+/// let cp949 = [0xB0, 0xE6, 0xBF, 0xB5]; // "경영"
+/// assert_eq!(decode_input(&cp949, InputEncoding::Auto).unwrap(), "경영");
+/// assert_eq!(decode_input(&cp949, InputEncoding::Cp949).unwrap(), "경영");
+/// assert!(decode_input(&cp949, InputEncoding::Utf8).is_err());
+/// assert_eq!(decode_input("경영".as_bytes(), InputEncoding::Auto).unwrap(), "경영");
+/// ```
+///
+/// ## Errors
+/// 지정한 인코딩으로 해독할 수 없는 바이트가 있는 경우 오류가 발생한다.
+pub fn decode_input(content: &[u8], encoding: InputEncoding) -> Result<String, Box<dyn Error>> {
+    match (std::str::from_utf8(content), encoding) {
+        (Ok(content), InputEncoding::Auto | InputEncoding::Utf8) => Ok(content.to_string()),
+        (Err(error), InputEncoding::Utf8) => Err(format!(
+            "입력 파일을 UTF-8로 해독할 수 없습니다: {} (--input-encoding cp949를 지정해주세요)",
+            error
+        )
+        .into()),
+        (_, encoding) => {
+            let (content, had_errors) = encoding_rs::EUC_KR.decode_without_bom_handling(content);
+            if had_errors {
+                return Err(if encoding == InputEncoding::Auto {
+                    "입력 파일을 UTF-8 또는 CP949로 해독할 수 없습니다.".into()
+                } else {
+                    "입력 파일을 CP949로 해독할 수 없습니다.".into()
+                });
+            }
+            if encoding == InputEncoding::Auto {
+                notice!("입력 파일이 UTF-8이 아니므로 CP949로 해독합니다.");
+            }
+            Ok(content.into_owned())
+        }
+    }
+}
+
+/// JSON 토큰 사이에 올 수 있는 표준 공백 문자인지 확인하는 메서드
+fn is_json_whitespace(character: char) -> bool {
+    matches!(character, ' ' | '\t' | '\n' | '\r')
+}
+
+/// JSON 해독을 방해하는 문자(BOM, 제어 문자, 비표준 공백)인지 확인하는 메서드
+fn is_noise(character: char) -> bool {
+    character.is_whitespace()
+        || character.is_control()
+        || matches!(character, '\u{feff}' | '\u{200b}')
+}
+
+/// 입력 파일의 내용에서 JSON 해독을 방해하는 문자를 정리하는 메서드
+///
+/// 엑셀 등에서 저장한 파일에 흔히 붙는 다음 문자를 정리한다. 문자열 값 안의 문자는 건드리지 않는다.
+///
+/// * 내용 앞뒤의 BOM, 공백, 제어 문자는 제거한다.
+/// * 토큰 사이의 비표준 공백(줄바꿈 없는 공백, 전각 공백, 폭 없는 공백 등)과 제어 문자는 일반 공백으로 바꾼다.
+///
+/// 정리할 문자가 없는 경우 `None`을 반환한다.
+///
+/// ## Examples
+/// ```
+/// use suwon_mate_admin_tool::input::clean_json;
+/// let content = "\u{feff}\u{0}\n{\"estbLectDtaiList\":\u{a0}[{\"subjtNm\": \"회계\u{a0}원리\"}]}\u{3000}";
+/// let cleaned = clean_json(content).unwrap();
+/// assert_eq!(cleaned, "{\"estbLectDtaiList\": [{\"subjtNm\": \"회계\u{a0}원리\"}]}");
+/// assert!(serde_json::from_str::<serde_json::Value>(&cleaned).is_ok());
+/// assert_eq!(clean_json("{\"estbLectDtaiList\": []}\n"), None);
+/// ```
+pub fn clean_json(content: &str) -> Option<String> {
+    let trimmed = content.trim_matches(is_noise);
+    let has_noise = trimmed
+        .chars()
+        .any(|character| is_noise(character) && !is_json_whitespace(character));
+    if !has_noise && content.trim_matches(is_json_whitespace).len() == trimmed.len() {
+        return None;
+    }
+    let mut cleaned = String::with_capacity(trimmed.len());
+    let mut in_string = false;
+    let mut escaped = false;
+    for character in trimmed.chars() {
+        if in_string {
+            match character {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => in_string = false,
+                _ => {}
+            }
+            cleaned.push(character);
+        } else if is_noise(character) && !is_json_whitespace(character) {
+            cleaned.push(' ');
+        } else {
+            in_string = character == '"';
+            cleaned.push(character);
+        }
+    }
+    Some(cleaned)
+}
+
+/// 표준 JSON이 아닌 주석과 후행 쉼표를 제거하는 메서드
+///
+/// 사람이 직접 고친 입력 파일에 흔히 들어가는 `//` 한 줄 주석, `/* */` 블록 주석과 `}`, `]` 바로 앞의 쉼표를 제거한다.
+/// 문자열 값 안의 문자는 건드리지 않는다. 제거할 것이 없는 경우 `None`을 반환한다.
+///
+/// ## Examples
+/// ```
+/// use suwon_mate_admin_tool::input::strip_lenient_json;
+/// let content = "{\n  // 2024년 1학기\n  \"estbLectDtaiList\": [\n    {\"subjtNm\": \"http://a/*b*/\", /* 비고 */ \"point\": 3,},\n  ],\n}";
+/// let stripped = strip_lenient_json(content).unwrap();
+/// let value: serde_json::Value = serde_json::from_str(&stripped).unwrap();
+/// assert_eq!(value["estbLectDtaiList"][0]["subjtNm"], "http://a/*b*/");
+/// assert_eq!(value["estbLectDtaiList"][0]["point"], 3);
+/// assert_eq!(strip_lenient_json("{\"a\": \"//,]\"}"), None);
+/// ```
+pub fn strip_lenient_json(content: &str) -> Option<String> {
+    let characters: Vec<char> = content.chars().collect();
+    let mut stripped = String::with_capacity(content.len());
+    let mut changed = false;
+    let mut in_string = false;
+    let mut escaped = false;
+    let mut index = 0;
+    while index < characters.len() {
+        let character = characters[index];
+        let next = characters.get(index + 1).copied();
+        if in_string {
+            match character {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => in_string = false,
+                _ => {}
+            }
+            stripped.push(character);
+            index += 1;
+            continue;
+        }
+        match (character, next) {
+            ('/', Some('/')) => {
+                changed = true;
+                while index < characters.len() && characters[index] != '\n' {
+                    index += 1;
+                }
+                continue;
+            }
+            ('/', Some('*')) => {
+                changed = true;
+                index += 2;
+                while index < characters.len()
+                    && !(characters[index] == '*' && characters.get(index + 1) == Some(&'/'))
+                {
+                    index += 1;
+                }
+                stripped.push(' ');
+                index += 2;
+                continue;
+            }
+            _ => {}
+        }
+        in_string = character == '"';
+        stripped.push(character);
+        index += 1;
+    }
+    let characters: Vec<char> = stripped.chars().collect();
+    let mut result = String::with_capacity(stripped.len());
+    let mut in_string = false;
+    let mut escaped = false;
+    for (index, &character) in characters.iter().enumerate() {
+        if in_string {
+            match character {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => in_string = false,
+                _ => {}
+            }
+        } else if character == ',' {
+            let closes = characters[index + 1..]
+                .iter()
+                .find(|character| !is_json_whitespace(**character))
+                .is_some_and(|character| matches!(character, '}' | ']'));
+            if closes {
+                changed = true;
+                continue;
+            }
+        } else {
+            in_string = character == '"';
+        }
+        result.push(character);
+    }
+    changed.then_some(result)
+}
+
+/// 첫 페이지 파일로부터 이어지는 페이지 파일 목록을 찾는 메서드
+///
+/// 파일명이 숫자로 끝나는 경우(`page1.json`, `open_01.json` 등) 숫자를 하나씩 늘려가며 존재하는 파일을 모두 찾는다.
+/// 숫자 앞에 0이 채워진 경우 같은 자릿수를 유지한다. 첫 페이지 파일은 항상 목록의 처음에 포함된다.
+///
+/// ## Arguments
+///
+/// * `first_page` - 첫 페이지 파일의 경로
+///
+/// ## Examples
+/// ```
+/// use suwon_mate_admin_tool::input::page_files;
+/// let directory = std::env::temp_dir().join("suwon_mate_page_doctest");
+/// std::fs::create_dir_all(&directory).unwrap();
+/// for page in ["page01.json", "page02.json", "page04.json"] {
+///     std::fs::write(directory.join(page), "{}").unwrap();
+/// }
+/// let pages = page_files(&directory.join("page01.json"));
+/// assert_eq!(pages, vec![directory.join("page01.json"), directory.join("page02.json")]);
+/// ```
+pub fn page_files(first_page: &Path) -> Vec<PathBuf> {
+    let mut pages = vec![first_page.to_path_buf()];
+    let Some(stem) = first_page.file_stem().and_then(|stem| stem.to_str()) else {
+        return pages;
+    };
+    let prefix = stem.trim_end_matches(|c: char| c.is_ascii_digit());
+    let digits = &stem[prefix.len()..];
+    let Ok(first_number) = digits.parse::<u64>() else {
+        return pages;
+    };
+    let extension = first_page
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .map(|extension| format!(".{}", extension))
+        .unwrap_or_default();
+    for number in first_number + 1.. {
+        let page = first_page.with_file_name(format!(
+            "{}{:0width$}{}",
+            prefix,
+            number,
+            extension,
+            width = digits.len()
+        ));
+        if !page.is_file() {
+            break;
+        }
+        pages.push(page);
+    }
+    pages
+}
+
+/// 여러 페이지로 나뉜 응답을 하나로 합치는 메서드
+///
+/// 각 페이지의 `estbLectDtaiList` 목록을 순서대로 이어 붙이며, 그 외의 최상위 항목은 첫 페이지의 값을 사용한다.
+///
+/// ## Arguments
+///
+/// * `pages` - 각 페이지 파일의 내용
+///
+/// ## Examples
+/// ```
+/// use serde_json::json;
+/// use suwon_mate_admin_tool::input::merge_pages;
+/// let pages = vec![
+///     json!({"estbLectDtaiList": [{"subjtCd": "1"}]}),
+///     json!({"estbLectDtaiList": [{"subjtCd": "2"}]}),
+/// ];
+/// let merged = merge_pages(pages).unwrap();
+/// assert_eq!(merged["estbLectDtaiList"].as_array().unwrap().len(), 2);
+/// ```
+///
+/// ## Errors
+/// 어느 한 페이지라도 `estbLectDtaiList` 목록이 없는 경우 오류가 발생한다.
+pub fn merge_pages(pages: Vec<Value>) -> Result<Value, Box<dyn Error>> {
+    let mut merged: Option<Value> = None;
+    for (index, mut page) in pages.into_iter().enumerate() {
+        let subjects = page[SUBJECT_LIST_KEY]
+            .as_array_mut()
+            .map(std::mem::take)
+            .ok_or_else(|| format!("{}번째 페이지에 과목 목록이 없습니다.", index + 1))?;
+        match merged.as_mut() {
+            None => {
+                page[SUBJECT_LIST_KEY] = Value::Array(subjects);
+                merged = Some(page);
+            }
+            Some(merged) => merged[SUBJECT_LIST_KEY]
+                .as_array_mut()
+                .expect("첫 페이지의 과목 목록은 배열이다.")
+                .extend(subjects),
+        }
+    }
+    merged.ok_or_else(|| "합칠 페이지가 없습니다.".into())
+}
+
+/// 과목 목록으로 볼 수 있는 항목의 키 목록을 구하는 메서드
+///
+/// 객체를 하위 객체까지 따라 들어가며, 비어있지 않은 객체의 배열인 항목을 `data.list`처럼 `.`으로 구분한 키로 모은다.
+/// 배열 안으로는 들어가지 않는다.
+///
+/// ## Examples
+/// ```
+/// use serde_json::json;
+/// use suwon_mate_admin_tool::input::root_key_candidates;
+/// let content = json!({
+///     "count": 1,
+///     "errors": [],
+///     "codes": ["A"],
+///     "data": {"list": [{"subjtCd": "1"}], "page": {"items": [{"no": 1}]}}
+/// });
+/// assert_eq!(root_key_candidates(&content), vec!["data.list", "data.page.items"]);
+/// ```
+pub fn root_key_candidates(content: &Value) -> Vec<String> {
+    fn collect(value: &Value, prefix: &str, candidates: &mut Vec<String>) {
+        for (key, value) in value.as_object().into_iter().flatten() {
+            let path = if prefix.is_empty() {
+                key.clone()
+            } else {
+                format!("{}.{}", prefix, key)
+            };
+            match value {
+                Value::Array(list) if !list.is_empty() && list.iter().all(Value::is_object) => {
+                    candidates.push(path)
+                }
+                Value::Object(_) => collect(value, &path, candidates),
+                _ => {}
+            }
+        }
+    }
+    let mut candidates = vec![];
+    collect(content, "", &mut candidates);
+    candidates
+}
+
+/// 과목 목록으로 볼 수 있는 항목이 여럿일 때 터미널에서 사용할 항목을 고르게 하는 메서드
+///
+/// ## Errors
+/// 입력을 읽을 수 없거나 목록에 없는 번호를 입력한 경우 오류가 발생한다.
+fn choose_root_key(content: &Value, candidates: &[String]) -> Result<String, Box<dyn Error>> {
+    notice!("과목 목록으로 볼 수 있는 항목이 여럿입니다. 사용할 항목의 번호를 입력해주세요.");
+    for (index, key) in candidates.iter().enumerate() {
+        let count = content
+            .pointer(&format!("/{}", key.replace('.', "/")))
+            .and_then(Value::as_array)
+            .map_or(0, Vec::len);
+        notice!("{}) {} ({}개)", index + 1, key, count);
+    }
+    print!("번호: ");
+    std::io::stdout().flush()?;
+    let mut answer = String::new();
+    std::io::stdin().lock().read_line(&mut answer)?;
+    answer
+        .trim()
+        .parse::<usize>()
+        .ok()
+        .and_then(|number| candidates.get(number.checked_sub(1)?))
+        .cloned()
+        .ok_or_else(|| format!("목록에 없는 번호입니다: {}", answer.trim()).into())
+}
+
+/// 과목 목록을 `estbLectDtaiList` 키로 옮기는 메서드
+///
+/// 일부 추출본은 과목 목록의 키가 `list`나 `data`이므로 이를 `estbLectDtaiList`로 옮겨 이후 과정에서 같은 구조로 다룰 수 있도록 한다.
+///
+/// * `root_key`가 주어진 경우 해당 키의 목록을 옮긴다. `data.list`처럼 `.`으로 구분하여 하위 키를 지정할 수 있다.
+/// * `root_key`가 없고 `estbLectDtaiList` 키도 없는 경우 [root_key_candidates]로 과목 목록으로 볼 수 있는 항목을 찾아 옮긴다.
+///   후보가 여럿이면 터미널에서 실행한 경우 후보 목록을 보여주고 고르게 하며, 그 외에는 후보 목록과 함께 오류가 발생한다.
+///
+/// ## Arguments
+///
+/// * `content` - 입력 파일의 내용
+/// * `root_key` - 과목 목록이 들어있는 키
+///
+/// ## Examples
+/// ```
+/// use serde_json::json;
+/// use suwon_mate_admin_tool::input::normalize_root_key;
+/// let content = normalize_root_key(json!({"data": {"list": [{"subjtCd": "1"}]}}), Some("data.list")).unwrap();
+/// assert_eq!(content["estbLectDtaiList"][0]["subjtCd"], "1");
+/// let content = normalize_root_key(json!({"count": 1, "list": [{"subjtCd": "1"}]}), None).unwrap();
+/// assert_eq!(content["estbLectDtaiList"][0]["subjtCd"], "1");
+/// let content = normalize_root_key(json!({"data": {"list": [{"subjtCd": "1"}]}}), None).unwrap();
+/// assert_eq!(content["estbLectDtaiList"][0]["subjtCd"], "1");
+/// assert!(normalize_root_key(json!({"list": [{}], "data": [{}]}), None).is_err());
+/// assert!(normalize_root_key(json!({"list": []}), Some("data")).is_err());
+/// ```
+///
+/// ## Errors
+/// * `root_key`에 해당하는 배열이 없는 경우
+/// * `root_key`가 없고 과목 목록으로 볼 수 있는 항목이 여럿인데 터미널에서 실행하지 않았거나, 고른 번호가 올바르지 않은 경우
+pub fn normalize_root_key(
+    mut content: Value,
+    root_key: Option<&str>,
+) -> Result<Value, Box<dyn Error>> {
+    let pointer = match root_key {
+        Some(root_key) => format!("/{}", root_key.replace('.', "/")),
+        None if content.get(SUBJECT_LIST_KEY).is_some() => return Ok(content),
+        None => {
+            let candidates = root_key_candidates(&content);
+            let key = match candidates.as_slice() {
+                [] => return Ok(content),
+                [key] => {
+                    notice!("{} 키의 목록을 과목 목록으로 사용합니다.", key);
+                    key.clone()
+                }
+                keys if std::io::stdin().is_terminal() && std::io::stdout().is_terminal() => {
+                    choose_root_key(&content, keys)?
+                }
+                keys => {
+                    return Err(format!(
+                        "과목 목록으로 볼 수 있는 항목이 여럿입니다: {} (--source-root-key 옵션으로 지정해주세요)",
+                        keys.join(", ")
+                    )
+                    .into())
+                }
+            };
+            format!("/{}", key.replace('.', "/"))
+        }
+    };
+    let subjects = content
+        .pointer_mut(&pointer)
+        .filter(|value| value.is_array())
+        .map(Value::take)
+        .ok_or_else(|| {
+            format!(
+                "{} 키에 과목 목록이 없습니다.",
+                root_key.unwrap_or(&pointer[1..])
+            )
+        })?;
+    content[SUBJECT_LIST_KEY] = subjects;
+    Ok(content)
+}
+
+/// 입력 파일 경로가 `http://` 또는 `https://`로 시작하는 URL인지 확인하는 메서드
+///
+/// ## Examples
+/// ```
+/// use std::path::Path;
+/// use suwon_mate_admin_tool::input::is_url;
+/// assert!(is_url(Path::new("https://example.com/open_class.json")));
+/// assert!(!is_url(Path::new("data/open_class.json")));
+/// ```
+pub fn is_url(path: &Path) -> bool {
+    path.to_str()
+        .is_some_and(|path| path.starts_with("http://") || path.starts_with("https://"))
+}
+
+/// URL 입력의 원본을 캐시를 거쳐 가져오는 메서드
+fn fetch_url(url: &str, options: &InputOptions) -> Result<Vec<u8>, Box<dyn Error>> {
+    match &options.cache {
+        Some(cache) => cache.fetch(url, None, options.offline, || fetch(url)),
+        None if options.offline => {
+            Err("캐시 디렉토리를 정할 수 없어 오프라인 모드를 사용할 수 없습니다.".into())
+        }
+        None => fetch(url),
+    }
+}
+
+/// 입력 파일의 내용을 문자열로 바꾸거나 정리하지 않고 그대로 JSON 해독할 수 있는지 확인하는 메서드
+///
+/// UTF-8로 해독할 수 있고 [clean_json]으로 정리할 문자가 없으며, 주석을 허용하지 않는 경우 `true`를 반환한다.
+/// 이 경우 읽어들이거나 매핑한 내용을 복사하지 않고 바로 해독한다. 읽어들인 내용은 [parse_json_bytes]로 해독하며,
+/// 매핑한 내용은 고쳐 쓸 수 없으므로 항상 serde_json으로 해독한다.
+///
+/// ## Examples
+/// ```
+/// use suwon_mate_admin_tool::input::{is_standard_json, InputEncoding, InputOptions};
+/// let options = InputOptions::default();
+/// assert!(is_standard_json(br#"{"estbLectDtaiList": []}"#, &options));
+/// assert!(!is_standard_json("\u{feff}{}".as_bytes(), &options));
+/// assert!(!is_standard_json(&[0xB0, 0xE6], &options));
+/// let options = InputOptions { encoding: InputEncoding::Cp949, ..Default::default() };
+/// assert!(!is_standard_json(b"{}", &options));
+/// ```
+pub fn is_standard_json(content: &[u8], options: &InputOptions) -> bool {
+    options.encoding != InputEncoding::Cp949
+        && !options.lenient_json
+        && std::str::from_utf8(content).is_ok_and(|content| clean_json(content).is_none())
+}
+
+/// JSON 해독 오류에 입력 파일의 경로를 덧붙이는 메서드
+fn json_error(path: &Path, error: &dyn Error) -> Box<dyn Error> {
+    format!(
+        "{}을(를) JSON으로 해독할 수 없습니다: {}",
+        path.display(),
+        error
+    )
+    .into()
+}
+
+/// 입력 파일을 읽는 방식
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct InputOptions {
+    /// 이어지는 페이지 파일을 찾아 하나로 합칠지 여부 ([page_files] 참고)
+    pub merge_pages: bool,
+    /// 과목 목록이 들어있는 키 ([normalize_root_key] 참고)
+    pub root_key: Option<String>,
+    /// 입력 파일의 문자 인코딩 ([decode_input] 참고)
+    pub encoding: InputEncoding,
+    /// JSON 입력 파일의 주석과 후행 쉼표를 허용할지 여부 ([strip_lenient_json] 참고)
+    pub lenient_json: bool,
+    /// URL 입력의 원본을 저장할 캐시 ([Cache] 참고)
+    pub cache: Option<Cache>,
+    /// URL 입력을 내려받지 않고 캐시에 저장된 원본만 사용할지 여부
+    pub offline: bool,
+    /// CSV, XLSX, XML 입력 파일의 열 매핑 ([ColumnMapping] 참고)
+    pub mapping: ColumnMapping,
+    /// 입력 파일의 데이터 세트 ([Dataset] 참고)
+    pub dataset: Dataset,
+    /// 입력 파일을 메모리에 매핑하여 읽을지 여부 ([map_file] 참고)
+    pub mmap: bool,
+}
+
+/// 입력 파일을 읽는 메서드
+///
+/// 파일은 [read_file]로 읽어(`mmap`이 `true`이면 [map_file]로 매핑하여) 압축된 경우 압축을 풀고(경로가 URL이면 `cache`를 거쳐 내려받고), 내용은 [decode_input]을 통해 UTF-8로 바뀐 뒤 [clean_json]으로 BOM과 비표준 공백이 정리되며, 과목 목록은 [normalize_root_key]를 통해 항상 `estbLectDtaiList` 키로 옮겨진다.
+/// 정리할 것이 없는 표준 JSON 파일([is_standard_json])은 읽어들이거나 매핑한 내용을 문자열로 복사하지 않고 바로 해독한다.
+/// 로컬 `.json` 파일은 앞부분이 표준 JSON 객체로 보이면([stream::starts_as_plain_json]) [stream::stream_input]으로 파일 내용을
+/// 문자열로 복사하지 않고 해독하며, 파일 중간에서 해독에 실패한 경우에만 안내를 출력한 뒤 위의 방식으로 다시 읽는다.
+/// `mmap`이나 `lenient_json`이 `true`인 경우, 인코딩이 CP949인 경우, `simd-json` 기능을 켠 경우에는 스트리밍 해독을 사용하지 않는다.
+/// 확장자가 `.csv`인 파일은 [csv_to_input]으로, `.xlsx`인 파일은 [xlsx_to_input]으로, `.xml`인 파일은 [xml_to_input]으로
+/// `mapping`에 따라 과목 목록으로 바꾼다.
+/// `merge_pages`가 `true`인 경우 [page_files]로 찾은 모든 페이지 파일을 읽어 하나로 합친 내용을 반환한다.
+/// 마지막으로 `dataset`이 학부가 아니면 [Dataset::apply]로 데이터 세트의 필드를 학부 필드로 옮긴다.
+///
+/// ## Arguments
+///
+/// * `path` - 입력 파일(또는 첫 페이지 파일)의 경로
+/// * `options` - 입력 파일을 읽는 방식
+///
+/// ## Errors
+/// * 파일이 존재하지 않거나 읽을 수 없는 경우
+/// * 지정한 인코딩으로 파일을 해독할 수 없는 경우
+/// * 과목 목록을 찾지 못한 경우
+/// * CSV, XLSX, XML 해독이 불가능하거나 매핑에 적힌 헤더나 시트가 입력 파일에 없는 경우
+/// * JSON해독이 불가능한 경우
+pub fn read_input(path: &Path, options: &InputOptions) -> Result<Value, Box<dyn Error>> {
+    let read_page = |page: &Path| -> Result<Value, Box<dyn Error>> {
+        let streamable = !cfg!(feature = "simd-json")
+            && !options.mmap
+            && !options.lenient_json
+            && options.encoding != InputEncoding::Cp949
+            && !is_url(page)
+            && page
+                .extension()
+                .is_some_and(|extension| extension == "json")
+            && stream::starts_as_plain_json(page);
+        if streamable {
+            // 중첩된 키(`data.list`)는 최상위 항목을 그대로 두고 normalize_root_key에서 찾는다.
+            let list_key = options
+                .root_key
+                .as_deref()
+                .filter(|root_key| !root_key.contains('.'))
+                .unwrap_or(SUBJECT_LIST_KEY);
+            match stream::stream_input(page, list_key) {
+                Ok(value) => return normalize_root_key(value, options.root_key.as_deref()),
+                Err(error) => stream::fallback_notice(page, &*error),
+            }
+        }
+        let content = match page.to_str().filter(|_| is_url(page)) {
+            Some(url) => InputBytes::Owned(fetch_url(url, options)?),
+            None if options.mmap => map_file(page)?,
+            None => InputBytes::Owned(read_file(page)?),
+        };
+        if page
+            .extension()
+            .is_some_and(|extension| extension == "xlsx")
+        {
+            return xlsx_to_input(&content, &options.mapping);
+        }
+        let is_table = page
+            .extension()
+            .is_some_and(|extension| extension == "csv" || extension == "xml");
+        let value = if !is_table && is_standard_json(&content, options) {
+            match content {
+                InputBytes::Owned(content) => parse_json_bytes(content),
+                InputBytes::Mapped(map) => Ok(serde_json::from_slice(&map)?),
+            }
+            .map_err(|error| json_error(page, &*error))?
+        } else {
+            let mut content = decode_input(&content, options.encoding)?;
+            if page.extension().is_some_and(|extension| extension == "csv") {
+                return csv_to_input(&content, &options.mapping);
+            }
+            if page.extension().is_some_and(|extension| extension == "xml") {
+                return xml_to_input(&content, &options.mapping);
+            }
+            if let Some(cleaned) = clean_json(&content) {
+                notice!(
+                    "{}의 BOM과 비표준 공백, 제어 문자를 정리하였습니다.",
+                    page.display()
+                );
+                content = cleaned;
+            }
+            if options.lenient_json {
+                if let Some(stripped) = strip_lenient_json(&content) {
+                    notice!("{}의 주석과 후행 쉼표를 제거하였습니다.", page.display());
+                    content = stripped;
+                }
+            }
+            parse_json(content).map_err(|error| json_error(page, &*error))?
+        };
+        normalize_root_key(value, options.root_key.as_deref())
+    };
+    let mut content = if options.merge_pages {
+        let pages = page_files(path);
+        if pages.len() > 1 {
+            notice!(
+                "{}개의 페이지 파일을 합칩니다: {}",
+                pages.len(),
+                pages
+                    .iter()
+                    .map(|page| page.display().to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            );
+        }
+        let contents = pages
+            .iter()
+            .map(|page| read_page(page))
+            .collect::<Result<Vec<_>, _>>()?;
+        self::merge_pages(contents)?
+    } else {
+        read_page(path)?
+    };
+    if options.dataset == Dataset::Undergrad {
+        return Ok(content);
+    }
+    let filled = options.dataset.apply(&mut content);
+    notice!(
+        "{}의 {} 개설강좌 필드 {}개를 학부 필드로 옮겼습니다.",
+        path.display(),
+        options.dataset.name(),
+        filled
+    );
+    Ok(content)
+}
+
+/// 입력 파일 경로 목록의 글롭 패턴을 실제 파일 목록으로 펼치는 메서드
+///
+/// `*`, `?`, `[`가 포함된 경로는 글롭 패턴으로 보고 일치하는 파일을 이름 순으로 펼치며, 그 외의 경로와 URL은 그대로 사용한다.
+///
+/// ## Examples
+/// ```
+/// use std::path::PathBuf;
+/// use suwon_mate_admin_tool::input::expand_patterns;
+/// let directory = std::env::temp_dir().join("suwon_mate_expand_doctest");
+/// std::fs::create_dir_all(&directory).unwrap();
+/// for file in ["open_2.json", "open_1.json", "todo.json"] {
+///     std::fs::write(directory.join(file), "{}").unwrap();
+/// }
+/// let files = expand_patterns(&[directory.join("open_*.json"), PathBuf::from("todo.json")]).unwrap();
+/// assert_eq!(files, vec![directory.join("open_1.json"), directory.join("open_2.json"), PathBuf::from("todo.json")]);
+/// assert!(expand_patterns(&[directory.join("none_*.json")]).is_err());
+/// ```
+///
+/// ## Errors
+/// 글롭 패턴이 올바르지 않거나 일치하는 파일이 없는 경우 오류가 발생한다.
+pub fn expand_patterns(patterns: &[PathBuf]) -> Result<Vec<PathBuf>, Box<dyn Error>> {
+    let mut files = vec![];
+    for pattern in patterns {
+        let text = pattern.to_string_lossy();
+        if is_url(pattern) || !text.contains(['*', '?', '[']) {
+            files.push(pattern.clone());
+            continue;
+        }
+        let mut matched = glob::glob(&text)?.collect::<Result<Vec<_>, _>>()?;
+        if matched.is_empty() {
+            return Err(format!("{}와(과) 일치하는 입력 파일이 없습니다.", text).into());
+        }
+        matched.sort();
+        files.extend(matched);
+    }
+    Ok(files)
+}
+
+/// 여러 입력 파일의 과목 목록을 과목 키(`subjtCd-diclNo`) 기준으로 중복을 제거하며 합치는 메서드
+///
+/// 같은 과목 키의 과목이 여러 파일에 있으면 먼저 나온 파일의 과목을 사용하며, 그 외의 최상위 항목은 첫 파일의 값을 사용한다.
+/// 합친 내용과 제거한 중복 과목의 수를 반환한다.
+///
+/// ## Examples
+/// ```
+/// use serde_json::json;
+/// use suwon_mate_admin_tool::input::merge_inputs;
+/// let contents = vec![
+///     json!({"estbLectDtaiList": [{"subjtCd": "1", "diclNo": "001"}, {"subjtCd": "2", "diclNo": "001"}]}),
+///     json!({"estbLectDtaiList": [{"subjtCd": "2", "diclNo": "001"}, {"subjtCd": "2", "diclNo": "002"}]}),
+/// ];
+/// let (merged, duplicates) = merge_inputs(contents).unwrap();
+/// assert_eq!(merged["estbLectDtaiList"].as_array().unwrap().len(), 3);
+/// assert_eq!(duplicates, 1);
+/// ```
+///
+/// ## Errors
+/// 어느 한 파일이라도 `estbLectDtaiList` 목록이 없는 경우 오류가 발생한다.
+pub fn merge_inputs(contents: Vec<Value>) -> Result<(Value, usize), Box<dyn Error>> {
+    let mut merged = self::merge_pages(contents)?;
+    let subjects = merged[SUBJECT_LIST_KEY]
+        .as_array_mut()
+        .expect("합친 과목 목록은 배열이다.");
+    let before = subjects.len();
+    let mut seen = HashSet::new();
+    subjects.retain(|subject| seen.insert(subject_key(subject)));
+    let duplicates = before - subjects.len();
+    Ok((merged, duplicates))
+}
+
+/// 여러 입력 파일(또는 글롭 패턴)을 읽어 하나의 내용으로 합치는 메서드
+///
+/// 파일은 [expand_patterns]로 펼친 뒤 각각 [read_input]으로 읽으며, 파일이 둘 이상이면 [merge_inputs]로 중복을 제거하며 합친다.
+///
+/// ## Errors
+/// * 글롭 패턴과 일치하는 파일이 없는 경우
+/// * [read_input] 또는 [merge_inputs]에서 오류가 발생한 경우
+pub fn read_inputs(patterns: &[PathBuf], options: &InputOptions) -> Result<Value, Box<dyn Error>> {
+    let files = expand_patterns(patterns)?;
+    let mut contents = files
+        .iter()
+        .map(|file| read_input(file, options))
+        .collect::<Result<Vec<_>, _>>()?;
+    if contents.len() == 1 {
+        return Ok(contents.remove(0));
+    }
+    let (merged, duplicates) = merge_inputs(contents)?;
+    notice!(
+        "{}개의 입력 파일을 합쳤습니다. (중복 과목 {}개 제거)",
+        files.len(),
+        duplicates
+    );
+    Ok(merged)
+}
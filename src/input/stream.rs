@@ -0,0 +1,212 @@
+//! # 스트리밍 입력 해독
+//!
+//! 수백 MB의 입력 파일을 문자열로 읽은 뒤 `Value`로 해독하면 파일 내용과 해독 결과를 동시에 메모리에 두어야 한다.
+//! [for_each_subject]는 `serde_json::Deserializer::from_reader`로 입력 파일을 읽으면서 과목 목록의 과목을
+//! 하나씩 해독하여 넘기므로, 파일 전체나 과목 목록 전체를 메모리에 두지 않고 과목을 처리할 수 있다.
+//!
+//! 표준 JSON만 해독할 수 있으므로 BOM이나 주석이 있는 파일, UTF-8이 아닌 파일은 [crate::input::read_input]으로 읽어야 한다.
+//! [crate::input::read_input]은 로컬 JSON 파일의 앞부분을 [starts_as_plain_json]으로 확인하여 스트리밍으로 해독할 수 있어 보이는
+//! 경우에만 [stream_input]으로 읽고, 파일 중간에서 해독에 실패한 경우에만 그 이유를 알린 뒤 파일 전체를 읽어 해독한다.
+//!
+//! [stream_input]은 파일 내용을 문자열로 복사하지 않을 뿐, 해독한 과목 목록 전체는 여전히 메모리에 둔다.
+//! 과목 목록을 메모리에 두지 않으려면 [for_each_subject]로 과목을 하나씩 처리해야 한다.
+use std::error::Error;
+use std::fmt;
+use std::fs::File;
+use std::io::{BufReader, Read};
+use std::path::Path;
+
+use serde::de::{DeserializeSeed, Deserializer, MapAccess, SeqAccess, Visitor};
+use serde_json::{Map, Value};
+
+/// [starts_as_plain_json]이 확인하는 파일 앞부분의 크기 (바이트)
+const PEEK_SIZE: usize = 64 * 1024;
+
+/// 과목 목록의 과목을 하나씩 `visit`에 넘기는 시퀀스 방문자
+struct SubjectVisitor<'a, F> {
+    visit: &'a mut F,
+}
+
+impl<'de, F: FnMut(Value)> DeserializeSeed<'de> for SubjectVisitor<'_, F> {
+    type Value = usize;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<usize, D::Error> {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'de, F: FnMut(Value)> Visitor<'de> for SubjectVisitor<'_, F> {
+    type Value = usize;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("과목 목록 배열")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<usize, A::Error> {
+        let mut count = 0;
+        while let Some(subject) = seq.next_element::<Value>()? {
+            (self.visit)(subject);
+            count += 1;
+        }
+        Ok(count)
+    }
+}
+
+/// 과목 목록 외의 최상위 항목과 넘긴 과목의 수 (과목 목록이 없으면 `None`)
+type Visited = (Map<String, Value>, Option<usize>);
+
+/// 최상위 객체를 읽으며 `list_key`의 과목 목록만 [SubjectVisitor]로 넘기는 방문자
+struct TopLevelVisitor<'a, F> {
+    list_key: &'a str,
+    visit: &'a mut F,
+}
+
+impl<'de, F: FnMut(Value)> Visitor<'de> for TopLevelVisitor<'_, F> {
+    type Value = Visited;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("최상위 JSON 객체")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut rest = Map::new();
+        let mut count = None;
+        while let Some(key) = map.next_key::<String>()? {
+            if key == self.list_key && count.is_none() {
+                count = Some(map.next_value_seed(SubjectVisitor {
+                    visit: &mut *self.visit,
+                })?);
+            } else {
+                let value = map.next_value()?;
+                rest.insert(key, value);
+            }
+        }
+        Ok((rest, count))
+    }
+}
+
+/// 입력 파일의 과목 목록(`list_key`)의 과목을 하나씩 해독하여 `visit`에 넘기는 메서드
+///
+/// 과목 목록 외의 최상위 항목과 넘긴 과목의 수를 반환한다.
+///
+/// ## Examples
+/// ```
+/// use suwon_mate_admin_tool::input::stream::for_each_subject;
+/// let content = r#"{"totalCount": 2, "estbLectDtaiList": [{"subjtCd": "1"}, {"subjtCd": "2"}]}"#;
+/// let mut codes = vec![];
+/// let (rest, count) = for_each_subject(content.as_bytes(), "estbLectDtaiList", |subject| {
+///     codes.push(subject["subjtCd"].as_str().unwrap().to_string());
+/// })
+/// .unwrap();
+/// assert_eq!(codes, vec!["1", "2"]);
+/// assert_eq!(count, 2);
+/// assert_eq!(rest["totalCount"], 2);
+/// assert!(for_each_subject(r#"{"list": []}"#.as_bytes(), "estbLectDtaiList", |_| {}).is_err());
+/// ```
+///
+/// ## Errors
+/// * JSON 해독이 불가능하거나 최상위 항목이 객체가 아닌 경우
+/// * 과목 목록이 없거나 배열이 아닌 경우
+pub fn for_each_subject<R, F>(
+    reader: R,
+    list_key: &str,
+    mut visit: F,
+) -> Result<(Map<String, Value>, usize), Box<dyn Error>>
+where
+    R: Read,
+    F: FnMut(Value),
+{
+    let (rest, count) = visit_subjects(reader, list_key, &mut visit)?;
+    let count = count.ok_or_else(|| format!("입력 파일에 {} 목록이 없습니다.", list_key))?;
+    Ok((rest, count))
+}
+
+/// [for_each_subject]와 같지만 과목 목록이 없으면 넘긴 과목의 수 대신 `None`을 반환하는 메서드
+fn visit_subjects<R, F>(reader: R, list_key: &str, visit: &mut F) -> Result<Visited, Box<dyn Error>>
+where
+    R: Read,
+    F: FnMut(Value),
+{
+    let mut deserializer = serde_json::Deserializer::from_reader(reader);
+    let result = deserializer.deserialize_map(TopLevelVisitor { list_key, visit })?;
+    deserializer.end()?;
+    Ok(result)
+}
+
+/// 파일의 앞부분만 읽어 스트리밍으로 해독할 수 있는 표준 JSON 객체로 보이는지 확인하는 메서드
+///
+/// BOM으로 시작하거나, 공백 다음의 첫 문자가 `{`가 아니거나, 앞부분을 UTF-8로 해독할 수 없는 경우(CP949 등) `false`를 반환한다.
+/// 파일을 열 수 없는 경우에도 `false`를 반환하므로, 오류는 파일 전체를 읽을 때 보고된다.
+///
+/// ## Examples
+/// ```
+/// use suwon_mate_admin_tool::input::stream::starts_as_plain_json;
+/// let path = std::env::temp_dir().join("suwon_mate_plain_json_doctest.json");
+/// std::fs::write(&path, " {\"estbLectDtaiList\": [{\"subjtNm\": \"자료구조\"}]}").unwrap();
+/// assert!(starts_as_plain_json(&path));
+/// std::fs::write(&path, "\u{feff}{}").unwrap();
+/// assert!(!starts_as_plain_json(&path));
+/// std::fs::write(&path, b"{\"subjtNm\": \"\xc0\xda\xb7\xe1\"}").unwrap();
+/// assert!(!starts_as_plain_json(&path));
+/// assert!(!starts_as_plain_json(&std::env::temp_dir().join("suwon_mate_none.json")));
+/// ```
+pub fn starts_as_plain_json(path: &Path) -> bool {
+    let Ok(file) = File::open(path) else {
+        return false;
+    };
+    let mut head = Vec::with_capacity(PEEK_SIZE);
+    if file.take(PEEK_SIZE as u64).read_to_end(&mut head).is_err() {
+        return false;
+    }
+    // 앞부분의 끝에서 잘린 멀티바이트 문자는 해독 오류로 보지 않는다.
+    let valid_utf8 = match std::str::from_utf8(&head) {
+        Ok(_) => true,
+        Err(error) => error.error_len().is_none(),
+    };
+    valid_utf8
+        && head
+            .iter()
+            .find(|byte| !byte.is_ascii_whitespace())
+            .is_some_and(|byte| *byte == b'{')
+}
+
+/// 입력 파일을 [for_each_subject]로 읽어 과목 목록(`list_key`)과 나머지 최상위 항목을 담은 값으로 만드는 메서드
+///
+/// 파일 내용을 문자열로 복사하지 않을 뿐 과목 목록 전체를 해독한 값으로 메모리에 두며, 과목을 하나씩 처리하지는 않는다.
+/// `list_key` 목록이 없으면 최상위 항목을 모두 그대로 담아 반환하므로, 과목 목록의 키를 찾는 것은 [crate::input::normalize_root_key]에 맡긴다.
+///
+/// ## Examples
+/// ```
+/// use suwon_mate_admin_tool::input::stream::stream_input;
+/// let path = std::env::temp_dir().join("suwon_mate_stream_input_doctest.json");
+/// std::fs::write(&path, r#"{"estbLectDtaiList": [{"subjtCd": "1"}], "totalCount": 1}"#).unwrap();
+/// let input = stream_input(&path, "estbLectDtaiList").unwrap();
+/// assert_eq!(input["estbLectDtaiList"][0]["subjtCd"], "1");
+/// assert_eq!(input["totalCount"], 1);
+/// std::fs::write(&path, r#"{"list": [{"subjtCd": "1"}]}"#).unwrap();
+/// assert_eq!(stream_input(&path, "estbLectDtaiList").unwrap()["list"][0]["subjtCd"], "1");
+/// ```
+///
+/// ## Errors
+/// 파일을 읽을 수 없거나 JSON 해독이 불가능한 경우, 최상위 항목이 객체가 아닌 경우 오류가 발생한다.
+pub fn stream_input(path: &Path, list_key: &str) -> Result<Value, Box<dyn Error>> {
+    let mut subjects = vec![];
+    let (mut rest, count) = visit_subjects(
+        BufReader::new(File::open(path)?),
+        list_key,
+        &mut |subject| subjects.push(subject),
+    )?;
+    if count.is_some() {
+        rest.insert(list_key.to_string(), Value::Array(subjects));
+    }
+    Ok(Value::Object(rest))
+}
+
+/// 파일 중간에서 스트리밍 해독에 실패하여 파일 전체를 읽어 다시 해독한다는 안내를 출력하는 메서드
+pub(crate) fn fallback_notice(path: &Path, error: &dyn Error) {
+    notice!(
+        "주의: {}을(를) 스트리밍으로 해독하지 못하여 파일 전체를 읽어 다시 해독합니다. ({})",
+        path.display(),
+        error
+    );
+}
@@ -0,0 +1,69 @@
+//! # XLSX 입력
+//!
+//! 엑셀 파일의 개설강좌 시트를 [ColumnMapping]에 따라 `estbLectDtaiList` 형태의 입력 내용으로 바꾼다.
+//! 읽을 시트는 [ColumnMapping::sheet]로 지정하며, 지정하지 않으면 첫 시트를 읽는다.
+use std::error::Error;
+use std::io::Cursor;
+
+use calamine::{Reader, Xlsx};
+use serde_json::Value;
+
+use crate::input::mapping::ColumnMapping;
+
+/// XLSX 파일 내용을 `{"estbLectDtaiList": [...]}` 형태의 입력 내용으로 바꾸는 메서드
+///
+/// 시트의 첫 행은 헤더로 사용하며, 셀의 값은 표시되는 문자열로 기록한다.
+///
+/// ## Examples
+/// ```
+/// use rust_xlsxwriter::Workbook;
+/// use serde_json::json;
+/// use suwon_mate_admin_tool::input::mapping::ColumnMapping;
+/// use suwon_mate_admin_tool::input::xlsx::xlsx_to_input;
+/// let mut workbook = Workbook::new();
+/// let worksheet = workbook.add_worksheet().set_name("개설강좌").unwrap();
+/// worksheet.write_string(0, 0, "과목코드").unwrap();
+/// worksheet.write_string(0, 1, "학점").unwrap();
+/// worksheet.write_string(1, 0, "00123").unwrap();
+/// worksheet.write_number(1, 1, 3).unwrap();
+/// let content = workbook.save_to_buffer().unwrap();
+///
+/// let mapping = ColumnMapping::from_yaml("sheet: 개설강좌\ncolumns:\n  subjtCd: 과목코드\n  point: 학점\n").unwrap();
+/// assert_eq!(
+///     xlsx_to_input(&content, &mapping).unwrap(),
+///     json!({"estbLectDtaiList": [{"subjtCd": "00123", "point": "3"}]})
+/// );
+/// let mapping = ColumnMapping::from_yaml("sheet: 강의계획서\n").unwrap();
+/// assert!(xlsx_to_input(&content, &mapping).is_err());
+/// ```
+///
+/// ## Errors
+/// * XLSX 해독이 불가능하거나 시트를 찾을 수 없는 경우
+/// * 매핑에 적힌 헤더가 시트에 없는 경우
+pub fn xlsx_to_input(content: &[u8], mapping: &ColumnMapping) -> Result<Value, Box<dyn Error>> {
+    let mut workbook = Xlsx::new(Cursor::new(content))?;
+    let sheet = match &mapping.sheet {
+        Some(sheet) => sheet.clone(),
+        None => workbook
+            .sheet_names()
+            .first()
+            .cloned()
+            .ok_or("XLSX 파일에 시트가 없습니다.")?,
+    };
+    let range = workbook.worksheet_range(&sheet).map_err(|error| {
+        format!(
+            "XLSX 파일에서 '{}' 시트를 읽을 수 없습니다: {}",
+            sheet, error
+        )
+    })?;
+    let mut rows = range
+        .rows()
+        .map(|row| row.iter().map(|cell| cell.to_string()).collect::<Vec<_>>());
+    let headers: Vec<String> = rows
+        .next()
+        .ok_or_else(|| format!("'{}' 시트가 비어있습니다.", sheet))?
+        .iter()
+        .map(|header| header.trim().to_string())
+        .collect();
+    mapping.to_subject_list(&headers, rows)
+}
@@ -0,0 +1,208 @@
+//! # XML 입력
+//!
+//! 일부 학사 시스템이 XML로 응답하는 개설강좌 목록을 `estbLectDtaiList` 형태의 입력 내용으로 바꾼다.
+//!
+//! 자식 요소가 모두 값만 가진 요소(예: `<item><subjtCd>1</subjtCd>...</item>`)를 과목 하나로 보며,
+//! 자식 요소와 속성의 이름을 필드 이름으로 사용한다. 과목으로 볼 수 있는 요소의 이름이 여럿이면
+//! [ColumnMapping::record]로 과목 요소의 이름을 지정해야 한다. 필드 이름은 CSV와 마찬가지로 [ColumnMapping]에 따라 바뀐다.
+use std::error::Error;
+
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use serde_json::Value;
+
+use crate::input::mapping::ColumnMapping;
+
+/// XML 요소
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+struct Element {
+    /// 요소 이름 (네임스페이스 접두사 제외)
+    name: String,
+    /// 속성 목록
+    attributes: Vec<(String, String)>,
+    /// 자식 요소 목록
+    children: Vec<Element>,
+    /// 요소의 문자열 값
+    text: String,
+}
+
+impl Element {
+    /// 시작 태그로부터 요소를 만드는 메서드
+    fn from_start(start: &BytesStart) -> Result<Self, Box<dyn Error>> {
+        let attributes = start
+            .attributes()
+            .map(|attribute| {
+                let attribute = attribute?;
+                let key = String::from_utf8_lossy(attribute.key.local_name().as_ref()).to_string();
+                Ok((key, attribute.unescape_value()?.to_string()))
+            })
+            .collect::<Result<Vec<_>, Box<dyn Error>>>()?;
+        Ok(Self {
+            name: String::from_utf8_lossy(start.local_name().as_ref()).to_string(),
+            attributes,
+            ..Default::default()
+        })
+    }
+
+    /// 과목으로 볼 수 있는 요소인지 확인하는 메서드
+    fn is_record(&self) -> bool {
+        !self.children.is_empty() && self.children.iter().all(|child| child.children.is_empty())
+    }
+
+    /// 과목으로 볼 수 있는 모든 하위 요소를 문서 순서대로 모으는 메서드
+    fn records<'a>(&'a self, records: &mut Vec<&'a Element>) {
+        if self.is_record() {
+            records.push(self);
+        }
+        for child in self.children.iter() {
+            child.records(records);
+        }
+    }
+}
+
+/// XML 문서를 요소 트리로 해독하는 메서드
+fn parse_document(content: &str) -> Result<Element, Box<dyn Error>> {
+    let mut reader = Reader::from_str(content);
+    reader.config_mut().trim_text(true);
+    let mut stack = vec![Element::default()];
+    loop {
+        match reader.read_event()? {
+            Event::Start(start) => stack.push(Element::from_start(&start)?),
+            Event::Empty(start) => {
+                let element = Element::from_start(&start)?;
+                stack
+                    .last_mut()
+                    .expect("문서 요소가 있다.")
+                    .children
+                    .push(element);
+            }
+            Event::End(_) => {
+                let element = stack.pop().expect("시작 태그가 있다.");
+                stack
+                    .last_mut()
+                    .ok_or("XML의 태그 짝이 맞지 않습니다.")?
+                    .children
+                    .push(element);
+            }
+            Event::Text(text) => {
+                let text = text.unescape()?;
+                stack
+                    .last_mut()
+                    .expect("문서 요소가 있다.")
+                    .text
+                    .push_str(&text);
+            }
+            Event::CData(data) => {
+                let data = data.into_inner();
+                let text = String::from_utf8_lossy(&data);
+                stack
+                    .last_mut()
+                    .expect("문서 요소가 있다.")
+                    .text
+                    .push_str(&text);
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+    match <[Element; 1]>::try_from(stack) {
+        Ok([document]) => Ok(document),
+        Err(_) => Err("XML의 태그가 닫히지 않았습니다.".into()),
+    }
+}
+
+/// XML 내용을 `{"estbLectDtaiList": [...]}` 형태의 입력 내용으로 바꾸는 메서드
+///
+/// 과목마다 없는 필드는 `null`로 기록한다.
+///
+/// ## Examples
+/// ```
+/// use serde_json::json;
+/// use suwon_mate_admin_tool::input::mapping::ColumnMapping;
+/// use suwon_mate_admin_tool::input::xml::xml_to_input;
+/// let content = r#"<?xml version="1.0" encoding="UTF-8"?>
+/// <response>
+///     <header><resultCode>00</resultCode></header>
+///     <list>
+///         <item diclNo="001"><subjtCd>1</subjtCd><subjtNm>회계원리 &amp; 실습</subjtNm></item>
+///         <item diclNo="002"><subjtCd>1</subjtCd><ltrPrfsNm/></item>
+///     </list>
+/// </response>"#;
+/// let mapping = ColumnMapping::from_yaml("record: item\n").unwrap();
+/// assert_eq!(
+///     xml_to_input(content, &mapping).unwrap(),
+///     json!({"estbLectDtaiList": [
+///         {"diclNo": "001", "subjtCd": "1", "subjtNm": "회계원리 & 실습", "ltrPrfsNm": null},
+///         {"diclNo": "002", "subjtCd": "1", "subjtNm": null, "ltrPrfsNm": null}
+///     ]})
+/// );
+/// assert!(xml_to_input(content, &ColumnMapping::default()).is_err());
+/// assert!(xml_to_input("<list><item>", &mapping).is_err());
+/// ```
+///
+/// ## Errors
+/// * XML 해독이 불가능한 경우
+/// * 과목으로 볼 수 있는 요소나 지정한 과목 요소가 없거나, 요소 이름이 여럿인데 [ColumnMapping::record]가 지정되지 않은 경우
+/// * 매핑에 적힌 헤더가 XML에 없는 경우
+pub fn xml_to_input(content: &str, mapping: &ColumnMapping) -> Result<Value, Box<dyn Error>> {
+    let document = parse_document(content.trim_start_matches('\u{feff}'))?;
+    let mut records = vec![];
+    document.records(&mut records);
+    let record = match &mapping.record {
+        Some(record) => record.clone(),
+        None => {
+            let mut names: Vec<&str> = records.iter().map(|record| record.name.as_str()).collect();
+            names.sort_unstable();
+            names.dedup();
+            match names.as_slice() {
+                [name] => name.to_string(),
+                [] => return Err("XML에서 과목으로 볼 수 있는 요소를 찾지 못하였습니다.".into()),
+                names => {
+                    return Err(format!(
+                        "과목으로 볼 수 있는 요소가 여럿입니다: {} (열 매핑 파일의 record 항목으로 지정해주세요)",
+                        names.join(", ")
+                    )
+                    .into())
+                }
+            }
+        }
+    };
+    records.retain(|element| element.name == record);
+    if records.is_empty() {
+        return Err(format!("XML에 과목 요소({})가 없습니다.", record).into());
+    }
+    let records: Vec<Vec<(&str, &str)>> = records
+        .into_iter()
+        .map(|element| {
+            element
+                .attributes
+                .iter()
+                .map(|(name, value)| (name.as_str(), value.as_str()))
+                .chain(
+                    element
+                        .children
+                        .iter()
+                        .map(|child| (child.name.as_str(), child.text.as_str())),
+                )
+                .collect()
+        })
+        .collect();
+    let mut headers: Vec<String> = vec![];
+    for (name, _) in records.iter().flatten() {
+        if !headers.iter().any(|header| header == name) {
+            headers.push(name.to_string());
+        }
+    }
+    let rows = records.iter().map(|fields| {
+        headers
+            .iter()
+            .map(|header| {
+                fields
+                    .iter()
+                    .find(|(name, _)| name == header)
+                    .map_or(String::new(), |(_, value)| value.to_string())
+            })
+            .collect()
+    });
+    mapping.to_subject_list(&headers, rows)
+}
@@ -0,0 +1,195 @@
+//! # 입력 필드 값 분포 프로파일
+//!
+//! 새 학기 입력 파일에 이상이 없는지 빠르게 파악할 수 있도록 과목 목록(`estbLectDtaiList`)의 필드별로
+//! 고유값 개수, 최빈값, null 비율을 구하여 Markdown 표로 정리한다.
+//!
+//! 필드가 없는 과목은 해당 필드의 값이 null인 것으로 본다.
+use std::collections::{BTreeMap, HashMap};
+use std::error::Error;
+use std::fs::File;
+use std::io::BufReader;
+use std::path::Path;
+
+use serde_json::Value;
+
+use crate::input::stream::for_each_subject;
+use crate::input::SUBJECT_LIST_KEY;
+use crate::report::escape_cell;
+
+/// 필드 하나의 값 분포
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct FieldProfile {
+    /// 필드 이름
+    pub name: String,
+    /// null이 아닌 값의 고유값 개수
+    pub distinct_count: usize,
+    /// null이 아닌 값 중 가장 많이 등장한 값과 등장 횟수 (횟수가 같으면 사전순으로 앞선 값)
+    pub most_common: Option<(String, usize)>,
+    /// 값이 null이거나 필드가 없는 과목의 수
+    pub null_count: usize,
+}
+
+impl FieldProfile {
+    /// 전체 과목 `total`개 중 null인 과목의 비율(%)
+    pub fn null_ratio(&self, total: usize) -> f64 {
+        self.null_count as f64 * 100.0 / total.max(1) as f64
+    }
+}
+
+/// 분포를 셀 때 사용할 값의 표현 (문자열은 따옴표 없이 사용)
+fn value_text(value: &Value) -> String {
+    match value {
+        Value::String(text) => text.clone(),
+        value => value.to_string(),
+    }
+}
+
+/// 과목을 하나씩 받아 필드별 값 분포를 세는 구조체
+///
+/// 과목 목록 전체를 메모리에 두지 않고 스트리밍으로 읽은 과목도 셀 수 있다.
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct SubjectProfiler {
+    /// 필드 이름별로 null이 아닌 값의 등장 횟수
+    counts: BTreeMap<String, HashMap<String, usize>>,
+    /// 센 과목의 수
+    total: usize,
+}
+
+impl SubjectProfiler {
+    /// 과목 하나의 필드 값을 세는 메서드
+    pub fn add(&mut self, subject: &Value) {
+        self.total += 1;
+        for (key, value) in subject.as_object().into_iter().flatten() {
+            let values = self.counts.entry(key.clone()).or_default();
+            if !value.is_null() {
+                *values.entry(value_text(value)).or_default() += 1;
+            }
+        }
+    }
+
+    /// 센 과목의 수
+    pub fn total(&self) -> usize {
+        self.total
+    }
+
+    /// 센 과목의 필드별 값 분포를 필드 이름 순서로 구하는 메서드
+    pub fn profiles(&self) -> Vec<FieldProfile> {
+        self.counts
+            .iter()
+            .map(|(name, values)| {
+                let present: usize = values.values().sum();
+                let most_common = values
+                    .iter()
+                    .max_by(|left, right| left.1.cmp(right.1).then_with(|| right.0.cmp(left.0)))
+                    .map(|(value, count)| (value.clone(), *count));
+                FieldProfile {
+                    name: name.clone(),
+                    distinct_count: values.len(),
+                    most_common,
+                    null_count: self.total - present,
+                }
+            })
+            .collect()
+    }
+
+    /// 센 과목의 필드별 값 분포를 Markdown 문서로 만드는 메서드
+    pub fn markdown(&self) -> String {
+        let mut profile = format!(
+            "# 입력 필드 값 분포\n\n\
+             - 과목 수: {}\n\n\
+             | 필드 | 고유값 수 | 최빈값 | 최빈값 횟수 | null 수 | null 비율 |\n\
+             | --- | ---: | --- | ---: | ---: | ---: |\n",
+            self.total
+        );
+        for field in self.profiles() {
+            let (most_common, most_common_count) = match &field.most_common {
+                Some((value, count)) => (escape_cell(value), *count),
+                None => (String::from("(없음)"), 0),
+            };
+            profile.push_str(&format!(
+                "| {} | {} | {} | {} | {} | {:.1}% |\n",
+                escape_cell(&field.name),
+                field.distinct_count,
+                most_common,
+                most_common_count,
+                field.null_count,
+                field.null_ratio(self.total)
+            ));
+        }
+        profile
+    }
+}
+
+/// 과목 목록의 필드별 값 분포를 필드 이름 순서로 구하는 메서드
+///
+/// ## Examples
+/// ```
+/// use serde_json::json;
+/// use suwon_mate_admin_tool::input_profile::profile_subjects;
+/// let subjects = [
+///     json!({"subjtCd": "1", "point": 3}),
+///     json!({"subjtCd": "2", "point": 3}),
+///     json!({"subjtCd": "2", "point": null}),
+/// ];
+/// let profiles = profile_subjects(&subjects);
+/// assert_eq!(profiles[0].name, "point");
+/// assert_eq!(profiles[0].distinct_count, 1);
+/// assert_eq!(profiles[0].most_common, Some(("3".to_string(), 2)));
+/// assert_eq!(profiles[0].null_count, 1);
+/// assert_eq!(profiles[1].name, "subjtCd");
+/// assert_eq!(profiles[1].most_common, Some(("2".to_string(), 2)));
+/// assert_eq!(profiles[1].null_count, 0);
+/// ```
+pub fn profile_subjects(subjects: &[Value]) -> Vec<FieldProfile> {
+    let mut profiler = SubjectProfiler::default();
+    for subject in subjects {
+        profiler.add(subject);
+    }
+    profiler.profiles()
+}
+
+/// 입력 내용의 필드별 값 분포를 Markdown 문서로 만드는 메서드
+///
+/// ## Examples
+/// ```
+/// use serde_json::json;
+/// use suwon_mate_admin_tool::input_profile::input_profile;
+/// let input = json!({"estbLectDtaiList": [{"subjtCd": "1", "ltrPrfsNm": null}, {"subjtCd": "1"}]});
+/// let profile = input_profile(&input);
+/// assert!(profile.contains("- 과목 수: 2"));
+/// assert!(profile.contains("| ltrPrfsNm | 0 | (없음) | 0 | 2 | 100.0% |"));
+/// assert!(profile.contains("| subjtCd | 1 | 1 | 2 | 0 | 0.0% |"));
+/// ```
+pub fn input_profile(input: &Value) -> String {
+    let mut profiler = SubjectProfiler::default();
+    for subject in input[SUBJECT_LIST_KEY].as_array().into_iter().flatten() {
+        profiler.add(subject);
+    }
+    profiler.markdown()
+}
+
+/// 입력 파일을 스트리밍으로 읽으며 필드별 값 분포를 세는 메서드
+///
+/// 과목 목록이 `estbLectDtaiList` 키에 들어있는 표준 JSON 파일만 읽을 수 있으며, 과목 목록 전체를 메모리에 두지 않는다.
+///
+/// ## Examples
+/// ```
+/// use suwon_mate_admin_tool::input_profile::stream_profile;
+/// let path = std::env::temp_dir().join("suwon_mate_stream_profile_doctest.json");
+/// std::fs::write(&path, r#"{"estbLectDtaiList": [{"subjtCd": "1"}, {"subjtCd": "1"}]}"#).unwrap();
+/// let profiler = stream_profile(&path).unwrap();
+/// assert_eq!(profiler.total(), 2);
+/// assert!(profiler.markdown().contains("| subjtCd | 1 | 1 | 2 | 0 | 0.0% |"));
+/// ```
+///
+/// ## Errors
+/// 파일을 읽을 수 없거나 JSON 해독이 불가능한 경우, 과목 목록이 없는 경우 오류가 발생한다.
+pub fn stream_profile(path: &Path) -> Result<SubjectProfiler, Box<dyn Error>> {
+    let mut profiler = SubjectProfiler::default();
+    for_each_subject(
+        BufReader::new(File::open(path)?),
+        SUBJECT_LIST_KEY,
+        |subject| profiler.add(&subject),
+    )?;
+    Ok(profiler)
+}
@@ -0,0 +1,70 @@
+//! # 구 스키마 DB
+//!
+//! 새 항목을 알지 못하는 예전 버전 앱을 위해 구 스키마에 맞춘 DB를 만든다.
+//!
+//! 구 스키마는 아래와 같이 옵션으로 추가된 항목이 없는 구조이다.
+//!
+//! * 최상위 항목은 `departments`, `estbLectDtaiList`, `contacts`, `version`만 가진다. (불완전한 DB는 `_quick` 키를 그대로 사용)
+//! * 과목 정보는 [crate::SUBJECT_FIELDS]의 필드만 가진다. (`rating` 등 제외)
+//! * `version`은 `app_ver`와 `db_ver`만 가지며, `app_ver`에는 레거시 앱 버전이 기록된다.
+use serde_json::{json, Map, Value};
+
+use crate::SUBJECT_FIELDS;
+
+/// 구 스키마 DB가 가지는 최상위 항목
+pub const LEGACY_SECTIONS: [&str; 6] = [
+    "departments",
+    "departments_quick",
+    "estbLectDtaiList",
+    "estbLectDtaiList_quick",
+    "contacts",
+    "version",
+];
+
+/// DB를 구 스키마에 맞게 바꾸는 메서드
+///
+/// `version.app_ver`에는 DB의 `version.legacy_app_ver` 값이 기록된다.
+///
+/// ## Examples
+/// ```
+/// use serde_json::json;
+/// use suwon_mate_admin_tool::legacy::legacy_db;
+/// let db = json!({
+///     "estbLectDtaiList": {"경영학부": [{"subjtCd": "1", "diclNo": "001", "rating": 4.5}]},
+///     "academic_calendar": [],
+///     "version": {"app_ver": "2.0", "db_ver": "3.0", "legacy_app_ver": "1.2", "compatibility": []}
+/// });
+/// assert_eq!(legacy_db(&db), json!({
+///     "estbLectDtaiList": {"경영학부": [{"subjtCd": "1", "diclNo": "001"}]},
+///     "version": {"app_ver": "1.2", "db_ver": "3.0"}
+/// }));
+/// ```
+pub fn legacy_db(db: &Value) -> Value {
+    let mut legacy = Map::new();
+    for section in LEGACY_SECTIONS {
+        if let Some(value) = db.get(section) {
+            legacy.insert(section.to_string(), value.clone());
+        }
+    }
+    for key in ["estbLectDtaiList", "estbLectDtaiList_quick"] {
+        let subjects = legacy
+            .get_mut(key)
+            .and_then(Value::as_object_mut)
+            .into_iter()
+            .flat_map(|lists| lists.values_mut())
+            .filter_map(Value::as_array_mut)
+            .flatten()
+            .filter_map(Value::as_object_mut);
+        for subject in subjects {
+            subject.retain(|field, _| SUBJECT_FIELDS.contains(&field.as_str()));
+        }
+    }
+    legacy.insert(
+        String::from("version"),
+        json!({
+            "app_ver": db["version"]["legacy_app_ver"],
+            "db_ver": db["version"]["db_ver"],
+        }),
+    );
+    Value::Object(legacy)
+}
@@ -1,26 +1,513 @@
 //! # Suwon mate admin tool
 //!
 //! `suwon_mate_admin_tool`은 수원 메이트 앱을 위한 DB를 생성한다.
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::error::Error;
 use std::fs::File;
-use std::io::{Read, Write};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
 
-use clap::Parser;
-use serde_json::{json, Value};
+use clap::{Args, Parser, Subcommand};
+use ed25519_dalek::{SigningKey, VerifyingKey};
+use rayon::prelude::*;
+use serde_json::{json, Map, Value};
+
+use crate::anomaly::{section_anomalies, DEFAULT_MAX_SECTIONS};
+use crate::anonymize::{anonymize_db, AnonymizeMode, InputAnonymizer};
+use crate::cache::{default_cache_dir, Cache};
+use crate::campus::{departments_by_campus, read_campus_inputs};
+use crate::calendar::academic_calendar;
+use crate::chain::{chain_failures, link_previous};
+use crate::checksum::{content_checksum, write_sidecar};
+use crate::codes::{apply_dept_codes, parse_code_map};
+use crate::compatibility::{apply_matrix, parse_matrix};
+use crate::delta::json_patch;
+use crate::evaluation::merge_evaluations;
+use crate::firebase::{firebase_import, FIREBASE_NODE_PATH};
+use crate::extract::{extract_db, ExtractFilter};
+use crate::fix::fix_input;
+use crate::fuzzy::closest_department;
+use crate::graph::departments_dot;
+use crate::ics::{timetable_ics, IcsOptions};
+use crate::input::dataset::Dataset;
+use crate::input::mapping::ColumnMapping;
+use crate::input::stream::starts_as_plain_json;
+use crate::input::{
+    expand_patterns, input_size, is_url, page_files, read_input, read_inputs, InputEncoding,
+    InputOptions, STDIN_PATH,
+};
+use crate::input_profile::{input_profile, stream_profile};
+use crate::legacy::legacy_db;
+use crate::memory::{enable_tracking, parse_size, MemoryLimit};
+use crate::migration::favorite_mapping;
+use crate::network::RetryPolicy;
+use crate::normalize::normalize_input;
+use crate::office::department_contacts;
+use crate::output::bson::BsonSplit;
+use crate::output::compress::{compress_file, Compression};
+use crate::output::flatbuffers::fbs_schema;
+use crate::output::protobuf::proto_schema;
+use crate::output::sql::SqlDialect;
+use crate::output::{write_db, write_json, OutputFormat, OutputOptions};
+use crate::overrides::{apply_overrides, OVERRIDES_FILE};
+use crate::portal::{
+    collect, parse_param, PortalAuth, PortalAuthKind, PortalRequest, DEFAULT_PAGE_SIZE,
+};
+use crate::preview::preview_html;
+use crate::progress::{Progress, PROGRESS_FILE};
+use crate::prune::prune_null;
+use crate::pull::{pull, PullOptions};
+use crate::qa::{write_qa_sample, QA_SAMPLE_SUFFIX};
+use crate::rebuild::{build_fingerprint, save_build_state, state_file_for, unchanged_result};
+use crate::regress::{golden_options, regress_differences, MAX_REPORTED_DIFFERENCES};
+use crate::remote_config::{sync_db_version, RemoteConfigTarget, DEFAULT_PARAMETER};
+use crate::report::build_report;
+use crate::resources::collect_resources;
+use crate::rules::{apply_rules, RuleSet};
+use crate::schema::{json_schema, schema_document, schema_html, DocsFormat};
+use crate::search::{search_subjects, SearchField};
+use crate::secret::{
+    delete_keyring_secret, keyring_secret, prompt_secret, store_keyring_secret,
+    SIGNING_KEY_ENTRY,
+};
+use crate::signature::{parse_key, read_key_file, sign_db, to_hex, verify_db};
+use crate::size_profile::size_profile;
+use crate::split::{write_split, MANIFEST_FILE};
+use crate::template::{
+    companion_path, local_date, now, parse_utc_offset, render, semester, timestamp,
+};
+use crate::timetable::{timetable_failures, TimetableDictionary};
+use crate::trends::{trend_point, trends_chart, trends_csv, TrendsFormat};
+use crate::validate::{bulk_validate, DEFAULT_PATTERN};
+use crate::version_info::{version_info, VERSION_FILE};
+
+/// 사람이 읽는 안내 메세지를 출력하는 매크로
+///
+/// 평소에는 표준출력으로 출력하며, [platform::set_messages_to_stderr]로 설정한 경우(`--json-summary`)에는
+/// 표준출력에 요약 JSON만 남도록 표준에러로 출력한다.
+#[macro_export]
+macro_rules! notice {
+    ($($arg:tt)*) => {
+        if $crate::platform::messages_to_stderr() {
+            eprintln!($($arg)*);
+        } else {
+            println!($($arg)*);
+        }
+    };
+}
+
+pub mod anomaly;
+pub mod anonymize;
+pub mod cache;
+pub mod calendar;
+pub mod campus;
+pub mod canonical;
+pub mod chain;
+pub mod checksum;
+pub mod codes;
+pub mod compatibility;
+pub mod delta;
+pub mod evaluation;
+pub mod extract;
+pub mod firebase;
+pub mod fix;
+pub mod fuzzy;
+pub mod graph;
+pub mod history;
+pub mod ics;
+pub mod input;
+pub mod input_profile;
+pub mod legacy;
+pub mod memory;
+pub mod migration;
+pub mod network;
+pub mod normalize;
+pub mod office;
+pub mod output;
+pub mod overrides;
+pub mod platform;
+pub mod portal;
+pub mod preview;
+pub mod progress;
+pub mod prune;
+pub mod pull;
+pub mod qa;
+pub mod rebuild;
+pub mod regress;
+pub mod remote_config;
+pub mod report;
+pub mod resources;
+pub mod rules;
+pub mod schema;
+pub mod search;
+pub mod secret;
+pub mod shadow;
+pub mod signature;
+pub mod size_profile;
+pub mod split;
+pub mod template;
+pub mod timetable;
+pub mod trends;
+pub mod validate;
+pub mod version_info;
+
+/// DB에 기록되는 과목 정보의 필드 목록
+///
+/// 열 단위로 과목 정보를 기록하는 출력 형식에서 열의 순서로 사용된다.
+pub const SUBJECT_FIELDS: [&str; 18] = [
+    "estbDpmjNm",
+    "estbMjorNm",
+    "trgtGrdeCd",
+    "subjtNm",
+    "ltrPrfsNm",
+    "deptNm",
+    "facDvnm",
+    "timtSmryCn",
+    "lssnLangNm",
+    "subjtCd",
+    "diclNo",
+    "subjtEstbYear",
+    "point",
+    "cltTerrNm",
+    "sexCdNm",
+    "hffcStatNm",
+    "clsfNm",
+    "capprTypeNm",
+];
 
 /// 수원메이트용 DB제작 프로그램
 ///
 /// 수원 메이트 앱 용으로 사용될 json형태의 DB 파일을 제작할 수 있습니다.
+/// 하위 명령을 지정하면 이미 만들어진 DB 파일을 다루는 작업을 수행할 수 있습니다.
 #[derive(Parser)]
-#[command(author)]
+#[command(author, args_conflicts_with_subcommands = true)]
+pub struct Cli {
+    /// 수행할 하위 명령
+    #[command(subcommand)]
+    pub command: Option<Command>,
+    /// DB 제작에 필요한 인자
+    #[command(flatten)]
+    pub build: Option<ProgramArgument>,
+    /// DB 제작 기록을 남길 JSON Lines 파일 [기본값: <사용자 데이터 디렉토리>/suwon_mate_admin_tool/history.jsonl]
+    #[arg(long)]
+    pub history_file: Option<PathBuf>,
+    /// DB 제작 기록을 남기지 않음
+    #[arg(long)]
+    pub no_history: bool,
+    /// 네트워크 요청에 사용할 프록시 주소 (지정하지 않으면 HTTPS_PROXY, HTTP_PROXY 환경 변수를 따름)
+    #[arg(long, global = true)]
+    pub proxy: Option<String>,
+}
+
+/// DB 파일을 다루는 하위 명령
+#[derive(Subcommand)]
+pub enum Command {
+    /// 이미 만들어진 DB에서 전화번호와 이메일 주소를 제거한 공개용 사본을 생성합니다.
+    Anonymize {
+        /// 개인정보를 제거할 DB 파일
+        db_file: PathBuf,
+        /// 공개용 사본을 저장할 파일 [기본값: <DB 파일명>_anonymized.json]
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+        /// 개인정보 처리 방식
+        #[arg(short, long, value_enum, default_value_t = AnonymizeMode::Mask)]
+        mode: AnonymizeMode,
+    },
+    /// 외부 공유용으로 입력 파일의 교수명, 이메일 주소, 전화번호를 가짜 값으로 바꾼 사본을 생성합니다.
+    ///
+    /// 여러 입력 파일을 함께 지정하면 파일 사이에서도 같은 교수는 같은 가짜 이름으로 바뀝니다.
+    AnonymizeInput {
+        /// 익명화할 입력 파일 목록 (개설 강좌 조회 및 강의 계획서 DB 파일)
+        #[arg(required = true)]
+        input_files: Vec<PathBuf>,
+        /// 사본을 저장할 디렉토리 [기본값: 각 입력 파일과 같은 디렉토리]
+        #[arg(short, long)]
+        output_dir: Option<PathBuf>,
+    },
+    /// ed25519 개인키로 DB에 서명하여 version 항목에 기록합니다.
+    Sign {
+        /// 서명할 DB 파일
+        db_file: PathBuf,
+        /// 16진수로 기록된 ed25519 개인키(시드) 파일 [기본값: 숨김 프롬프트로 입력]
+        #[arg(short, long, conflicts_with = "key_from_keyring")]
+        key: Option<PathBuf>,
+        /// OS 키링에 저장된 개인키 사용 (`secret set`으로 미리 저장 필요)
+        #[arg(long)]
+        key_from_keyring: bool,
+        /// 서명된 DB를 저장할 파일 [기본값: DB 파일 덮어쓰기]
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// --split-output으로 배포된 최신 DB를 매니페스트를 통해 내려받아 로컬에 배치합니다.
+    Pull {
+        /// 매니페스트(manifest.json)의 URL (file:// 경로 가능)
+        manifest_url: String,
+        /// 파일을 배치할 디렉토리
+        #[arg(short, long, default_value = ".")]
+        output: PathBuf,
+        /// 파일의 SHA-256 해시와 크기를 매니페스트와 비교하여 검증
+        #[arg(long)]
+        verify: bool,
+        /// 매니페스트의 서명을 확인할 16진수 ed25519 공개키 파일
+        #[arg(short, long, requires = "verify")]
+        public_key: Option<PathBuf>,
+    },
+    /// ed25519 공개키로 DB의 서명을 검증하여 위변조 여부를 확인합니다.
+    Verify {
+        /// 검증할 DB 파일
+        db_file: PathBuf,
+        /// 16진수로 기록된 ed25519 공개키 파일
+        #[arg(short, long)]
+        public_key: PathBuf,
+    },
+    /// 버전 순서대로 나열된 DB 파일의 버전 체인을 확인하여 중간 버전의 누락이나 변조를 찾습니다.
+    VerifyChain {
+        /// 오래된 버전부터 나열한 DB 파일 목록
+        #[arg(required = true, num_args = 2..)]
+        db_files: Vec<PathBuf>,
+    },
+    /// 디렉토리에 보관 중인 결과 DB 파일을 일괄 검증하여 손상되었거나 스키마를 위반한 파일을 찾습니다.
+    BulkValidate {
+        /// 결과 DB 파일이 보관된 디렉토리
+        directory: PathBuf,
+        /// 검증할 파일명 패턴
+        #[arg(short, long, default_value = DEFAULT_PATTERN)]
+        pattern: String,
+    },
+    /// 입력 파일의 BOM, 문자열 앞뒤 공백, 중복 과목을 자동으로 교정하여 새 입력 파일을 생성합니다.
+    Fix {
+        /// 교정할 입력 파일 (개설 강좌 조회 또는 강의 계획서 DB 파일)
+        input_file: PathBuf,
+        /// 교정된 입력 파일을 저장할 경로 [기본값: <입력 파일명>_fixed.json]
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// 완성된 DB에서 특정 학부나 과목 코드의 과목만 뽑아 작은 DB 파일을 생성합니다.
+    Extract {
+        /// 과목을 뽑을 DB 파일
+        db_file: PathBuf,
+        /// 뽑을 학부명 (여러 번 지정 가능)
+        #[arg(short, long = "department")]
+        departments: Vec<String>,
+        /// 뽑을 과목 코드 또는 과목 키(subjtCd-diclNo) (여러 번 지정 가능)
+        #[arg(short, long = "subject-code")]
+        subject_codes: Vec<String>,
+        /// 추출한 DB를 저장할 파일 [기본값: <DB 파일명>_extract.json]
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// 이전 DB를 새 DB로 바꾸는 JSON Patch(RFC 6902) 델타 파일을 생성합니다.
+    Delta {
+        /// 이전 DB 파일
+        #[arg(short, long)]
+        previous: PathBuf,
+        /// 새 DB 파일
+        #[arg(short, long)]
+        current: PathBuf,
+        /// 델타 파일을 저장할 경로
+        #[arg(short, long, default_value = "delta.json")]
+        output: PathBuf,
+    },
+    /// 버전 순서대로 나열된 DB 파일로부터 과목 수, 교양 과목 비율, 영어 강의 비율의 추세를 계산합니다.
+    Trends {
+        /// 오래된 버전부터 나열한 DB 파일 목록
+        #[arg(required = true)]
+        db_files: Vec<PathBuf>,
+        /// 출력 형식
+        #[arg(short, long, value_enum, default_value_t = TrendsFormat::Csv)]
+        format: TrendsFormat,
+        /// 통계를 저장할 파일 [기본값: trends.csv 또는 trends.json]
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// OS 키링에 민감한 값(서명 키 등)을 저장하거나 지웁니다.
+    Secret {
+        /// 수행할 작업
+        #[command(subcommand)]
+        action: SecretAction,
+    },
+    /// 결과 DB의 구조와 필드 설명을 담은 스키마 문서를 생성합니다.
+    GenerateDocs {
+        /// 문서 형식
+        #[arg(short, long, value_enum, default_value_t = DocsFormat::Html)]
+        format: DocsFormat,
+        /// 문서를 저장할 파일 [기본값: schema.html, schema.json, suwon_mate.proto 또는 suwon_mate.fbs]
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// 결과 DB에서 과목명 또는 교수명에 검색어가 포함된 과목을 찾아 보여줍니다.
+    Grep {
+        /// 검색할 DB 파일 (JSON 형식)
+        db_file: PathBuf,
+        /// 찾을 문자열
+        pattern: String,
+        /// 검색할 필드
+        #[arg(short, long, value_enum, default_value_t = SearchField::All)]
+        field: SearchField,
+        /// 영문 대소문자를 구분하지 않음
+        #[arg(short, long)]
+        ignore_case: bool,
+        /// 찾은 과목을 한 줄에 하나씩 JSON으로 출력
+        #[arg(long)]
+        json: bool,
+    },
+    /// 결과 DB에서 섹션과 과목 필드별로 차지하는 용량을 분석하여 표로 보여줍니다.
+    SizeProfile {
+        /// 분석할 DB 파일 (JSON 형식)
+        db_file: PathBuf,
+        /// 분석 결과를 Markdown 파일로 저장할 경로 [기본값: 화면에 출력]
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// 포털 개설강좌 조회 API의 모든 페이지를 받아 하나의 입력 파일로 저장합니다.
+    ///
+    /// 받은 원본은 캐시 디렉토리(~/.cache/suwon_mate_admin_tool/)에 학기와 날짜별로 함께 저장됩니다.
+    Fetch {
+        /// 개설강좌 조회 API의 URL
+        url: String,
+        /// 페이지 번호와 페이지 크기 외에 함께 보낼 폼 인자 (예: --param sYear=2024, 여러 번 지정 가능)
+        #[arg(long = "param", value_parser = parse_param)]
+        params: Vec<(String, String)>,
+        /// 한 페이지에 요청할 과목 수
+        #[arg(long, default_value_t = DEFAULT_PAGE_SIZE)]
+        page_size: usize,
+        /// 응답에서 과목 목록이 들어있는 키 [기본값: estbLectDtaiList 또는 자동 탐색]
+        #[arg(long, visible_alias = "root-key")]
+        source_root_key: Option<String>,
+        /// 캐시에 저장할 학기 (예: 2024-1학기) [기본값: 받은 원본의 개설 연도와 학기]
+        #[arg(long)]
+        semester: Option<String>,
+        /// 네트워크를 사용하지 않고 캐시에 저장된 가장 최근의 원본을 사용
+        #[arg(long)]
+        offline: bool,
+        /// 요청이 일시적으로 실패한 경우 다시 시도할 최대 횟수
+        #[arg(long, default_value_t = RetryPolicy::default().max_retries)]
+        max_retries: u32,
+        /// 첫 재시도 전의 대기 시간(밀리초, 재시도할 때마다 두 배로 늘어남)
+        #[arg(long, default_value_t = 500)]
+        retry_backoff_ms: u64,
+        /// 페이지 요청 사이의 지연 시간(밀리초)
+        #[arg(long, default_value_t = 200)]
+        request_delay_ms: u64,
+        /// 요청에 포함할 자격 증명 (PORTAL_TOKEN, PORTAL_COOKIE 환경 변수 또는 키링에서 읽음)
+        #[arg(long, value_enum)]
+        auth: Option<PortalAuthKind>,
+        /// 저장할 입력 파일
+        #[arg(short, long, default_value = "open_class.json")]
+        output: PathBuf,
+    },
+    /// 입력 파일의 과목 필드별 고유값 개수, 최빈값, null 비율을 표로 보여줍니다.
+    ProfileInput {
+        /// 분석할 입력 파일 (개설 강좌 조회 또는 강의 계획서 DB 파일)
+        input_file: PathBuf,
+        /// 입력 파일에서 과목 목록이 들어있는 키 [기본값: estbLectDtaiList 또는 자동 탐색]
+        #[arg(long, visible_alias = "root-key")]
+        source_root_key: Option<String>,
+        /// 분석 결과를 Markdown 파일로 저장할 경로 [기본값: 화면에 출력]
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// 기준 입력으로 DB를 만들어 기준 출력(golden)과 의미론적으로 같은지 확인합니다.
+    ///
+    /// 기준 출력의 version 항목과 같은 버전으로 DB를 만들며, 과목과 학과의 순서와 version 항목은 비교하지 않습니다.
+    Regress {
+        /// 기준 출력 DB 파일
+        #[arg(long)]
+        golden: PathBuf,
+        /// 기준 입력 개설 강좌 조회 DB 파일
+        #[arg(short, long)]
+        open_class_file: PathBuf,
+        /// 기준 입력 강의 계획서 DB 파일
+        #[arg(short, long)]
+        class_todo_file: PathBuf,
+        /// 분류에 실패한 학부명이 기존 학부명과 한두 글자만 다른 경우 기존 학부로 병합
+        #[arg(long)]
+        auto_fuzzy_merge: bool,
+        /// 비교하지 않고 현재 결과로 기준 출력을 새로 저장
+        #[arg(long)]
+        update: bool,
+    },
+    /// 앱 개발자가 결과 DB를 검증하거나 참조할 수 있는 JSON Schema를 생성합니다.
+    Schema {
+        /// JSON Schema를 저장할 파일
+        #[arg(short, long, default_value = "suwon_mate.schema.json")]
+        output: PathBuf,
+    },
+    /// 이전 학기 DB와 비교하여 즐겨찾기 이관용 과목 키 매핑 테이블을 생성합니다.
+    FavoriteMap {
+        /// 이전 학기 DB 파일
+        #[arg(short, long)]
+        previous: PathBuf,
+        /// 새 학기 DB 파일
+        #[arg(short, long)]
+        current: PathBuf,
+        /// 매핑 테이블을 저장할 파일
+        #[arg(short, long, default_value = "favorite_map.json")]
+        output: PathBuf,
+    },
+    /// DB에 포함된 시간표 문자열을 해석하여 해석에 실패한 과목 목록을 생성합니다.
+    TimetableCheck {
+        /// 검사할 DB 파일
+        db_file: PathBuf,
+        /// 기본 사전에 추가할 시간표 예외 표기 사전 파일
+        #[arg(short, long)]
+        dictionary: Option<PathBuf>,
+        /// 해석에 실패한 과목 목록을 저장할 파일
+        #[arg(short, long, default_value = "timetable_failures.json")]
+        output: PathBuf,
+    },
+    /// DB의 시간표로 학과 또는 교수의 강의 일정을 주 단위 반복 일정의 iCalendar(ICS) 파일로 내보냅니다.
+    Ics {
+        /// 시간표를 가져올 DB 파일
+        db_file: PathBuf,
+        /// 개설 학부명 또는 학과명이 일치하는 과목만 내보냅니다.
+        #[arg(long)]
+        department: Option<String>,
+        /// 교수명이 일치하는 과목만 내보냅니다.
+        #[arg(long)]
+        professor: Option<String>,
+        /// 학기 시작일 (YYYY-MM-DD)
+        #[arg(long)]
+        start: String,
+        /// 반복할 주의 수
+        #[arg(long, default_value_t = 16)]
+        weeks: u32,
+        /// 기본 사전에 추가할 시간표 예외 표기 사전 파일
+        #[arg(short, long)]
+        dictionary: Option<PathBuf>,
+        /// ICS 파일을 저장할 경로
+        #[arg(short, long, default_value = "timetable.ics")]
+        output: PathBuf,
+    },
+}
+
+/// 키링 항목을 다루는 작업
+#[derive(Subcommand)]
+pub enum SecretAction {
+    /// 숨김 프롬프트로 값을 입력받아 키링에 저장합니다.
+    Set {
+        /// 키링 항목 이름
+        #[arg(default_value = SIGNING_KEY_ENTRY)]
+        name: String,
+    },
+    /// 키링에 저장된 값을 지웁니다.
+    Delete {
+        /// 키링 항목 이름
+        #[arg(default_value = SIGNING_KEY_ENTRY)]
+        name: String,
+    },
+}
+
+/// DB 제작에 필요한 프로그램 인자
+#[derive(Args)]
 pub struct ProgramArgument {
-    /// 개설 강좌 조회 DB 파일
-    #[arg(short, long)]
-    pub open_class_file: String,
-    /// 강의 계획서 DB 파일
+    /// 개설 강좌 조회 DB 파일 (여러 번 지정하거나 data/open_*.json과 같은 글롭 패턴 지정 가능, -는 표준입력)
+    #[arg(short, long, required = true)]
+    pub open_class_file: Vec<PathBuf>,
+    /// 강의 계획서 DB 파일 (-는 표준입력)
     #[arg(short, long)]
-    pub class_todo_file: String,
+    pub class_todo_file: PathBuf,
     /// DB에 기입할 최신 앱 버전
     #[arg(short, long, default_value_t = String::from("1.0"))]
     pub app_version: String,
@@ -30,6 +517,222 @@ pub struct ProgramArgument {
     /// DB에 기입할 레거시 앱 버전
     #[arg(short, long, default_value_t = String::from("1.0"))]
     pub legacy_app_version: String,
+    /// 작업 완료 후 결과 요약을 한 줄의 JSON으로 출력 (진행 메세지는 표준에러로 출력)
+    #[arg(long)]
+    pub json_summary: bool,
+    /// 결과 파일명 템플릿 (예: db_{semester}_{db_version}_{date}.json) [기본값: result_{db_version}.{format}]
+    #[arg(long)]
+    pub output_template: Option<String>,
+    /// 결과 파일명 템플릿의 {semester} 값 [기본값: 강의계획서의 개설 연도와 학기]
+    #[arg(long)]
+    pub semester: Option<String>,
+    /// 메모리 사용량의 상한 (예: 512M, 2G). 넘을 것으로 예상되거나 실제로 넘으면 오류를 내고 작업을 중단
+    #[arg(long)]
+    pub max_memory: Option<String>,
+    /// 빌드 시각(version.built_at)과 결과 파일명 템플릿의 {date}에 사용할 시간대 (KST, UTC, +09:00 등)
+    #[arg(long, default_value_t = String::from("KST"))]
+    pub timezone: String,
+    /// 결과 DB 파일의 출력 형식
+    #[arg(long, value_enum, default_value_t = OutputFormat::Json)]
+    pub format: OutputFormat,
+    /// 결과 DB 파일의 압축 방식
+    #[arg(long, value_enum, default_value_t = Compression::None)]
+    pub compress: Compression,
+    /// 압축 레벨 (gzip: 0~9, zstd: 1~22, br: 0~11) [기본값: 압축 방식의 기본 레벨]
+    #[arg(long, allow_negative_numbers = true)]
+    pub compress_level: Option<i32>,
+    /// DB의 version 항목과 결과 파일의 체크섬을 담은 버전 정보 파일을 함께 저장 (예: version.json)
+    #[arg(long, num_args = 0..=1, default_missing_value = VERSION_FILE)]
+    pub version_file: Option<PathBuf>,
+    /// 결과 DB에서 값이 null인 필드를 생략하여 용량을 줄임
+    #[arg(long)]
+    pub prune_null: bool,
+    /// 결과 파일 옆에 SHA-256 체크섬 파일(result_x.json.sha256)을 만들고 version.content_sha256에 DB 내용의 해시를 기록
+    #[arg(long)]
+    pub checksum: bool,
+    /// 교수 연락처(contacts)를 결과 DB에서 분리하여 contacts_[DB버전].json 파일로 따로 저장
+    #[arg(long)]
+    pub separate_contacts: bool,
+    /// 결과 DB를 firebase database:set에 바로 사용할 수 있도록 노드 경로로 감싸서 저장 (JSON 출력 전용)
+    #[arg(long, num_args = 0..=1, default_missing_value = FIREBASE_NODE_PATH)]
+    pub firebase_import: Option<String>,
+    /// DB를 학부별 과목 파일과 색인 파일로 나누고 manifest.json을 함께 저장할 디렉토리
+    #[arg(long)]
+    pub split_output: Option<PathBuf>,
+    /// 분할 출력의 매니페스트에 기록할 파일 URL의 기준 경로 (예: https://example.com/db)
+    #[arg(long, requires = "split_output")]
+    pub manifest_base_url: Option<String>,
+    /// JSON 출력 시 키 순서와 숫자 표현이 항상 같은 RFC 8785 canonical JSON으로 기록 (서명·해시 비교용)
+    #[arg(long)]
+    pub canonical: bool,
+    /// CSV 출력 시 파일 앞에 UTF-8 BOM을 기록 (한국어 Excel 호환용)
+    #[arg(long)]
+    pub csv_bom: bool,
+    /// SQL 덤프 출력 시 대상 데이터베이스
+    #[arg(long, value_enum, default_value_t = SqlDialect::Postgres)]
+    pub sql_dialect: SqlDialect,
+    /// BSON 출력 시 도큐먼트를 나누는 기준
+    #[arg(long, value_enum, default_value_t = BsonSplit::Single)]
+    pub bson_split: BsonSplit,
+    /// 입력 파일명이 숫자로 끝나는 경우(page1.json 등) 이어지는 페이지 파일을 찾아 하나로 합침
+    #[arg(long)]
+    pub merge_pages: bool,
+    /// 입력 파일의 문자 인코딩 (auto: UTF-8로 해독할 수 없으면 CP949로 해독)
+    #[arg(long, value_enum, default_value_t = InputEncoding::Auto)]
+    pub input_encoding: InputEncoding,
+    /// JSON 입력 파일의 // 및 /* */ 주석과 후행 쉼표를 허용
+    #[arg(long)]
+    pub lenient_json: bool,
+    /// URL 입력을 내려받지 않고 캐시(~/.cache/suwon_mate_admin_tool/)에 저장된 가장 최근의 원본만으로 빌드
+    #[arg(long)]
+    pub offline: bool,
+    /// 입력 파일을 메모리에 매핑하여 읽음 (수백 MB의 입력 파일용, 빌드 도중 입력 파일을 고치면 안 됨)
+    #[arg(long)]
+    pub mmap_input: bool,
+    /// 입력 파일에서 과목 목록이 들어있는 키 (예: list, data.list) [기본값: 입력 매핑 파일의 root_key, estbLectDtaiList 또는 자동 탐색]
+    #[arg(long, visible_alias = "root-key")]
+    pub source_root_key: Option<String>,
+    /// 입력 파일의 과목 목록 키와 CSV, XLSX, XML 입력 파일의 열 매핑을 담은 YAML 파일 (예: mapping.yaml)
+    #[arg(long)]
+    pub input_mapping: Option<PathBuf>,
+    /// 개설 강좌 조회 DB 파일마다 지정하는 캠퍼스 이름 (-o와 같은 순서로 지정하며, 과목에 campus 필드로 기록)
+    #[arg(long)]
+    pub campus: Vec<String>,
+    /// 캠퍼스별 학부·학과 목록을 departments_by_campus 항목으로 함께 기록
+    #[arg(long, requires = "campus")]
+    pub campus_departments: bool,
+    /// 입력 파일의 데이터 세트 (대학원, 계절학기는 필드 구성이 달라 학부 필드로 옮긴 뒤 처리)
+    #[arg(long, value_enum, default_value_t = Dataset::Undergrad)]
+    pub dataset: Dataset,
+    /// 자동 매칭보다 우선 적용할 과목 키(subjtCd-diclNo)별 학부, 학과, 연락처 보정 파일 (예: overrides.json)
+    #[arg(long, num_args = 0..=1, default_missing_value = OVERRIDES_FILE)]
+    pub overrides: Option<PathBuf>,
+    /// 빌드된 DB에 적용할 과목 포함/제외 규칙 YAML 파일 (예: rules.yaml)
+    #[arg(long)]
+    pub rules: Option<PathBuf>,
+    /// 과목 정보에 평점(rating)을 병합할 강의평가 요약 파일
+    #[arg(long)]
+    pub evaluation_file: Option<PathBuf>,
+    /// DB 버전별 최소 앱 버전을 선언한 호환성 매트릭스 YAML 파일
+    #[arg(long)]
+    pub compatibility_matrix: Option<PathBuf>,
+    /// version 항목에 버전과 해시를 기록할 직전 버전의 JSON DB 파일
+    #[arg(long)]
+    pub previous_db: Option<PathBuf>,
+    /// 강의계획서의 첨부파일 및 URL 항목을 모아 과목 키별 resources 섹션으로 기록
+    #[arg(long)]
+    pub collect_resources: bool,
+    /// DB에 academic_calendar 섹션으로 기록할 학사일정 JSON 파일
+    #[arg(long)]
+    pub academic_calendar: Option<PathBuf>,
+    /// 학부 → 학과 → 과목 트리로 DB를 탐색할 수 있는 미리보기 HTML 파일
+    #[arg(long)]
+    pub preview: Option<PathBuf>,
+    /// 학부 → 학과 관계를 Graphviz로 시각화할 수 있는 dot 파일
+    #[arg(long)]
+    pub dot: Option<PathBuf>,
+    /// 학부별로 지정한 개수만큼 무작위 과목을 뽑아 결과 파일 옆에 검수 시트(result_[DB버전]_qa_sample.csv)로 저장
+    #[arg(long)]
+    pub qa_sample: Option<usize>,
+    /// 검수 시트의 과목을 뽑을 때 사용할 난수 시드 (같은 DB와 시드로는 항상 같은 과목이 뽑힘) [기본값: 현재 시각]
+    #[arg(long, requires = "qa_sample")]
+    pub seed: Option<u64>,
+    /// 과목 하나에 허용되는 분반 수 (초과하면 경고)
+    #[arg(long, default_value_t = DEFAULT_MAX_SECTIONS)]
+    pub max_sections: usize,
+    /// 분류에 실패한 학부명이 기존 학부명과 한두 글자만 다른 경우 기존 학부로 병합
+    #[arg(long)]
+    pub auto_fuzzy_merge: bool,
+    /// 학부별 과목 수, 분류 실패 목록, 연락처 누락 목록을 담은 Markdown 리포트를 저장할 파일
+    #[arg(long)]
+    pub report: Option<PathBuf>,
+    /// DB에 department_contacts 섹션으로 기록할 학과 사무실 연락처 JSON 파일
+    #[arg(long)]
+    pub department_contacts: Option<PathBuf>,
+    /// 과목 정보에 dept_code를, DB에 department_codes 섹션을 기록할 학부·학과 공식 코드 매핑 JSON 파일
+    #[arg(long)]
+    pub dept_codes: Option<PathBuf>,
+    /// 예전 버전 앱을 위한 구 스키마 DB를 결과 파일명 뒤에 _legacy를 붙인 JSON 파일(result_[DB버전]_legacy.json)로 함께 저장
+    #[arg(long)]
+    pub legacy_output: bool,
+    /// 빌드 단계, 백분율, 경고 수를 다른 프로세스가 읽을 수 있도록 기록할 진행 상태 파일 (예: progress.json)
+    #[arg(long, num_args = 0..=1, default_missing_value = PROGRESS_FILE)]
+    pub progress_file: Option<PathBuf>,
+    /// 기존 구현과 구조체 기반 구현을 동시에 실행하여 결과가 같은지 검증 (임시 기능)
+    #[arg(long)]
+    pub shadow_build: bool,
+    /// 입력 파일과 옵션이 직전 빌드와 같아도 DB를 다시 만듦
+    #[arg(long)]
+    pub force_rebuild: bool,
+    /// 빌드 성공 시 DB 버전을 갱신할 원격 설정 (firebase:<프로젝트 ID> 또는 API URL)
+    #[arg(long)]
+    pub remote_config: Option<String>,
+    /// 원격 설정에서 DB 버전을 기록할 매개변수 이름
+    #[arg(long, default_value = DEFAULT_PARAMETER, requires = "remote_config")]
+    pub remote_config_parameter: String,
+}
+
+/// DB 제작 결과를 요약한 구조체이다.
+#[derive(Default, PartialEq, Debug)]
+pub struct BuildSummary {
+    /// DB에 기록된 과목의 수
+    pub subject_count: usize,
+    /// DB 제작 과정에서 발생한 경고 메세지 목록
+    pub warnings: Vec<String>,
+    /// 분류에 실패한 과목의 학부명 목록 (과목 하나당 한 번씩 기록)
+    pub unclassified_departments: Vec<String>,
+}
+
+/// DB 제작에 사용되는 옵션을 담은 구조체이다.
+///
+/// 옵션이 늘어나도 [make_db_content] 계열 메서드의 인자가 늘어나지 않도록 모든 옵션을 이 구조체로 전달한다.
+/// 필요한 옵션만 지정하고 나머지는 `..Default::default()`로 채워서 사용할 수 있다.
+///
+/// ## Examples
+/// ```
+/// use suwon_mate_admin_tool::BuildOptions;
+/// let options = BuildOptions {
+///     db_version: String::from("2.0"),
+///     ..Default::default()
+/// };
+/// assert_eq!(options.latest_app_version, "1.0");
+/// assert!(!options.quick_mode);
+/// ```
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct BuildOptions {
+    /// DB에 기입할 최신 앱 버전
+    pub latest_app_version: String,
+    /// DB에 기입할 DB 버전
+    pub db_version: String,
+    /// DB에 기입할 레거시 앱 버전
+    pub legacy_app_version: String,
+    /// 불완전한 DB 파일 만들기 기능 활성화 여부
+    pub quick_mode: bool,
+    /// 분류에 실패한 학부를 이름이 비슷한 기존 학부로 병합할지 여부
+    pub auto_fuzzy_merge: bool,
+    /// 과목을 나누어 처리할 때마다 확인할 메모리 사용량의 상한
+    pub memory_limit: Option<MemoryLimit>,
+}
+
+impl Default for BuildOptions {
+    fn default() -> Self {
+        Self {
+            latest_app_version: String::from("1.0"),
+            db_version: String::from("1.0"),
+            legacy_app_version: String::from("1.0"),
+            quick_mode: false,
+            auto_fuzzy_merge: false,
+            memory_limit: None,
+        }
+    }
+}
+
+impl BuildSummary {
+    /// 경고 메세지를 출력하고 목록에 추가하는 메서드
+    fn warn(&mut self, message: String) {
+        notice!("{}", message);
+        self.warnings.push(message);
+    }
 }
 
 
@@ -79,7 +782,7 @@ impl<'todo_class> ClassTodo<'todo_class> {
     ///
     /// ## Arguments
     ///
-    /// * `subjects` - `Value` 벡터형의 과목들에 대한 정보
+    /// * `subjects` - `Value` 슬라이스 형의 과목들에 대한 정보
     /// * `subject_code` - 스트링 슬라이스 형의 학부를 찾고자 하는 과목의 과목 코드
     /// * `dicl_number` - 스트링 슬라이스 형의 학부를 찾고자 하는 과목의 분반 코드
     ///
@@ -105,16 +808,15 @@ impl<'todo_class> ClassTodo<'todo_class> {
     ///         )
     ///```
     pub fn get_department_info(
-        subjects: &'todo_class Vec<Value>,
+        subjects: &'todo_class [Value],
         subject_code: &str,
         dicl_number: &str,
     ) -> Self {
         for subject in subjects.iter() {
-            if format!(
-                "{}-{}",
+            if (
                 subject["subjtCd"].as_str().unwrap_or(""),
-                subject["diclNo"].as_str().unwrap_or("")
-            ) == format!("{}-{}", subject_code, dicl_number)
+                subject["diclNo"].as_str().unwrap_or(""),
+            ) == (subject_code, dicl_number)
             {
                 return Self::new(
                     &subject["estbDpmjNm"],
@@ -128,6 +830,199 @@ impl<'todo_class> ClassTodo<'todo_class> {
     }
 }
 
+/// 강의 계획서의 과목을 과목 코드와 분반 코드로 찾는 색인
+///
+/// 개설 강좌마다 강의 계획서의 모든 과목을 훑는 [ClassTodo::get_department_info] 대신 한 번 만든 색인으로 과목을 찾는다.
+/// 같은 과목 코드와 분반 코드의 과목이 여럿인 경우 [ClassTodo::get_department_info]와 같이 처음 나온 과목을 사용한다.
+pub struct TodoIndex<'todo_class> {
+    /// 강의 계획서의 과목 목록
+    subjects: &'todo_class [Value],
+    /// (과목 코드, 분반 코드)를 키로 하는 `subjects`에서의 과목 위치
+    index: HashMap<(&'todo_class str, &'todo_class str), usize>,
+}
+
+impl<'todo_class> TodoIndex<'todo_class> {
+    /// **강의 계획서의** 과목 목록인 `subjects`로 [TodoIndex]를 생성한다.
+    ///
+    /// ## Examples
+    /// ```
+    /// use serde_json::{json, Value};
+    /// use suwon_mate_admin_tool::{ClassTodo, TodoIndex};
+    /// let subjects = vec![
+    ///     json!({"subjtCd": "11416", "diclNo": "038", "estbDpmjNm": "경영학부", "email": "a@suwon.ac.kr"}),
+    ///     json!({"subjtCd": "11416", "diclNo": "038", "estbDpmjNm": "공과대학"}),
+    /// ];
+    /// let index = TodoIndex::new(&subjects);
+    /// assert_eq!(index.len(), 1);
+    /// assert_eq!(index.get("11416", "038"), ClassTodo::get_department_info(&subjects, "11416", "038"));
+    /// assert_eq!(index.get("11416", "001").department, &Value::Null);
+    /// ```
+    pub fn new(subjects: &'todo_class [Value]) -> Self {
+        let mut index = HashMap::with_capacity(subjects.len());
+        for (position, subject) in subjects.iter().enumerate() {
+            index
+                .entry((
+                    subject["subjtCd"].as_str().unwrap_or(""),
+                    subject["diclNo"].as_str().unwrap_or(""),
+                ))
+                .or_insert(position);
+        }
+        Self { subjects, index }
+    }
+
+    /// 색인된 과목 키의 수
+    pub fn len(&self) -> usize {
+        self.index.len()
+    }
+
+    /// 색인된 과목이 없는지 여부
+    pub fn is_empty(&self) -> bool {
+        self.index.is_empty()
+    }
+
+    /// 과목 코드와 분반 코드로 과목의 학부, 학과, 이메일 주소, 전화번호를 찾는 메서드
+    ///
+    /// 과목을 찾지 못한 경우 모든 항목이 `Null`인 [ClassTodo]를 반환한다.
+    /// 과목 코드와 분반 코드를 문자열로 복사하지 않고 그대로 비교한다.
+    pub fn get(&self, subject_code: &str, dicl_number: &str) -> ClassTodo<'todo_class> {
+        match self.index.get(&(subject_code, dicl_number)) {
+            Some(&position) => {
+                let subject = &self.subjects[position];
+                ClassTodo::new(
+                    &subject["estbDpmjNm"],
+                    &subject["estbMjorNm"],
+                    &subject["email"],
+                    &subject["mpno"],
+                )
+            }
+            None => ClassTodo::new(&Value::Null, &Value::Null, &Value::Null, &Value::Null),
+        }
+    }
+}
+
+/// 과목 정보로부터 앱에서 과목을 구분하는 데 사용하는 과목 키(`subjtCd-diclNo`)를 만드는 메서드
+///
+/// ## Examples
+/// ```
+/// use serde_json::json;
+/// use suwon_mate_admin_tool::subject_key;
+/// assert_eq!(subject_key(&json!({"subjtCd": "11416", "diclNo": "038"})), "11416-038");
+/// ```
+pub fn subject_key(subject: &Value) -> String {
+    format!(
+        "{}-{}",
+        subject["subjtCd"].as_str().unwrap_or(""),
+        subject["diclNo"].as_str().unwrap_or("")
+    )
+}
+
+/// 병렬로 처리할 때 한 작업에 맡기는 개설 강좌의 수
+const SUBJECT_CHUNK_SIZE: usize = 512;
+
+/// 개설 강좌 일부를 처리한 결과
+///
+/// 여러 스레드에서 만든 결과를 개설 강좌의 순서대로 합치므로, 병렬로 처리하더라도 결과 DB와 경고의 순서는 순차 처리와 같다.
+/// 학부명과 교수명은 입력 파일의 문자열을 빌려 사용하므로 과목마다 복사하지 않는다.
+#[derive(Default)]
+struct PartialBuild<'a> {
+    /// 학부별 학과 목록
+    departments: HashMap<&'a str, BTreeSet<&'a str>>,
+    /// 학부와 DB에 기록할 과목 정보 (개설 강좌 순서)
+    subjects: Vec<(&'a str, Value)>,
+    /// 학부, 교수명과 교수 연락처 (개설 강좌 순서)
+    contacts: Vec<(&'a str, &'a str, Value)>,
+    /// 경고 메세지 목록
+    warnings: Vec<String>,
+    /// 분류에 실패한 과목의 학부명 목록
+    unclassified_departments: Vec<String>,
+}
+
+/// 개설 강좌 일부의 학부, 학과, 연락처를 찾아 [PartialBuild]를 만드는 메서드
+///
+/// `departments_set`은 강의 계획서의 학부 목록으로, 목록에 없는 학부의 과목은 분류에 실패한 과목으로 기록한다.
+fn process_subjects<'a>(
+    subjects: &'a [Value],
+    todo_index: &TodoIndex<'a>,
+    departments_set: &BTreeSet<&'a str>,
+    auto_fuzzy_merge: bool,
+) -> PartialBuild<'a> {
+    let mut partial = PartialBuild::default();
+    for subject in subjects.iter() {
+        let temp = todo_index.get(
+            subject["subjtCd"].as_str().unwrap_or(""),
+            subject["diclNo"].as_str().unwrap_or(""),
+        );
+        let mut department = temp.department.as_str().unwrap();
+        let suggestion = if departments_set.contains(department) {
+            None
+        } else {
+            closest_department(department, departments_set.iter().copied())
+        };
+        if let (Some(candidate), true) = (suggestion, auto_fuzzy_merge) {
+            partial.warnings.push(format!(
+                "주의: 분류에 실패한 학부({})를 이름이 비슷한 기존 학부({})로 병합하였습니다.",
+                department, candidate
+            ));
+            department = candidate;
+        }
+        if let Some(major) = temp.major.as_str() {
+            partial
+                .departments
+                .entry(department)
+                .or_default()
+                .insert(major);
+        }
+        if !departments_set.contains(department) {
+            let hint = suggestion
+                .map(|candidate| format!(" 혹시 {} 아닌가요?", candidate))
+                .unwrap_or_default();
+            partial.warnings.push(format!(
+                "주의: 분류에 실패한 학부 및 학과가 존재합니다. ({}){}",
+                department, hint
+            ));
+            partial
+                .unclassified_departments
+                .push(department.to_string());
+            continue;
+        }
+        let mut record = json!({
+            "trgtGrdeCd": subject["trgtGrdeCd"],
+            "subjtNm": subject["subjtNm"],
+            "ltrPrfsNm" : subject["ltrPrfsNm"],
+            "deptNm" : subject["deptNm"],
+            "facDvnm" : subject["facDvnm"],
+            "timtSmryCn" : subject["timtSmryCn"],
+            "lssnLangNm" : subject["lssnLangNm"],
+            "subjtCd": subject["subjtCd"],
+            "diclNo" : subject["diclNo"],
+            "subjtEstbYear" : subject["subjtEstbYear"],
+            "point" : subject["point"],
+            "cltTerrNm" : subject["cltTerrNm"],
+            "sexCdNm" : subject["sexCdNm"],
+            "hffcStatNm" : subject["hffcStatNm"],
+            "clsfNm": subject["clsfNm"],
+            "capprTypeNm" : subject["capprTypeNm"],
+            "estbDpmjNm": department,
+            "estbMjorNm": temp.major,
+        });
+        if !subject["campus"].is_null() {
+            record["campus"] = subject["campus"].clone();
+        }
+        partial.subjects.push((department, record));
+        if let Some(name) = subject["ltrPrfsNm"].as_str() {
+            partial.contacts.push((
+                department,
+                name,
+                json!({
+                    "email": temp.email,
+                    "mpno": temp.phone
+                }),
+            ));
+        }
+    }
+    partial
+}
+
 /// 지정된 파일을 읽고 쓰는 작업을 진행하는 메서드
 ///
 /// `program_args`로부터 필요한 인자값을 받아서 파일을 읽고 작업 후 파일을 쓰는 작업을 진행한다.
@@ -142,49 +1037,935 @@ impl<'todo_class> ClassTodo<'todo_class> {
 /// ## Panics
 /// 파일의 쓰기권한이 부여되지 않은 경우 해당 메서드는 호출될 수 없다.
 pub fn file_process(program_args: ProgramArgument) -> Result<(), Box<dyn Error>> {
-    let mut open_class_file = File::open(program_args.open_class_file)?;
-    let mut class_todo_file = File::open(program_args.class_todo_file)?;
-    let mut open_class_content = String::new();
-    let mut class_todo_content = String::new();
-    open_class_file.read_to_string(&mut open_class_content)?;
-    class_todo_file.read_to_string(&mut class_todo_content)?;
-    let mut db_file = File::create(format!("result_{}.json", program_args.db_version))
-        .unwrap_or_else(|error| {
-            println!(
-                "다음과 같은 이유로 DB 파일 생성에 실패하였습니다: {}",
-                error
+    platform::set_messages_to_stderr(program_args.json_summary);
+    let progress = Progress::new(program_args.progress_file.clone());
+    let result = build_process(program_args, &progress);
+    if let Err(error) = &result {
+        progress.fail(&error.to_string());
+    }
+    result
+}
+
+/// 입력 파일을 읽기 전에 DB 제작에 사용할 모든 입력 파일의 크기를 더하는 메서드
+///
+/// 글롭 패턴과 일치하는 파일과 `--merge-pages`로 합칠 페이지 파일을 모두 포함하며, 압축 파일은 압축을 푼 크기로 센다.
+/// URL과 표준입력은 크기를 미리 알 수 없으므로 세지 않는다.
+///
+/// ## Errors
+/// 글롭 패턴이 올바르지 않거나 일치하는 파일이 없는 경우 오류가 발생한다.
+fn total_input_size(program_args: &ProgramArgument) -> Result<u64, Box<dyn Error>> {
+    let mut files = expand_patterns(&program_args.open_class_file)?;
+    files.push(program_args.class_todo_file.clone());
+    if program_args.merge_pages {
+        files = files.iter().flat_map(|file| page_files(file)).collect();
+    }
+    files.extend(
+        [
+            &program_args.rules,
+            &program_args.evaluation_file,
+            &program_args.academic_calendar,
+            &program_args.department_contacts,
+            &program_args.dept_codes,
+            &program_args.compatibility_matrix,
+            &program_args.previous_db,
+        ]
+        .into_iter()
+        .flatten()
+        .cloned(),
+    );
+    // 읽을 수 없는 파일은 크기를 세지 않고, 파일을 읽는 단계에서 원래의 오류를 내도록 한다.
+    Ok(files.iter().map(|file| input_size(file).unwrap_or(0)).sum())
+}
+
+/// [file_process]의 실제 작업을 진행하며 단계마다 `progress`에 진행 상태를 기록하는 메서드
+fn build_process(
+    program_args: ProgramArgument,
+    progress: &Progress,
+) -> Result<(), Box<dyn Error>> {
+    if program_args.firebase_import.is_some() && program_args.format != OutputFormat::Json {
+        return Err("--firebase-import 옵션은 JSON 출력에서만 사용할 수 있습니다.".into());
+    }
+    let remote_config = program_args
+        .remote_config
+        .as_deref()
+        .map(RemoteConfigTarget::parse)
+        .transpose()?;
+    let built_at = now();
+    let utc_offset = parse_utc_offset(&program_args.timezone)?;
+    let memory_limit = match &program_args.max_memory {
+        Some(max_memory) => {
+            enable_tracking();
+            Some(MemoryLimit(parse_size(max_memory)?))
+        }
+        None => None,
+    };
+    let mapping = match &program_args.input_mapping {
+        Some(path) => ColumnMapping::from_yaml(&std::fs::read_to_string(path)?)?,
+        None => ColumnMapping::default(),
+    };
+    let input_options = InputOptions {
+        merge_pages: program_args.merge_pages,
+        root_key: program_args
+            .source_root_key
+            .clone()
+            .or_else(|| mapping.root_key.clone()),
+        encoding: program_args.input_encoding,
+        lenient_json: program_args.lenient_json,
+        cache: default_cache_dir().ok().map(|directory| Cache::new(&directory)),
+        offline: program_args.offline,
+        mapping,
+        dataset: program_args.dataset,
+        mmap: program_args.mmap_input,
+    };
+    if let Some(memory_limit) = memory_limit {
+        memory_limit.check_inputs(total_input_size(&program_args)?)?;
+    }
+    progress.update("입력 파일 읽기", 0, 0);
+    let open_class_content = if program_args.campus.is_empty() {
+        read_inputs(&program_args.open_class_file, &input_options)?
+    } else {
+        read_campus_inputs(
+            &program_args.open_class_file,
+            &program_args.campus,
+            &input_options,
+        )?
+    };
+    let stdin = Path::new(STDIN_PATH);
+    let class_todo_content = if program_args.class_todo_file == stdin {
+        if program_args.open_class_file.iter().all(|file| file != stdin) {
+            read_input(stdin, &input_options)?
+        } else if program_args.open_class_file.len() == 1 {
+            open_class_content.clone()
+        } else {
+            return Err("표준입력(-)은 입력 파일 하나에만 사용할 수 있습니다.".into());
+        }
+    } else {
+        read_input(&program_args.class_todo_file, &input_options)?
+    };
+    let mut open_class_content = open_class_content;
+    let mut class_todo_content = class_todo_content;
+    let open_class_changed = normalize_input(&mut open_class_content);
+    let class_todo_changed = normalize_input(&mut class_todo_content);
+    if open_class_changed + class_todo_changed > 0 {
+        notice!(
+            "입력 정규화: 개설 강좌 {}개, 강의 계획서 {}개 필드의 공백과 타입을 정리하였습니다.",
+            open_class_changed, class_todo_changed
+        );
+    }
+    let quick_mode = open_class_content == class_todo_content;
+    if let Some(overrides_file) = &program_args.overrides {
+        let count = apply_overrides(&mut class_todo_content, &read_db_file(overrides_file)?)?;
+        notice!("보정 파일에 따라 {}개 과목의 정보를 보정하였습니다.", count);
+    }
+    let result_path = match &program_args.output_template {
+        Some(template) => {
+            let mut values = HashMap::from([
+                ("db_version", program_args.db_version.clone()),
+                ("app_version", program_args.app_version.clone()),
+                ("date", local_date(built_at, utc_offset)),
+                ("format", program_args.format.extension().to_string()),
+            ]);
+            let semester = program_args
+                .semester
+                .clone()
+                .or_else(|| semester(&class_todo_content));
+            if let Some(semester) = semester {
+                values.insert("semester", semester);
+            }
+            PathBuf::from(render(template, &values)?)
+        }
+        None => PathBuf::from(format!(
+            "result_{}.{}",
+            program_args.db_version,
+            program_args.format.extension()
+        )),
+    };
+    let extra_inputs = [
+        &program_args.rules,
+        &program_args.evaluation_file,
+        &program_args.academic_calendar,
+        &program_args.department_contacts,
+        &program_args.dept_codes,
+        &program_args.compatibility_matrix,
+        &program_args.previous_db,
+    ]
+    .into_iter()
+    .flatten()
+    .map(std::fs::read)
+    .collect::<Result<Vec<_>, _>>()?;
+    let extra_inputs = extra_inputs.iter().map(Vec::as_slice).collect::<Vec<_>>();
+    let fingerprint = build_fingerprint(
+        &[&open_class_content, &class_todo_content],
+        &extra_inputs,
+        &fingerprint_options(&program_args),
+    );
+    let state_file = state_file_for(&result_path);
+    let output_key = program_args.output_template.clone().unwrap_or_else(|| {
+        format!("result_{{db_version}}.{}", program_args.format.extension())
+    });
+    if !program_args.force_rebuild {
+        if let Some(previous_result) = unchanged_result(&state_file, &output_key, &fingerprint) {
+            notice!(
+                "변경 없음: 입력 파일과 옵션이 직전 빌드({})와 같아 DB를 다시 만들지 않습니다. (--force-rebuild로 강제 빌드)",
+                previous_result.display()
             );
-            std::process::exit(1);
+            if program_args.json_summary {
+                println!("{}", json_summary(&previous_result, &program_args, None));
+            }
+            progress.finish(0);
+            return Ok(());
+        }
+    }
+    let build = if program_args.shadow_build {
+        shadow::shadow_build
+    } else {
+        build_db_value
+    };
+    let build_options = BuildOptions {
+        latest_app_version: program_args.app_version.clone(),
+        db_version: program_args.db_version.clone(),
+        legacy_app_version: program_args.legacy_app_version.clone(),
+        quick_mode,
+        auto_fuzzy_merge: program_args.auto_fuzzy_merge,
+        memory_limit,
+    };
+    progress.update("DB 제작", 20, 0);
+    let (mut db, mut summary) = build(&open_class_content, &class_todo_content, &build_options)
+        .map_err(|error| {
+            format!(
+                "DB 내용 생성 과정에서 다음과 같은 오류가 발생되었습니다: {}",
+                error
+            )
+        })?;
+    progress.update("후처리", 50, summary.warnings.len());
+    if let Some(memory_limit) = memory_limit {
+        memory_limit.check("DB 제작")?;
+    }
+    if let Some(rules_file) = &program_args.rules {
+        let rules = RuleSet::from_yaml(&std::fs::read_to_string(rules_file)?)?;
+        let removed = apply_rules(&mut db, &rules);
+        summary.subject_count -= removed;
+        notice!("규칙에 따라 {}개의 과목이 제외되었습니다.", removed);
+    }
+    if program_args.campus_departments {
+        db["departments_by_campus"] = departments_by_campus(&db);
+    }
+    for anomaly in section_anomalies(&db, program_args.max_sections) {
+        summary.warn(anomaly);
+    }
+    if let Some(evaluation_file) = &program_args.evaluation_file {
+        for failure in merge_evaluations(&mut db, &read_db_file(evaluation_file)?)? {
+            summary.warn(failure);
+        }
+    }
+    if program_args.collect_resources {
+        db["resources"] = collect_resources(&db, &class_todo_content);
+    }
+    if let Some(calendar_file) = &program_args.academic_calendar {
+        db["academic_calendar"] = academic_calendar(&read_db_file(calendar_file)?)?;
+    }
+    if let Some(contacts_file) = &program_args.department_contacts {
+        let (contacts, warnings) = department_contacts(&db, &read_db_file(contacts_file)?)?;
+        for warning in warnings {
+            summary.warn(warning);
+        }
+        db["department_contacts"] = contacts;
+    }
+    if let Some(codes_file) = &program_args.dept_codes {
+        let codes = parse_code_map(&read_db_file(codes_file)?)?;
+        let unmapped = apply_dept_codes(&mut db, &codes);
+        if !unmapped.is_empty() {
+            summary.warn(format!(
+                "다음 학부 또는 학과의 코드를 찾지 못하였습니다: {}",
+                unmapped.join(", ")
+            ));
+        }
+    }
+    if let Some(matrix_file) = &program_args.compatibility_matrix {
+        let rules = parse_matrix(&std::fs::read_to_string(matrix_file)?)?;
+        if let Some(warning) = apply_matrix(&mut db, &rules) {
+            summary.warn(warning);
+        }
+    }
+    if let Some(previous_db) = &program_args.previous_db {
+        link_previous(&mut db, &read_db_file(previous_db)?);
+    }
+    db["version"]["built_at"] = json!(timestamp(built_at, utc_offset));
+    if program_args.prune_null {
+        notice!(
+            "값이 null인 필드 {}개를 생략하였습니다.",
+            prune_null(&mut db)
+        );
+    }
+    if program_args.separate_contacts {
+        let contacts_path =
+            result_path.with_file_name(format!("contacts_{}.json", program_args.db_version));
+        let contacts = json!({
+            "contacts": db.as_object_mut().and_then(|db| db.remove("contacts")),
+            "version": {"db_ver": program_args.db_version},
         });
-    db_file
-        .write_all(
-            make_db_content(
-                &open_class_content,
-                &class_todo_content,
-                &program_args.app_version,
-                &program_args.db_version,
-                open_class_content == class_todo_content,
+        write_json(&contacts_path, &contacts)?;
+        notice!(
+            "연락처가 {}파일로 분리되어 저장되었습니다.",
+            contacts_path.display()
+        );
+    }
+    if program_args.checksum {
+        db["version"]["content_sha256"] = json!(content_checksum(&db));
+    }
+    let output_options = OutputOptions {
+        csv_bom: program_args.csv_bom,
+        sql_dialect: program_args.sql_dialect,
+        bson_split: program_args.bson_split,
+        canonical: program_args.canonical,
+    };
+    if let Some(memory_limit) = memory_limit {
+        memory_limit.check("결과 파일 저장 준비")?;
+    }
+    progress.update("결과 파일 저장", 70, summary.warnings.len());
+    let wrapped = program_args
+        .firebase_import
+        .as_deref()
+        .map(|node_path| firebase_import(&db, node_path))
+        .transpose()?;
+    let output = wrapped.as_ref().unwrap_or(&db);
+    let legacy_path = companion_path(&result_path, "_legacy.json");
+    let qa_sample_path = companion_path(&result_path, QA_SAMPLE_SUFFIX);
+    let result_path = write_db(output, program_args.format, &result_path, &output_options)
+        .and_then(|_| {
+            compress_file(
+                &result_path,
+                program_args.compress,
+                program_args.compress_level,
             )
-                .unwrap_or_else(|error| {
+        })
+        .map_err(|error| {
+            format!(
+                "다음과 같은 이유로 DB 파일 생성에 실패하였습니다: {}",
+                error
+            )
+        })?;
+    progress.update("부가 파일 저장", 85, summary.warnings.len());
+    notice!(
+        "작업이 완료되었습니다. {}파일로 저장되었습니다.",
+        result_path.display()
+    );
+    if program_args.checksum {
+        let sidecar = write_sidecar(&result_path)?;
+        notice!("체크섬이 {}파일로 저장되었습니다.", sidecar.display());
+    }
+    if program_args.legacy_output {
+        write_json(&legacy_path, &legacy_db(&db))?;
+        notice!(
+            "구 스키마 DB가 {}파일로 저장되었습니다.",
+            legacy_path.display()
+        );
+    }
+    if let Some(version_file) = &program_args.version_file {
+        write_json(version_file, &version_info(&db, &result_path)?)?;
+        notice!(
+            "버전 정보가 {}파일로 저장되었습니다.",
+            version_file.display()
+        );
+    }
+    if let Some(split_output) = &program_args.split_output {
+        let manifest = write_split(&db, split_output, program_args.manifest_base_url.as_deref())?;
+        notice!(
+            "학부별 분할 파일 {}개와 매니페스트가 {}에 저장되었습니다.",
+            manifest["departments"].as_object().map_or(0, Map::len),
+            split_output.join(MANIFEST_FILE).display()
+        );
+    }
+    if let Some(report) = &program_args.report {
+        File::create(report)?.write_all(build_report(&db, &summary).as_bytes())?;
+        notice!("빌드 리포트가 {}파일로 저장되었습니다.", report.display());
+    }
+    if let Some(preview) = &program_args.preview {
+        File::create(preview)?.write_all(preview_html(&db).as_bytes())?;
+        notice!("미리보기가 {}파일로 저장되었습니다.", preview.display());
+    }
+    if let Some(dot) = &program_args.dot {
+        File::create(dot)?.write_all(departments_dot(&db).as_bytes())?;
+        notice!("학부-학과 그래프가 {}파일로 저장되었습니다.", dot.display());
+    }
+    if let Some(count) = program_args.qa_sample {
+        let seed = match program_args.seed {
+            Some(seed) => seed,
+            None => std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)?
+                .as_nanos() as u64,
+        };
+        write_qa_sample(&db, &qa_sample_path, count, seed, program_args.csv_bom)?;
+        notice!(
+            "검수 시트가 {}파일로 저장되었습니다. (시드: {})",
+            qa_sample_path.display(),
+            seed
+        );
+    }
+    save_build_state(&state_file, &output_key, &fingerprint, &result_path)?;
+    if let Some(target) = &remote_config {
+        progress.update("원격 설정 갱신", 95, summary.warnings.len());
+        let parameter = &program_args.remote_config_parameter;
+        if sync_db_version(target, parameter, &program_args.db_version)? {
+            notice!(
+                "원격 설정의 {}을(를) {}(으)로 갱신하였습니다.",
+                parameter, program_args.db_version
+            );
+        } else {
+            notice!("원격 설정의 {}이(가) 이미 최신입니다.", parameter);
+        }
+    }
+    progress.finish(summary.warnings.len());
+    if program_args.json_summary {
+        println!(
+            "{}",
+            json_summary(&result_path, &program_args, Some(&summary))
+        );
+    }
+    Ok(())
+}
+
+/// `--json-summary`로 출력할 결과 요약을 만드는 메서드
+///
+/// 빌드를 건너뛴 경우(`summary`가 `None`)에도 같은 항목을 가지며, 이때 `result_file`은 직전 빌드의 결과 파일이고
+/// `subject_count`와 `warning_count`는 `null`이다.
+fn json_summary(
+    result_file: &Path,
+    program_args: &ProgramArgument,
+    summary: Option<&BuildSummary>,
+) -> Value {
+    json!({
+        "result_file": result_file.to_string_lossy(),
+        "skipped": summary.is_none(),
+        "app_ver": program_args.app_version,
+        "db_ver": program_args.db_version,
+        "subject_count": summary.map(|summary| summary.subject_count),
+        "warning_count": summary.map(|summary| summary.warnings.len()),
+    })
+}
+
+/// 빌드 지문에 포함할 옵션을 모으는 메서드
+///
+/// 스케줄 빌드마다 바뀌는 DB 버전과 빌드 결과에 영향을 주지 않는 옵션은 제외한다.
+fn fingerprint_options(program_args: &ProgramArgument) -> Value {
+    json!({
+        "app_version": program_args.app_version,
+        "legacy_app_version": program_args.legacy_app_version,
+        "format": program_args.format.extension(),
+        "compress": format!("{:?}", program_args.compress),
+        "compress_level": program_args.compress_level,
+        "canonical": program_args.canonical,
+        "csv_bom": program_args.csv_bom,
+        "sql_dialect": format!("{:?}", program_args.sql_dialect),
+        "bson_split": format!("{:?}", program_args.bson_split),
+        "output_template": program_args.output_template,
+        "semester": program_args.semester,
+        "timezone": program_args.timezone,
+        "merge_pages": program_args.merge_pages,
+        "source_root_key": program_args.source_root_key,
+        "input_encoding": format!("{:?}", program_args.input_encoding),
+        "dataset": format!("{:?}", program_args.dataset),
+        "campus_departments": program_args.campus_departments,
+        "collect_resources": program_args.collect_resources,
+        "version_file": program_args.version_file,
+        "prune_null": program_args.prune_null,
+        "checksum": program_args.checksum,
+        "legacy_output": program_args.legacy_output,
+        "separate_contacts": program_args.separate_contacts,
+        "firebase_import": program_args.firebase_import,
+        "split_output": program_args.split_output,
+        "manifest_base_url": program_args.manifest_base_url,
+        "report": program_args.report,
+        "preview": program_args.preview,
+        "dot": program_args.dot,
+        "qa_sample": program_args.qa_sample,
+        "seed": program_args.seed,
+        "auto_fuzzy_merge": program_args.auto_fuzzy_merge,
+        "shadow_build": program_args.shadow_build,
+    })
+}
+
+/// 하위 명령을 수행하는 메서드
+///
+/// `command`에 해당하는 작업을 진행한 뒤 결과 파일을 저장한다.
+///
+/// ## Arguments
+///
+/// * `command` - `Command`형태의 하위 명령
+///
+/// ## Errors
+/// * 인자값으로 주어진 파일이 존재하지 않거나 JSON해독이 불가능한 경우
+/// * 표준 I/O가 정상 동작하지 않는 경우
+pub fn command_process(command: Command) -> Result<(), Box<dyn Error>> {
+    match command {
+        Command::Anonymize {
+            db_file,
+            output,
+            mode,
+        } => {
+            let mut db = read_db_file(&db_file)?;
+            anonymize_db(&mut db, mode);
+            let output = output.unwrap_or_else(|| {
+                let mut file_name = db_file.file_stem().unwrap_or_default().to_os_string();
+                file_name.push("_anonymized.json");
+                db_file.with_file_name(file_name)
+            });
+            save_db_file(&output, &db)?;
+        }
+        Command::AnonymizeInput {
+            input_files,
+            output_dir,
+        } => {
+            let mut anonymizer = InputAnonymizer::default();
+            for input_file in input_files.iter() {
+                let mut input = read_db_file(input_file)?;
+                let count = anonymizer.anonymize_input(&mut input);
+                println!(
+                    "{}에서 {}개의 값을 가짜 값으로 바꾸었습니다.",
+                    input_file.display(),
+                    count
+                );
+                let mut file_name = input_file.file_stem().unwrap_or_default().to_os_string();
+                file_name.push("_anonymized.json");
+                let output = match &output_dir {
+                    Some(output_dir) => output_dir.join(file_name),
+                    None => input_file.with_file_name(file_name),
+                };
+                save_db_file(&output, &input)?;
+            }
+        }
+        Command::Sign {
+            db_file,
+            key,
+            key_from_keyring,
+            output,
+        } => {
+            let mut db = read_db_file(&db_file)?;
+            let key = match key {
+                Some(key) => read_key_file(&key)?,
+                None if key_from_keyring => parse_key(&keyring_secret(SIGNING_KEY_ENTRY)?)?,
+                None => parse_key(&prompt_secret("ed25519 개인키(16진수): ")?)?,
+            };
+            let key = SigningKey::from_bytes(&key);
+            sign_db(&mut db, &key);
+            save_db_file(output.as_ref().unwrap_or(&db_file), &db)?;
+            println!(
+                "검증에 사용할 공개키: {}",
+                to_hex(key.verifying_key().as_bytes())
+            );
+        }
+        Command::Pull {
+            manifest_url,
+            output,
+            verify,
+            public_key,
+        } => {
+            let public_key = match public_key {
+                Some(public_key) => Some(VerifyingKey::from_bytes(&read_key_file(&public_key)?)?),
+                None => None,
+            };
+            let options = PullOptions { verify, public_key };
+            let (count, warnings) = pull(&manifest_url, &output, &options)?;
+            for warning in warnings {
+                println!("주의: {}", warning);
+            }
+            println!(
+                "작업이 완료되었습니다. 파일 {}개와 매니페스트가 {}에 배치되었습니다.",
+                count,
+                output.display()
+            );
+        }
+        Command::Verify {
+            db_file,
+            public_key,
+        } => {
+            let db = read_db_file(&db_file)?;
+            verify_db(
+                &db,
+                &VerifyingKey::from_bytes(&read_key_file(&public_key)?)?,
+            )?;
+            println!(
+                "서명이 확인되었습니다. {}파일은 변조되지 않았습니다.",
+                db_file.display()
+            );
+        }
+        Command::VerifyChain { db_files } => {
+            let dbs = db_files
+                .iter()
+                .map(|db_file| Ok((db_file.display().to_string(), read_db_file(db_file)?)))
+                .collect::<Result<Vec<_>, Box<dyn Error>>>()?;
+            let failures = chain_failures(&dbs);
+            for failure in failures.iter() {
+                println!("{}", failure);
+            }
+            if !failures.is_empty() {
+                return Err(format!(
+                    "버전 체인에서 {}개의 문제가 발견되었습니다.",
+                    failures.len()
+                )
+                .into());
+            }
+            println!("버전 체인이 확인되었습니다. 누락되거나 변조된 버전이 없습니다.");
+        }
+        Command::BulkValidate { directory, pattern } => {
+            let results = bulk_validate(&directory, &pattern)?;
+            let mut failed = 0;
+            for (file, problems) in results.iter() {
+                if problems.is_empty() {
+                    println!("정상: {}", file.display());
+                    continue;
+                }
+                failed += 1;
+                println!("문제 발견: {}", file.display());
+                for problem in problems {
+                    println!("  - {}", problem);
+                }
+            }
+            if failed > 0 {
+                return Err(format!(
+                    "{}개의 파일 중 {}개의 파일에서 문제가 발견되었습니다.",
+                    results.len(),
+                    failed
+                )
+                .into());
+            }
+            println!("{}개의 파일이 모두 정상입니다.", results.len());
+        }
+        Command::Fix { input_file, output } => {
+            let (fixed, report) = fix_input(&std::fs::read(&input_file)?)?;
+            if report.bom_removed {
+                println!("파일 앞의 BOM을 제거하였습니다.");
+            }
+            println!(
+                "문자열 {}개의 앞뒤 공백과 중복 과목 {}개를 제거하였습니다.",
+                report.trimmed, report.duplicates_removed
+            );
+            for key in report.conflicts.iter() {
+                println!(
+                    "주의: 과목 키는 같지만 내용이 다른 과목이 있어 교정하지 않았습니다. ({})",
+                    key
+                );
+            }
+            let output = output.unwrap_or_else(|| {
+                let mut file_name = input_file.file_stem().unwrap_or_default().to_os_string();
+                file_name.push("_fixed.json");
+                input_file.with_file_name(file_name)
+            });
+            save_db_file(&output, &fixed)?;
+        }
+        Command::Extract {
+            db_file,
+            departments,
+            subject_codes,
+            output,
+        } => {
+            if departments.is_empty() && subject_codes.is_empty() {
+                return Err("--department 또는 --subject-code 옵션으로 뽑을 과목을 지정해주세요.".into());
+            }
+            let filter = ExtractFilter {
+                departments,
+                subject_codes,
+            };
+            let (extracted, count) = extract_db(&read_db_file(&db_file)?, &filter);
+            println!("{}개의 과목을 뽑았습니다.", count);
+            let output = output.unwrap_or_else(|| {
+                let mut file_name = db_file.file_stem().unwrap_or_default().to_os_string();
+                file_name.push("_extract.json");
+                db_file.with_file_name(file_name)
+            });
+            save_db_file(&output, &extracted)?;
+        }
+        Command::Delta {
+            previous,
+            current,
+            output,
+        } => {
+            let patch = json_patch(&read_db_file(&previous)?, &read_db_file(&current)?);
+            println!(
+                "{}개의 변경 연산이 생성되었습니다.",
+                patch.as_array().map_or(0, Vec::len)
+            );
+            save_db_file(&output, &patch)?;
+        }
+        Command::Trends {
+            db_files,
+            format,
+            output,
+        } => {
+            let points = db_files
+                .iter()
+                .map(|db_file| Ok(trend_point(&read_db_file(db_file)?)))
+                .collect::<Result<Vec<_>, Box<dyn Error>>>()?;
+            let (content, default_output) = match format {
+                TrendsFormat::Csv => (trends_csv(&points)?, "trends.csv"),
+                TrendsFormat::Json => (trends_chart(&points).to_string(), "trends.json"),
+            };
+            let output = output.unwrap_or_else(|| PathBuf::from(default_output));
+            File::create(&output)?.write_all(content.as_bytes())?;
+            println!(
+                "작업이 완료되었습니다. {}파일로 저장되었습니다.",
+                output.display()
+            );
+        }
+        Command::FavoriteMap {
+            previous,
+            current,
+            output,
+        } => {
+            let mapping = favorite_mapping(&read_db_file(&previous)?, &read_db_file(&current)?);
+            save_db_file(&output, &mapping)?;
+            println!(
+                "연결하지 못한 과목 수: {}",
+                mapping["unmatched"].as_array().map_or(0, Vec::len)
+            );
+        }
+        Command::Secret { action } => match action {
+            SecretAction::Set { name } => {
+                store_keyring_secret(&name, &prompt_secret(&format!("{} 값: ", name))?)?;
+                println!("키링에 {} 항목을 저장하였습니다.", name);
+            }
+            SecretAction::Delete { name } => {
+                delete_keyring_secret(&name)?;
+                println!("키링에서 {} 항목을 지웠습니다.", name);
+            }
+        },
+        Command::SizeProfile { db_file, output } => {
+            let profile = size_profile(&read_db_file(&db_file)?);
+            match output {
+                Some(output) => {
+                    File::create(&output)?.write_all(profile.as_bytes())?;
                     println!(
-                        "DB 내용 생성 과정에서 다음과 같은 오류가 발생되었습니다: {}",
-                        error
+                        "작업이 완료되었습니다. {}파일로 저장되었습니다.",
+                        output.display()
                     );
-                    std::process::exit(1);
-                })
-                .as_bytes(),
-        )
-        .expect("DB파일 쓰기 실패");
+                }
+                None => print!("{}", profile),
+            }
+        }
+        Command::Fetch {
+            url,
+            params,
+            page_size,
+            source_root_key,
+            semester,
+            offline,
+            max_retries,
+            retry_backoff_ms,
+            request_delay_ms,
+            auth,
+            output,
+        } => {
+            let auth = match auth {
+                Some(kind) if !offline => Some(PortalAuth::load(kind)?),
+                _ => None,
+            };
+            let request = PortalRequest {
+                url,
+                params,
+                page_size,
+                root_key: source_root_key,
+                retry: RetryPolicy {
+                    max_retries,
+                    initial_backoff: Duration::from_millis(retry_backoff_ms),
+                    request_delay: Duration::from_millis(request_delay_ms),
+                    ..Default::default()
+                },
+                auth,
+                ..Default::default()
+            };
+            let cache = Cache::new(&default_cache_dir()?);
+            let content = cache.fetch(&request.source(), semester.as_deref(), offline, || {
+                Ok(collect(&request)?.to_string().into_bytes())
+            })?;
+            File::create(&output)?.write_all(&content)?;
+            println!(
+                "작업이 완료되었습니다. {}파일로 저장되었습니다.",
+                output.display()
+            );
+        }
+        Command::ProfileInput {
+            input_file,
+            source_root_key,
+            output,
+        } => {
+            let streamable = !cfg!(feature = "simd-json")
+                && source_root_key.is_none()
+                && !is_url(&input_file)
+                && input_file
+                    .extension()
+                    .is_some_and(|extension| extension == "json")
+                && starts_as_plain_json(&input_file);
+            let profile = match streamable.then(|| stream_profile(&input_file)) {
+                Some(Ok(profiler)) => profiler.markdown(),
+                // 과목 목록의 키가 다르면 read_input이 키를 찾고, 파일 중간에서 해독에 실패한 경우 실패한 이유를 알린 뒤 파일 전체를 읽는다.
+                Some(Err(_)) | None => {
+                    let options = InputOptions {
+                        root_key: source_root_key,
+                        ..Default::default()
+                    };
+                    input_profile(&read_input(&input_file, &options)?)
+                }
+            };
+            match output {
+                Some(output) => {
+                    File::create(&output)?.write_all(profile.as_bytes())?;
+                    println!(
+                        "작업이 완료되었습니다. {}파일로 저장되었습니다.",
+                        output.display()
+                    );
+                }
+                None => print!("{}", profile),
+            }
+        }
+        Command::Regress {
+            golden,
+            open_class_file,
+            class_todo_file,
+            auto_fuzzy_merge,
+            update,
+        } => {
+            let golden_db = if golden.exists() {
+                read_db_file(&golden)?
+            } else if update {
+                Value::Null
+            } else {
+                return Err(format!("기준 출력 파일({})이 없습니다.", golden.display()).into());
+            };
+            let input_options = InputOptions::default();
+            let open_class_content = read_input(&open_class_file, &input_options)?;
+            let class_todo_content = read_input(&class_todo_file, &input_options)?;
+            let options = BuildOptions {
+                quick_mode: open_class_content == class_todo_content,
+                ..golden_options(&golden_db, auto_fuzzy_merge)
+            };
+            let (db, _) = build_db_value(&open_class_content, &class_todo_content, &options)?;
+            if update {
+                save_db_file(&golden, &db)?;
+                return Ok(());
+            }
+            let differences = regress_differences(&golden_db, &db);
+            if !differences.is_empty() {
+                for difference in differences.iter().take(MAX_REPORTED_DIFFERENCES) {
+                    println!("- {}", difference);
+                }
+                return Err(format!("기준 출력과 {}곳이 다릅니다.", differences.len()).into());
+            }
+            println!("기준 출력과 같은 결과를 만들었습니다.");
+        }
+        Command::Grep {
+            db_file,
+            pattern,
+            field,
+            ignore_case,
+            json,
+        } => {
+            let db = read_db_file(&db_file)?;
+            let found = search_subjects(&db, &pattern, field, ignore_case);
+            for found in found.iter() {
+                if json {
+                    println!("{}", found.subject);
+                } else {
+                    println!("{}", found.line());
+                }
+            }
+            if !json {
+                println!("{}개의 과목을 찾았습니다.", found.len());
+            }
+        }
+        Command::Schema { output } => {
+            save_db_file(&output, &json_schema())?;
+        }
+        Command::GenerateDocs { format, output } => {
+            let (content, default_output) = match format {
+                DocsFormat::Json => (schema_document().to_string(), "schema.json"),
+                DocsFormat::Html => (schema_html(), "schema.html"),
+                DocsFormat::Proto => (proto_schema(), "suwon_mate.proto"),
+                DocsFormat::Fbs => (fbs_schema(), "suwon_mate.fbs"),
+            };
+            let output = output.unwrap_or_else(|| PathBuf::from(default_output));
+            File::create(&output)?.write_all(content.as_bytes())?;
+            println!(
+                "작업이 완료되었습니다. {}파일로 저장되었습니다.",
+                output.display()
+            );
+        }
+        Command::TimetableCheck {
+            db_file,
+            dictionary,
+            output,
+        } => {
+            let timetable_dictionary = read_timetable_dictionary(dictionary.as_deref())?;
+            let failures = timetable_failures(&read_db_file(&db_file)?, &timetable_dictionary);
+            for failure in failures.iter() {
+                println!(
+                    "주의: 시간표를 해석할 수 없습니다. ({} {})",
+                    failure["key"].as_str().unwrap_or(""),
+                    failure["error"].as_str().unwrap_or("")
+                );
+            }
+            save_db_file(&output, &Value::Array(failures))?;
+        }
+        Command::Ics {
+            db_file,
+            department,
+            professor,
+            start,
+            weeks,
+            dictionary,
+            output,
+        } => {
+            let options = IcsOptions {
+                department,
+                professor,
+                start,
+                weeks,
+            };
+            let (ics, skipped) = timetable_ics(
+                &read_db_file(&db_file)?,
+                &read_timetable_dictionary(dictionary.as_deref())?,
+                &options,
+            )?;
+            for reason in skipped.iter() {
+                println!("주의: 일정으로 만들지 못한 수업이 있습니다. ({})", reason);
+            }
+            File::create(&output)?.write_all(ics.as_bytes())?;
+            println!(
+                "작업이 완료되었습니다. {}파일로 저장되었습니다.",
+                output.display()
+            );
+        }
+    }
+    Ok(())
+}
+
+/// 기본 사전에 `path`의 시간표 예외 표기 사전을 더한 사전을 만드는 메서드
+fn read_timetable_dictionary(path: Option<&Path>) -> Result<TimetableDictionary, Box<dyn Error>> {
+    let mut timetable_dictionary = TimetableDictionary::default();
+    if let Some(path) = path {
+        timetable_dictionary.extend_from_json(&std::fs::read_to_string(path)?)?;
+    }
+    Ok(timetable_dictionary)
+}
+
+/// 이미 만들어진 DB 파일을 읽어 JSON으로 해독하는 메서드
+fn read_db_file(path: &Path) -> Result<Value, Box<dyn Error>> {
+    Ok(serde_json::from_str(&std::fs::read_to_string(path)?)?)
+}
+
+/// DB의 내용을 파일로 저장하고 완료 메세지를 출력하는 메서드
+fn save_db_file(path: &Path, db: &Value) -> Result<(), Box<dyn Error>> {
+    write_json(path, db)?;
     println!(
-        "작업이 완료되었습니다. result_{}.json파일로 저장되었습니다.",
-        program_args.db_version.clone()
+        "작업이 완료되었습니다. {}파일로 저장되었습니다.",
+        path.display()
     );
     Ok(())
 }
 
 /// DB의 내용물을 만드는 메서드
 ///
-/// 제공된 두 파일의 내용과 인자값을 바탕으로 최종 DB파일을 생성하는 메서드이다.
+/// 제공된 두 파일의 내용과 인자값을 바탕으로 최종 DB파일의 내용을 `Value` 형태로 생성하는 메서드이다.
+/// 출력 형식에 맞는 직렬화는 [output::write_db]에서 진행된다.
 /// 제공된 파일에서 필요한 부분들만 합쳐서 진행되며, 만일 필요한 부분에 대한 정보가 제공된 파일에 존재하지 않는 경우
 /// `null`로 기록된다.
 /// `quick_mode`를 통해 생성한 DB는 개설 강좌 조회용 DB로만 만들어진 불완전한 DB 파일임을 명시할 수 있다. 이 경우 `estbLectDtaiList_quick`라는
@@ -194,120 +1975,166 @@ pub fn file_process(program_args: ProgramArgument) -> Result<(), Box<dyn Error>>
 ///
 /// * `open_class_content` - 스트링 슬라이스 형태의 개설 강좌 조회 DB 파일 내용
 /// * `class_todo_content` - 스트링 슬라이스 형태의 강의계획서 DB 파일 내용
-/// * `latest_app_version` - 스트링 슬라이스 형태의 최신 앱 버전
-/// * `db_version` - 스트링 슬라이스 형태의 DB 버전
-/// * `quick_mode` - `bool`타입의 불완전한 DB 파일 만들기 기능 활성화 여부
+/// * `options` - [BuildOptions] 형태의 DB 제작 옵션
 ///
 /// ## Examples
 ///
 /// ```
 /// use std::fs::File;
 /// use std::io::Read;
-/// use suwon_mate_admin_tool::make_db_content;
+/// use suwon_mate_admin_tool::{make_db_content, BuildOptions};
 /// let mut dummy_open_class_file =
 ///                  File::open("sample/sample_todo_class.json").expect("Sample파일을 찾을 수 없습니다.");
 ///              let mut dummy_open_class_data = String::new();
 ///              dummy_open_class_file
 ///                  .read_to_string(&mut dummy_open_class_data)
 ///                  .expect("Sample파일을 읽을 수 없습니다.");
-/// let content = make_db_content(&dummy_open_class_data, &dummy_open_class_data, "test", "test", true);
-/// assert_ne!(content.unwrap(), "".to_string());
+/// let options = BuildOptions { quick_mode: true, ..Default::default() };
+/// let content = make_db_content(&dummy_open_class_data, &dummy_open_class_data, &options);
+/// assert_ne!(content.unwrap().to_string(), "".to_string());
+/// ```
+///
+/// ## Errors
+/// 제공된 파일의 내용을 기반으로 JSON해독이 불가능 한 경우 오류가 발생한다.
+pub fn make_db_content(
+    open_class_content: &str,
+    class_todo_content: &str,
+    options: &BuildOptions,
+) -> Result<Value, Box<dyn Error>> {
+    let (db, _) = build_db_content(open_class_content, class_todo_content, options)?;
+    Ok(db)
+}
+
+/// DB의 내용물과 함께 제작 결과 요약을 만드는 메서드
+///
+/// [make_db_content]와 동일하게 DB의 내용물을 만들며, 기록된 과목의 수와 제작 과정에서 발생한 경고를 담은
+/// [BuildSummary]를 함께 반환한다.
+///
+/// 학부, 과목, 연락처 목록은 이름 순서로 기록되므로 같은 입력으로 제작한 DB는 항상 같은 내용이 된다.
+///
+/// ## Examples
+///
+/// ```
+/// use suwon_mate_admin_tool::{build_db_content, BuildOptions};
+/// let sample = std::fs::read_to_string("sample/sample_todo_class.json").expect("Sample파일을 읽을 수 없습니다.");
+/// let options = BuildOptions { quick_mode: true, ..Default::default() };
+/// let (_, summary) = build_db_content(&sample, &sample, &options).unwrap();
+/// assert_ne!(summary.subject_count, 0);
+/// ```
+///
+/// ```
+/// use serde_json::json;
+/// use suwon_mate_admin_tool::{build_db_content, BuildOptions};
+/// let subjects: Vec<_> = (0..16)
+///     .map(|number| json!({
+///         "subjtCd": number.to_string(), "diclNo": "001", "estbDpmjNm": format!("학부{}", number),
+///         "estbMjorNm": format!("학과{}", number), "ltrPrfsNm": format!("교수{}", number),
+///     }))
+///     .collect();
+/// let content = json!({"estbLectDtaiList": subjects}).to_string();
+/// let (db, _) = build_db_content(&content, &content, &BuildOptions::default()).unwrap();
+/// let (again, _) = build_db_content(&content, &content, &BuildOptions::default()).unwrap();
+/// assert_eq!(db.to_string(), again.to_string());
 /// ```
 ///
 /// ## Errors
 /// 제공된 파일의 내용을 기반으로 JSON해독이 불가능 한 경우 오류가 발생한다.
-pub fn make_db_content<'make_db>(
-    open_class_content: &'make_db str,
-    class_todo_content: &'make_db str,
-    latest_app_version: &'make_db str,
-    db_version: &'make_db str,
-    quick_mode: bool,
-) -> Result<String, Box<dyn Error>> {
-    let open_class_data: Value = serde_json::from_str(open_class_content)?;
-    let class_todo_data: Value = serde_json::from_str(class_todo_content)?;
+pub fn build_db_content(
+    open_class_content: &str,
+    class_todo_content: &str,
+    options: &BuildOptions,
+) -> Result<(Value, BuildSummary), Box<dyn Error>> {
+    let open_class_data = serde_json::from_str(open_class_content)?;
+    let class_todo_data = serde_json::from_str(class_todo_content)?;
+    build_db_value(&open_class_data, &class_todo_data, options)
+}
+
+/// 해독된 입력 파일로 DB의 내용물과 제작 결과 요약을 만드는 메서드
+///
+/// [build_db_content]와 같지만 이미 해독한 입력을 받으므로 입력 파일의 내용을 다시 해독하지 않는다.
+///
+/// ## Examples
+///
+/// ```
+/// use suwon_mate_admin_tool::{build_db_value, BuildOptions};
+/// let sample = std::fs::read_to_string("sample/sample_todo_class.json").expect("Sample파일을 읽을 수 없습니다.");
+/// let sample: serde_json::Value = serde_json::from_str(&sample).unwrap();
+/// let options = BuildOptions { quick_mode: true, ..Default::default() };
+/// let (_, summary) = build_db_value(&sample, &sample, &options).unwrap();
+/// assert_ne!(summary.subject_count, 0);
+/// assert!(build_db_value(&serde_json::json!({}), &sample, &options).is_err());
+/// ```
+///
+/// ## Errors
+/// 입력에 `estbLectDtaiList` 목록이 없는 경우 오류가 발생한다.
+pub fn build_db_value(
+    open_class_data: &Value,
+    class_todo_data: &Value,
+    options: &BuildOptions,
+) -> Result<(Value, BuildSummary), Box<dyn Error>> {
+    let latest_app_version = options.latest_app_version.as_str();
+    let db_version = options.db_version.as_str();
+    let quick_mode = options.quick_mode;
+    let mut summary = BuildSummary::default();
     let departments = class_todo_data["estbLectDtaiList"]
         .as_array()
-        .unwrap_or_else(|| {
-            println!("강의 계획서 DB로부터 학부 목록을 가져오는데 문제가 발생하였습니다.");
-            std::process::exit(1);
-        });
-    let mut departments_set = HashSet::new();
+        .ok_or("강의 계획서 DB로부터 학부 목록을 가져오는데 문제가 발생하였습니다.")?;
+    let mut departments_set = BTreeSet::new();
     for department in departments.iter() {
         departments_set.insert(department["estbDpmjNm"].as_str().unwrap_or_else(|| {
-            println!("계획서 파일에서 누락된 학부가 존재합니다.");
+            summary.warn("계획서 파일에서 누락된 학부가 존재합니다.".to_string());
             ""
         }));
     }
-    let mut departments_map: HashMap<&str, HashSet<&str>> = HashMap::new();
-    let mut subject_map: HashMap<String, Vec<Value>> = HashMap::new();
-    let mut contact_map: HashMap<String, HashMap<String, Value>> = HashMap::new();
+    let mut departments_map: BTreeMap<&str, BTreeSet<&str>> = BTreeMap::new();
+    let mut subject_map: BTreeMap<&str, Vec<Value>> = BTreeMap::new();
+    let mut contact_map: BTreeMap<&str, BTreeMap<&str, Value>> = BTreeMap::new();
     for department in departments_set.iter() {
-        subject_map.insert(department.parse()?, vec![]);
-        contact_map.insert(department.parse()?, HashMap::new());
+        subject_map.insert(department, vec![]);
+        contact_map.insert(department, BTreeMap::new());
     }
     let open_subjects = open_class_data["estbLectDtaiList"]
         .as_array()
-        .unwrap_or_else(|| {
-            println!("개설 강죄 조회 DB로부터 과목정보를 가져오는데 문제가 발생하였습니다.");
-            std::process::exit(1);
-        });
+        .ok_or("개설 강죄 조회 DB로부터 과목정보를 가져오는데 문제가 발생하였습니다.")?;
     let todo_subjects = class_todo_data["estbLectDtaiList"]
         .as_array()
-        .unwrap_or_else(|| {
-            println!("강의 계획서 DB로부터 과목 정보를 가져오는데 문제가 발생하였습니다.");
-            std::process::exit(1);
-        });
-    for subject in open_subjects.iter() {
-        let temp = ClassTodo::get_department_info(
-            todo_subjects,
-            subject["subjtCd"].as_str().unwrap_or(""),
-            subject["diclNo"].as_str().unwrap_or(""),
-        );
-        if !temp.major.is_null() {
-            if !departments_map.contains_key(temp.department.as_str().unwrap()) {
-                departments_map.insert(temp.department.as_str().unwrap(), HashSet::new());
+        .ok_or("강의 계획서 DB로부터 과목 정보를 가져오는데 문제가 발생하였습니다.")?;
+    let todo_index = TodoIndex::new(todo_subjects);
+    let partials = open_subjects
+        .par_chunks(SUBJECT_CHUNK_SIZE)
+        .map(|subjects| {
+            if let Some(memory_limit) = options.memory_limit {
+                memory_limit
+                    .check("DB 제작")
+                    .map_err(|error| error.to_string())?;
             }
-            departments_map
-                .get_mut(temp.department.as_str().unwrap())
-                .unwrap()
-                .insert(temp.major.as_str().unwrap());
-        }
-        if let Some(subject_map) = subject_map.get_mut(temp.department.as_str().unwrap()) {
-            subject_map.push(json!({
-                "trgtGrdeCd": subject["trgtGrdeCd"],
-                "subjtNm": subject["subjtNm"],
-                "ltrPrfsNm" : subject["ltrPrfsNm"],
-                "deptNm" : subject["deptNm"],
-                "facDvnm" : subject["facDvnm"],
-                "timtSmryCn" : subject["timtSmryCn"],
-                "lssnLangNm" : subject["lssnLangNm"],
-                "subjtCd": subject["subjtCd"],
-                "diclNo" : subject["diclNo"],
-                "subjtEstbYear" : subject["subjtEstbYear"],
-                "point" : subject["point"],
-                "cltTerrNm" : subject["cltTerrNm"],
-                "sexCdNm" : subject["sexCdNm"],
-                "hffcStatNm" : subject["hffcStatNm"],
-                "clsfNm": subject["clsfNm"],
-                "capprTypeNm" : subject["capprTypeNm"],
-                "estbDpmjNm": temp.department,
-                "estbMjorNm": temp.major,
-            }));
-        } else {
-            println!(
-                "주의: 분류에 실패한 학부 및 학과가 존재합니다. ({})",
-                temp.department.as_str().unwrap()
-            )
+            Ok(process_subjects(
+                subjects,
+                &todo_index,
+                &departments_set,
+                options.auto_fuzzy_merge,
+            ))
+        })
+        .collect::<Result<Vec<PartialBuild>, String>>()?;
+    for partial in partials {
+        for warning in partial.warnings {
+            summary.warn(warning);
         }
-        if let Some(contact_map) = contact_map.get_mut(temp.department.as_str().unwrap()) {
-            match subject["ltrPrfsNm"].as_str() {
-                None => {}
-                Some(name) => {
-                    contact_map.insert(name.to_string(), json!({
-            "email": temp.email,
-            "mpno": temp.phone
-            }));
-                }
+        summary
+            .unclassified_departments
+            .extend(partial.unclassified_departments);
+        for (department, majors) in partial.departments {
+            departments_map.entry(department).or_default().extend(majors);
+        }
+        for (department, record) in partial.subjects {
+            if let Some(subject_map) = subject_map.get_mut(department) {
+                subject_map.push(record);
+                summary.subject_count += 1;
+            }
+        }
+        for (department, name, contact) in partial.contacts {
+            if let Some(contact_map) = contact_map.get_mut(department) {
+                contact_map.insert(name, contact);
             }
         }
     }
@@ -318,9 +2145,9 @@ pub fn make_db_content<'make_db>(
         "version": {
             "app_ver": latest_app_version,
             "db_ver": db_version,
-            "legacy_app_ver": "0.0"
+            "legacy_app_ver": options.legacy_app_version
         }
 
     });
-    Ok(result.to_string())
+    Ok((result, summary))
 }
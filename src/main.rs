@@ -1,11 +1,49 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
 use clap::Parser;
 
+use suwon_mate_admin_tool::history::{
+    append_history, current_user, default_history_file, history_entry,
+};
+use suwon_mate_admin_tool::memory::TrackingAllocator;
 use suwon_mate_admin_tool::*;
 
+#[global_allocator]
+static ALLOCATOR: TrackingAllocator = TrackingAllocator;
+
 fn main() {
-    let program_arguments = ProgramArgument::parse();
-    if let Err(error) = file_process(program_arguments) {
-        println!("응용 프로그램 오류 발생: {}", error);
+    platform::init_console();
+    let started_at = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs() as i64);
+    let args: Vec<String> = std::env::args_os()
+        .skip(1)
+        .map(|arg| arg.to_string_lossy().into_owned())
+        .collect();
+    let cli = Cli::parse();
+    let history_file = (cli.command.is_none() && !cli.no_history).then(|| {
+        cli.history_file
+            .clone()
+            .map_or_else(default_history_file, Ok)
+    });
+    let result = network::init_agent(cli.proxy.as_deref()).and_then(|_| match cli.command {
+        Some(command) => command_process(command),
+        None => file_process(cli.build.expect("DB 제작 인자가 누락되었습니다.")),
+    });
+    if let Some(history_file) = history_file {
+        let error = result.as_ref().err().map(ToString::to_string);
+        let entry = history_entry(
+            started_at,
+            current_user().as_deref(),
+            &args,
+            error.as_deref().map_or(Ok(()), Err),
+        );
+        if let Err(error) = history_file.and_then(|path| append_history(&path, &entry)) {
+            notice!("주의: 실행 기록을 남기지 못했습니다. ({})", error);
+        }
+    }
+    if let Err(error) = result {
+        notice!("응용 프로그램 오류 발생: {}", error);
 
         std::process::exit(1);
     }
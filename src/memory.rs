@@ -0,0 +1,206 @@
+//! # 메모리 사용량 보호
+//!
+//! 저사양 PC에서 메모리 부족으로 프로그램이 강제 종료되지 않도록 메모리 사용량을 추적하고, `--max-memory`로 지정한
+//! 상한을 넘을 것으로 예상되거나 실제로 넘은 경우 명확한 오류를 내고 작업을 중단한다.
+//! 예상 사용량은 입력 파일을 읽기 전에 파일 크기로 계산하며([MemoryLimit::check_inputs]), 실제 사용량은 DB 제작 중
+//! 과목을 나누어 처리할 때마다 확인한다([MemoryLimit::check]).
+//!
+//! 사용량은 [TrackingAllocator]가 할당 및 해제된 바이트 수를 세어 추적한다. 실행 파일에서 전역 할당자로 등록해야 하며,
+//! 등록되지 않은 경우(라이브러리로 사용하는 경우 등) 사용량은 항상 0으로 집계된다.
+//!
+//! 할당마다 원자적 연산으로 사용량을 세는 비용이 들기 때문에, [enable_tracking]을 호출하기 전에는 사용량을 세지 않는다.
+//! 추적하지 않는 동안에는 할당마다 추적 여부를 한 번 읽는 비용만 든다. 실행 파일은 `--max-memory`가 지정된 경우에만
+//! 추적을 시작한다.
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::error::Error;
+use std::sync::atomic::{AtomicBool, AtomicIsize, Ordering};
+
+/// 입력 파일 1바이트당 DB 제작에 필요한 것으로 예상되는 메모리 (바이트)
+///
+/// 입력 JSON을 `Value` 트리로 해독하면 원본의 수 배에 달하는 메모리가 필요하며, 결과 DB와 직렬화된 결과 파일도 함께 메모리에 올라간다.
+pub const BYTES_PER_INPUT_BYTE: u64 = 12;
+
+/// 사용량을 세고 있는지 여부
+static TRACKING: AtomicBool = AtomicBool::new(false);
+/// 추적을 시작한 이후 할당된 바이트 수
+///
+/// 추적을 시작하기 전에 할당된 메모리가 해제되면 음수가 될 수 있다.
+static ALLOCATED: AtomicIsize = AtomicIsize::new(0);
+/// 추적을 시작한 이후 가장 많이 할당되었던 바이트 수
+static PEAK: AtomicIsize = AtomicIsize::new(0);
+
+/// 할당된 메모리의 양을 집계하는 전역 할당자
+///
+/// ```
+/// use suwon_mate_admin_tool::memory::{allocated, enable_tracking, peak, TrackingAllocator};
+///
+/// #[global_allocator]
+/// static ALLOCATOR: TrackingAllocator = TrackingAllocator;
+///
+/// fn main() {
+///     assert_eq!(allocated(), 0);
+///     enable_tracking();
+///     let before = allocated();
+///     let buffer = vec![0u8; 1024 * 1024];
+///     assert!(allocated() >= before + buffer.len());
+///     drop(buffer);
+///     assert!(allocated() < before + 1024 * 1024);
+///     assert!(peak() >= before + 1024 * 1024);
+/// }
+/// ```
+pub struct TrackingAllocator;
+
+unsafe impl GlobalAlloc for TrackingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let pointer = System.alloc(layout);
+        if !pointer.is_null() && TRACKING.load(Ordering::Relaxed) {
+            record(layout.size() as isize);
+        }
+        pointer
+    }
+
+    unsafe fn dealloc(&self, pointer: *mut u8, layout: Layout) {
+        System.dealloc(pointer, layout);
+        if TRACKING.load(Ordering::Relaxed) {
+            record(-(layout.size() as isize));
+        }
+    }
+
+    unsafe fn realloc(&self, pointer: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_pointer = System.realloc(pointer, layout, new_size);
+        if !new_pointer.is_null() && TRACKING.load(Ordering::Relaxed) {
+            record(new_size as isize - layout.size() as isize);
+        }
+        new_pointer
+    }
+}
+
+/// 할당된 바이트 수를 `change`만큼 바꾸고 최대 사용량을 갱신하는 메서드
+fn record(change: isize) {
+    let allocated = ALLOCATED.fetch_add(change, Ordering::Relaxed) + change;
+    if change > 0 {
+        PEAK.fetch_max(allocated, Ordering::Relaxed);
+    }
+}
+
+/// 메모리 사용량 추적을 시작하는 메서드
+///
+/// 이후에 할당 및 해제된 메모리만 집계하므로, 사용량은 추적을 시작한 시점을 기준으로 한다.
+pub fn enable_tracking() {
+    TRACKING.store(true, Ordering::Relaxed);
+}
+
+/// 추적을 시작한 이후 현재 할당된 메모리의 양 (바이트)
+pub fn allocated() -> usize {
+    ALLOCATED.load(Ordering::Relaxed).max(0) as usize
+}
+
+/// 추적을 시작한 이후 가장 많이 할당되었던 메모리의 양 (바이트)
+pub fn peak() -> usize {
+    PEAK.load(Ordering::Relaxed).max(0) as usize
+}
+
+/// `512M`, `2G`와 같은 크기 표기를 바이트 수로 바꾸는 메서드
+///
+/// 단위는 `K`, `M`, `G`(1024의 거듭제곱)를 사용할 수 있으며, 뒤에 붙는 `B`나 `iB`는 무시한다. 단위가 없으면 바이트로 본다.
+///
+/// ## Examples
+/// ```
+/// use suwon_mate_admin_tool::memory::parse_size;
+/// assert_eq!(parse_size("512M").unwrap(), 512 * 1024 * 1024);
+/// assert_eq!(parse_size("1.5GiB").unwrap(), 3 * 512 * 1024 * 1024);
+/// assert_eq!(parse_size("4096").unwrap(), 4096);
+/// assert!(parse_size("많이").is_err());
+/// assert!(parse_size("0").is_err());
+/// ```
+///
+/// ## Errors
+/// 알 수 없는 표기이거나 크기가 0인 경우 오류가 발생한다.
+pub fn parse_size(text: &str) -> Result<u64, Box<dyn Error>> {
+    let invalid = || format!("알 수 없는 크기입니다: {} (예: 512M, 2G)", text);
+    let upper = text.trim().to_ascii_uppercase();
+    let number = upper
+        .trim_end_matches('B')
+        .trim_end_matches('I')
+        .trim_end_matches(['K', 'M', 'G']);
+    let unit: u64 = match upper[number.len()..].chars().next() {
+        Some('K') => 1 << 10,
+        Some('M') => 1 << 20,
+        Some('G') => 1 << 30,
+        _ => 1,
+    };
+    let size = number.trim().parse::<f64>().map_err(|_| invalid())? * unit as f64;
+    if !size.is_finite() || size < 1.0 {
+        return Err(invalid().into());
+    }
+    Ok(size as u64)
+}
+
+/// 바이트 수를 읽기 쉬운 형태로 바꾸는 메서드
+///
+/// ## Examples
+/// ```
+/// use suwon_mate_admin_tool::memory::format_size;
+/// assert_eq!(format_size(512), "512B");
+/// assert_eq!(format_size(3 * 512 * 1024 * 1024), "1.5GiB");
+/// ```
+pub fn format_size(bytes: u64) -> String {
+    let units = [("GiB", 1u64 << 30), ("MiB", 1 << 20), ("KiB", 1 << 10)];
+    units
+        .iter()
+        .find(|(_, unit)| bytes >= *unit)
+        .map_or(format!("{}B", bytes), |(name, unit)| {
+            format!("{:.1}{}", bytes as f64 / *unit as f64, name)
+        })
+}
+
+/// 메모리 사용량의 상한
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct MemoryLimit(pub u64);
+
+impl MemoryLimit {
+    /// 입력 파일의 크기로 예상한 메모리 사용량이 상한을 넘는지 확인하는 메서드
+    ///
+    /// ## Examples
+    /// ```
+    /// use suwon_mate_admin_tool::memory::MemoryLimit;
+    /// let limit = MemoryLimit(1024 * 1024);
+    /// assert!(limit.check_inputs(1024).is_ok());
+    /// assert!(limit.check_inputs(1024 * 1024).is_err());
+    /// ```
+    ///
+    /// ## Errors
+    /// 예상 사용량이 상한을 넘는 경우 오류가 발생한다.
+    pub fn check_inputs(&self, input_bytes: u64) -> Result<(), Box<dyn Error>> {
+        let estimated = input_bytes.saturating_mul(BYTES_PER_INPUT_BYTE);
+        if estimated > self.0 {
+            return Err(format!(
+                "입력 파일({})로 DB를 만드는 데 약 {}의 메모리가 필요할 것으로 예상되어 상한({})을 넘습니다. \
+                 --max-memory 값을 늘리거나 메모리가 더 큰 PC에서 실행해주세요.",
+                format_size(input_bytes),
+                format_size(estimated),
+                format_size(self.0)
+            )
+            .into());
+        }
+        Ok(())
+    }
+
+    /// 현재 메모리 사용량이 상한을 넘었는지 확인하는 메서드
+    ///
+    /// ## Errors
+    /// 현재 사용량이 상한을 넘은 경우 오류가 발생한다.
+    pub fn check(&self, stage: &str) -> Result<(), Box<dyn Error>> {
+        let current = allocated() as u64;
+        if current > self.0 {
+            return Err(format!(
+                "{} 중 메모리 사용량(약 {})이 상한({})을 넘어 작업을 중단하였습니다.",
+                stage,
+                format_size(current),
+                format_size(self.0)
+            )
+            .into());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,99 @@
+//! # 즐겨찾기 이관
+//!
+//! 학기가 바뀌면 앱 사용자의 즐겨찾기에 저장된 과목 키(`subjtCd-diclNo`)가 더 이상 유효하지 않게 된다.
+//! 이전 학기 DB와 새 학기 DB를 비교하여 동일한 과목의 새 과목 키를 알려주는 매핑 테이블을 생성한다.
+use std::collections::HashMap;
+
+use serde_json::{json, Map, Value};
+
+use crate::output::subjects;
+use crate::subject_key;
+
+/// 후보가 정확히 하나인 경우에만 해당 후보를 반환하는 메서드
+fn unique<'db>(candidates: Option<&Vec<&'db Value>>) -> Option<&'db Value> {
+    match candidates.map(Vec::as_slice) {
+        Some([candidate]) => Some(candidate),
+        _ => None,
+    }
+}
+
+/// 이전 학기 DB의 과목 키를 새 학기 DB의 과목 키로 연결하는 매핑 테이블을 만드는 메서드
+///
+/// 이전 학기의 과목마다 아래 순서대로 새 학기의 과목을 찾는다. 두 번째 단계부터는 후보가 하나인 경우에만 연결된다.
+///
+/// 1. 과목 키와 과목명이 모두 같은 과목
+/// 2. 과목 코드와 교수명이 같은 과목
+/// 3. 과목명과 교수명이 같은 과목
+/// 4. 과목 코드가 같은 과목
+///
+/// 결과는 `mappings`(이전 과목 키 → 새 과목 키)와 연결하지 못한 이전 과목 키 목록인 `unmatched`로 구성된다.
+///
+/// ## Arguments
+///
+/// * `previous` - 이전 학기 DB의 내용
+/// * `current` - 새 학기 DB의 내용
+///
+/// ## Examples
+/// ```
+/// use serde_json::json;
+/// use suwon_mate_admin_tool::migration::favorite_mapping;
+/// let previous = json!({"estbLectDtaiList": {"경영학부": [
+///     {"subjtCd": "11416", "diclNo": "037", "subjtNm": "전공진로세미나2", "ltrPrfsNm": "홍길동"},
+///     {"subjtCd": "11417", "diclNo": "001", "subjtNm": "폐강과목", "ltrPrfsNm": "김철수"}
+/// ]}});
+/// let current = json!({"estbLectDtaiList": {"경영학부": [
+///     {"subjtCd": "11416", "diclNo": "041", "subjtNm": "전공진로세미나2", "ltrPrfsNm": "홍길동"}
+/// ]}});
+/// let mapping = favorite_mapping(&previous, &current);
+/// assert_eq!(mapping["mappings"]["11416-037"], "11416-041");
+/// assert_eq!(mapping["unmatched"], json!(["11417-001"]));
+/// ```
+pub fn favorite_mapping(previous: &Value, current: &Value) -> Value {
+    let mut by_key: HashMap<String, &Value> = HashMap::new();
+    let mut by_code_professor: HashMap<(&str, &str), Vec<&Value>> = HashMap::new();
+    let mut by_name_professor: HashMap<(&str, &str), Vec<&Value>> = HashMap::new();
+    let mut by_code: HashMap<&str, Vec<&Value>> = HashMap::new();
+    for subject in subjects(current) {
+        let code = subject["subjtCd"].as_str().unwrap_or("");
+        let name = subject["subjtNm"].as_str().unwrap_or("");
+        by_key.insert(subject_key(subject), subject);
+        if let Some(professor) = subject["ltrPrfsNm"].as_str() {
+            by_code_professor
+                .entry((code, professor))
+                .or_default()
+                .push(subject);
+            by_name_professor
+                .entry((name, professor))
+                .or_default()
+                .push(subject);
+        }
+        by_code.entry(code).or_default().push(subject);
+    }
+
+    let mut mappings = Map::new();
+    let mut unmatched = vec![];
+    for subject in subjects(previous) {
+        let key = subject_key(subject);
+        let code = subject["subjtCd"].as_str().unwrap_or("");
+        let name = subject["subjtNm"].as_str().unwrap_or("");
+        let professor = subject["ltrPrfsNm"].as_str();
+        let found = by_key
+            .get(&key)
+            .copied()
+            .filter(|candidate| candidate["subjtNm"] == subject["subjtNm"])
+            .or_else(|| unique(professor.and_then(|p| by_code_professor.get(&(code, p)))))
+            .or_else(|| unique(professor.and_then(|p| by_name_professor.get(&(name, p)))))
+            .or_else(|| unique(by_code.get(code)));
+        match found {
+            Some(candidate) => {
+                mappings.insert(key, Value::String(subject_key(candidate)));
+            }
+            None => unmatched.push(key),
+        }
+    }
+    unmatched.sort();
+    json!({
+        "mappings": mappings,
+        "unmatched": unmatched,
+    })
+}
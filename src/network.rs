@@ -0,0 +1,205 @@
+//! # 네트워크 계층
+//!
+//! 모든 네트워크 요청은 [agent]가 반환하는 공용 HTTP 에이전트를 사용한다. 교내망에서만 접근되는 API를 위해
+//! `--proxy` 옵션 또는 `HTTPS_PROXY`, `HTTP_PROXY` 환경 변수로 지정한 프록시를 거쳐 요청할 수 있다.
+//!
+//! 포털 API는 종종 타임아웃이 나거나 일시적으로 오류를 응답하므로, 네트워크 요청을 지수 백오프로 재시도하고
+//! 연속된 요청 사이에 지연을 두어 서버에 부담을 주지 않도록 한다.
+//!
+//! 연결 실패, 타임아웃 등 전송 오류와 `429 Too Many Requests`, `5xx` 응답만 재시도하며, 그 외의 응답은 바로 실패로 본다.
+use std::error::Error;
+use std::fmt::Display;
+use std::sync::OnceLock;
+use std::thread;
+use std::time::Duration;
+
+use ureq::{Agent, AgentBuilder, Proxy};
+
+/// 프록시를 찾을 환경 변수 목록 (앞의 변수가 우선한다)
+pub const PROXY_ENV_VARS: [&str; 4] = ["HTTPS_PROXY", "https_proxy", "HTTP_PROXY", "http_proxy"];
+
+/// 공용 HTTP 에이전트
+static AGENT: OnceLock<Agent> = OnceLock::new();
+
+/// 환경 변수에 지정된 프록시를 찾는 메서드
+pub fn proxy_from_env() -> Option<String> {
+    PROXY_ENV_VARS
+        .iter()
+        .filter_map(|name| std::env::var(name).ok())
+        .find(|proxy| !proxy.trim().is_empty())
+}
+
+/// `proxy`를 거쳐 요청하는 HTTP 에이전트를 만드는 메서드
+///
+/// ## Examples
+/// ```
+/// use suwon_mate_admin_tool::network::build_agent;
+/// assert!(build_agent(None).is_ok());
+/// assert!(build_agent(Some("http://proxy.suwon.ac.kr:8080")).is_ok());
+/// assert!(build_agent(Some("ftp://proxy.suwon.ac.kr")).is_err());
+/// ```
+///
+/// ## Errors
+/// 프록시 주소가 올바르지 않은 경우 오류가 발생한다.
+pub fn build_agent(proxy: Option<&str>) -> Result<Agent, Box<dyn Error>> {
+    let mut builder = AgentBuilder::new();
+    if let Some(proxy) = proxy {
+        let proxy = Proxy::new(proxy)
+            .map_err(|error| format!("올바르지 않은 프록시 주소입니다: {} ({})", proxy, error))?;
+        builder = builder.proxy(proxy);
+    }
+    Ok(builder.build())
+}
+
+/// 공용 HTTP 에이전트가 사용할 프록시를 정하는 메서드
+///
+/// `proxy`가 없으면 [PROXY_ENV_VARS]의 환경 변수에서 프록시를 찾는다. 첫 요청 전에 한 번만 호출해야 한다.
+///
+/// ## Errors
+/// * 프록시 주소가 올바르지 않은 경우
+/// * 이미 공용 에이전트가 만들어진 경우
+pub fn init_agent(proxy: Option<&str>) -> Result<(), Box<dyn Error>> {
+    let proxy = proxy.map(str::to_string).or_else(proxy_from_env);
+    AGENT
+        .set(build_agent(proxy.as_deref())?)
+        .map_err(|_| "HTTP 에이전트가 이미 만들어졌습니다.".into())
+}
+
+/// 공용 HTTP 에이전트를 반환하는 메서드
+///
+/// [init_agent]를 호출하지 않은 경우 환경 변수의 프록시를 사용하며, 프록시 주소가 올바르지 않으면 프록시 없이 요청한다.
+pub fn agent() -> &'static Agent {
+    AGENT.get_or_init(|| {
+        build_agent(proxy_from_env().as_deref()).unwrap_or_else(|error| {
+            notice!("주의: {} 프록시 없이 요청합니다.", error);
+            ureq::agent()
+        })
+    })
+}
+
+/// 재시도 정책
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct RetryPolicy {
+    /// 첫 요청 이후 다시 시도할 최대 횟수
+    pub max_retries: u32,
+    /// 첫 재시도 전의 대기 시간 (재시도할 때마다 두 배로 늘어난다)
+    pub initial_backoff: Duration,
+    /// 재시도 전 대기 시간의 최댓값
+    pub max_backoff: Duration,
+    /// 연속된 요청 사이의 지연 시간
+    pub request_delay: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(10),
+            request_delay: Duration::from_millis(200),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// `attempt`번째(0부터 시작) 재시도 전의 대기 시간을 구하는 메서드
+    ///
+    /// ## Examples
+    /// ```
+    /// use std::time::Duration;
+    /// use suwon_mate_admin_tool::network::RetryPolicy;
+    /// let policy = RetryPolicy::default();
+    /// assert_eq!(policy.backoff(0), Duration::from_millis(500));
+    /// assert_eq!(policy.backoff(2), Duration::from_secs(2));
+    /// assert_eq!(policy.backoff(10), Duration::from_secs(10));
+    /// ```
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        self.initial_backoff
+            .saturating_mul(2u32.saturating_pow(attempt))
+            .min(self.max_backoff)
+    }
+}
+
+/// 요청 오류가 재시도할 만한 일시적인 오류인지 확인하는 메서드
+pub fn is_retryable(error: &ureq::Error) -> bool {
+    match error {
+        ureq::Error::Status(status, _) => *status == 429 || *status >= 500,
+        ureq::Error::Transport(_) => true,
+    }
+}
+
+/// `request`를 재시도 정책에 따라 실행하는 메서드
+///
+/// `retryable`이 `true`를 반환하는 오류가 발생하면 [RetryPolicy::backoff]만큼 기다린 뒤 다시 시도한다.
+/// `ureq::Error`처럼 큰 오류 값이 그대로 오가지 않도록 `request`는 오류를 `Box`에 담아 반환한다.
+///
+/// ## Arguments
+///
+/// * `policy` - 재시도 정책
+/// * `description` - 오류 메세지에 사용할 요청의 설명
+/// * `retryable` - 오류가 재시도할 만한 오류인지 확인하는 함수
+/// * `request` - 요청을 보내는 함수
+///
+/// ## Examples
+/// ```
+/// use std::time::Duration;
+/// use suwon_mate_admin_tool::network::{retry, RetryPolicy};
+/// let policy = RetryPolicy { initial_backoff: Duration::ZERO, ..Default::default() };
+/// let mut attempts = 0;
+/// let result = retry(&policy, "요청", |_: &&str| true, || {
+///     attempts += 1;
+///     if attempts < 3 { Err(Box::new("타임아웃")) } else { Ok(attempts) }
+/// });
+/// assert_eq!(result.unwrap(), 3);
+///
+/// let mut attempts = 0;
+/// let result: Result<(), _> = retry(&policy, "요청", |_: &&str| true, || {
+///     attempts += 1;
+///     Err(Box::new("타임아웃"))
+/// });
+/// assert!(result.is_err());
+/// assert_eq!(attempts, 4);
+///
+/// let mut attempts = 0;
+/// let result: Result<(), _> = retry(&policy, "요청", |_: &&str| false, || {
+///     attempts += 1;
+///     Err(Box::new("404"))
+/// });
+/// assert!(result.is_err());
+/// assert_eq!(attempts, 1);
+/// ```
+///
+/// ## Errors
+/// 재시도할 수 없는 오류가 발생하였거나 최대 재시도 횟수를 넘은 경우 마지막 오류가 반환된다.
+pub fn retry<T, E, R, F>(
+    policy: &RetryPolicy,
+    description: &str,
+    retryable: R,
+    mut request: F,
+) -> Result<T, Box<dyn Error>>
+where
+    E: Display,
+    R: Fn(&E) -> bool,
+    F: FnMut() -> Result<T, Box<E>>,
+{
+    let mut attempt = 0;
+    loop {
+        match request() {
+            Ok(value) => return Ok(value),
+            Err(error) if attempt < policy.max_retries && retryable(&error) => {
+                let backoff = policy.backoff(attempt);
+                attempt += 1;
+                notice!(
+                    "주의: {}에 실패하여 {}ms 후 다시 시도합니다. ({}/{}) ({})",
+                    description,
+                    backoff.as_millis(),
+                    attempt,
+                    policy.max_retries,
+                    error
+                );
+                thread::sleep(backoff);
+            }
+            Err(error) => return Err(format!("{}에 실패하였습니다: {}", description, error).into()),
+        }
+    }
+}
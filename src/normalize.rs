@@ -0,0 +1,97 @@
+//! # 입력 정규화
+//!
+//! 입력 파일을 해독한 직후 개설 강좌 조회 DB와 강의 계획서 DB의 과목 정보를 같은 형태로 맞추는 전처리 단계이다.
+//! 이후의 DB 제작과 후처리는 모두 정규화된 과목 정보를 사용하므로, 과목 키 비교나 학부 분류가 입력 파일의 표기 차이로
+//! 실패하지 않는다.
+//!
+//! * 코드 필드([CODE_FIELDS])는 숫자이면 문자열로 바꾸고 앞뒤 공백을 제거한다.
+//! * 이름 필드([NAME_FIELDS])는 앞뒤 공백을 제거한다.
+//! * 숫자 필드([NUMBER_FIELDS])는 숫자로 해독되는 문자열이면 숫자로 바꾼다.
+use serde_json::{Map, Number, Value};
+
+use crate::input::SUBJECT_LIST_KEY;
+
+/// 문자열로 통일하고 공백을 제거하는 코드 필드
+pub const CODE_FIELDS: [&str; 4] = ["subjtCd", "diclNo", "trgtGrdeCd", "subjtEstbYear"];
+/// 앞뒤 공백을 제거하는 이름 필드
+pub const NAME_FIELDS: [&str; 4] = ["estbDpmjNm", "estbMjorNm", "ltrPrfsNm", "subjtNm"];
+/// 숫자로 통일하는 필드
+pub const NUMBER_FIELDS: [&str; 1] = ["point"];
+
+/// 과목 정보 하나를 정규화하는 메서드
+///
+/// 값을 바꾼 필드의 수를 반환한다.
+///
+/// ## Examples
+/// ```
+/// use serde_json::json;
+/// use suwon_mate_admin_tool::normalize::normalize_subject;
+/// let mut subject = json!({"subjtCd": 11416, "diclNo": " 038 ", "estbDpmjNm": "경영학부 ", "point": "3", "subjtNm": "회계원리"});
+/// assert_eq!(normalize_subject(subject.as_object_mut().unwrap()), 4);
+/// assert_eq!(subject, json!({"subjtCd": "11416", "diclNo": "038", "estbDpmjNm": "경영학부", "point": 3, "subjtNm": "회계원리"}));
+/// ```
+pub fn normalize_subject(subject: &mut Map<String, Value>) -> usize {
+    let mut changed = 0;
+    for field in CODE_FIELDS {
+        let normalized = match subject.get(field) {
+            Some(Value::Number(number)) => Some(Value::String(number.to_string())),
+            Some(Value::String(code)) if code.trim() != code => {
+                Some(Value::String(code.trim().to_string()))
+            }
+            _ => None,
+        };
+        if let Some(normalized) = normalized {
+            subject.insert(field.to_string(), normalized);
+            changed += 1;
+        }
+    }
+    for field in NAME_FIELDS {
+        if let Some(Value::String(name)) = subject.get_mut(field) {
+            if name.trim() != name {
+                *name = name.trim().to_string();
+                changed += 1;
+            }
+        }
+    }
+    for field in NUMBER_FIELDS {
+        let number = match subject.get(field) {
+            Some(Value::String(number)) => parse_number(number.trim()),
+            _ => None,
+        };
+        if let Some(number) = number {
+            subject.insert(field.to_string(), Value::Number(number));
+            changed += 1;
+        }
+    }
+    changed
+}
+
+/// 문자열을 JSON 숫자로 해독하는 메서드 (정수는 정수로, 그 외에는 실수로 해독)
+fn parse_number(text: &str) -> Option<Number> {
+    match text.parse::<i64>() {
+        Ok(number) => Some(Number::from(number)),
+        Err(_) => text.parse::<f64>().ok().and_then(Number::from_f64),
+    }
+}
+
+/// 입력 파일의 과목 목록을 모두 정규화하는 메서드
+///
+/// 값을 바꾼 필드의 수를 반환한다.
+///
+/// ## Examples
+/// ```
+/// use serde_json::json;
+/// use suwon_mate_admin_tool::normalize::normalize_input;
+/// let mut input = json!({"estbLectDtaiList": [{"subjtCd": " 1"}, {"subjtCd": "2"}, "과목"]});
+/// assert_eq!(normalize_input(&mut input), 1);
+/// assert_eq!(input["estbLectDtaiList"][0]["subjtCd"], "1");
+/// ```
+pub fn normalize_input(input: &mut Value) -> usize {
+    input[SUBJECT_LIST_KEY]
+        .as_array_mut()
+        .into_iter()
+        .flatten()
+        .filter_map(Value::as_object_mut)
+        .map(normalize_subject)
+        .sum()
+}
@@ -0,0 +1,107 @@
+//! # 학과 사무실 연락처
+//!
+//! 별도로 관리하는 학과 사무실 연락처 파일을 검사하여 DB의 `department_contacts` 섹션으로 기록한다.
+//!
+//! 연락처 파일은 아래와 같은 항목의 배열이다. `department`(학부명)는 필수이며, `major`(학과명)를 생략하면 학부 사무실로 기록된다.
+//! `phone`과 `email` 중 하나 이상은 있어야 하며, `location`(사무실 위치)은 생략할 수 있다.
+//!
+//! ```json
+//! [{"department": "경영학부", "major": "경영학과", "phone": "031-229-0000", "email": "biz@suwon.ac.kr", "location": "글로벌경영관 201호"},
+//!  {"department": "경영학부", "phone": "031-229-0001"}]
+//! ```
+use std::error::Error;
+
+use serde_json::{json, Map, Value};
+
+use crate::output::department_lists;
+
+/// 연락처 파일의 내용을 검사하여 DB에 기록할 학과 사무실 연락처를 만드는 메서드
+///
+/// 결과는 학부명과 학과명(학부 사무실은 학부명)을 키로 하는 `{phone, email, location}` 객체이며, 없는 값은 `null`로 기록된다.
+/// DB에 없는 학부나 학과의 연락처도 기록되지만, 오타일 수 있으므로 경고 메세지 목록으로 함께 반환한다.
+///
+/// ## Arguments
+///
+/// * `db` - 학부 및 학과 목록을 확인할 DB의 내용
+/// * `content` - 연락처 파일의 내용
+///
+/// ## Examples
+/// ```
+/// use serde_json::json;
+/// use suwon_mate_admin_tool::office::department_contacts;
+/// let db = json!({"departments": {"경영학부": ["경영학과"]}});
+/// let (contacts, warnings) = department_contacts(&db, &json!([
+///     {"department": "경영학부", "major": "경영학과", "phone": "031-229-0000"},
+///     {"department": "경영학부", "email": "biz@suwon.ac.kr"},
+///     {"department": "경영학부", "major": "회계학과", "phone": "031-229-0002"}
+/// ])).unwrap();
+/// assert_eq!(contacts["경영학부"]["경영학과"]["phone"], "031-229-0000");
+/// assert_eq!(contacts["경영학부"]["경영학부"]["email"], "biz@suwon.ac.kr");
+/// assert_eq!(warnings.len(), 1);
+/// assert!(department_contacts(&db, &json!([{"department": "경영학부"}])).is_err());
+/// ```
+///
+/// ## Errors
+/// * 내용이 배열이 아닌 경우
+/// * 항목에 `department`가 없거나 `phone`과 `email`이 모두 없는 경우
+/// * 같은 학부와 학과의 연락처가 두 번 이상 기록된 경우
+pub fn department_contacts(
+    db: &Value,
+    content: &Value,
+) -> Result<(Value, Vec<String>), Box<dyn Error>> {
+    let entries = content
+        .as_array()
+        .ok_or("학과 사무실 연락처 파일은 항목의 배열이어야 합니다.")?;
+    let mut contacts = Map::new();
+    let mut warnings = vec![];
+    for (index, entry) in entries.iter().enumerate() {
+        let invalid = |reason: &str| {
+            format!(
+                "학과 사무실 연락처 {}번째 항목 {}: {}",
+                index + 1,
+                reason,
+                entry
+            )
+        };
+        let department = entry["department"]
+            .as_str()
+            .ok_or_else(|| invalid("학부명(department)이 없습니다"))?;
+        let major = entry["major"].as_str().unwrap_or(department);
+        if entry["phone"].as_str().is_none() && entry["email"].as_str().is_none() {
+            return Err(invalid("전화번호(phone)와 이메일(email)이 모두 없습니다").into());
+        }
+        let majors = department_lists(db).and_then(|lists| lists.get(department));
+        let known = match majors {
+            Some(majors) => {
+                major == department
+                    || majors
+                        .as_array()
+                        .is_some_and(|majors| majors.iter().any(|name| name == major))
+            }
+            None => false,
+        };
+        if !known {
+            warnings.push(format!(
+                "주의: 학과 사무실 연락처의 학부 또는 학과가 DB에 없습니다. ({} {})",
+                department, major
+            ));
+        }
+        let offices = contacts
+            .entry(department)
+            .or_insert_with(|| json!({}))
+            .as_object_mut()
+            .unwrap();
+        if offices.contains_key(major) {
+            return Err(invalid("같은 학부와 학과의 연락처가 이미 기록되었습니다").into());
+        }
+        offices.insert(
+            major.to_string(),
+            json!({
+                "phone": entry["phone"],
+                "email": entry["email"],
+                "location": entry["location"],
+            }),
+        );
+    }
+    Ok((Value::Object(contacts), warnings))
+}
@@ -0,0 +1,99 @@
+//! # BSON 출력
+//!
+//! MongoDB에 적재할 수 있도록 DB를 BSON 도큐먼트로 저장한다.
+//! 파일은 BSON 도큐먼트를 이어 붙인 형태로, `mongorestore`나 `bsondump`로 바로 읽을 수 있다.
+use std::error::Error;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+use clap::ValueEnum;
+use serde_json::{json, Value};
+
+use crate::output::{department_lists, subject_lists};
+
+/// BSON 도큐먼트를 나누는 기준
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default, ValueEnum)]
+pub enum BsonSplit {
+    /// DB 전체를 JSON과 같은 구조의 도큐먼트 하나로 기록
+    #[default]
+    Single,
+    /// 학부 하나당 학과 목록, 과목 목록, 교수 연락처, 버전 정보를 가진 도큐먼트 하나로 기록
+    Department,
+}
+
+/// DB를 BSON 파일에 기록할 도큐먼트 목록으로 나누는 메서드
+///
+/// [BsonSplit::Department]의 경우 각 도큐먼트의 `_id`는 학부명이다.
+///
+/// ## Examples
+/// ```
+/// use serde_json::json;
+/// use suwon_mate_admin_tool::output::bson::{bson_documents, BsonSplit};
+/// let db = json!({
+///     "departments": {"경영학부": ["경영학과"]},
+///     "estbLectDtaiList": {"경영학부": [{"subjtCd": "1"}], "교양대학": []},
+///     "contacts": {"경영학부": {"홍길동": {"email": null, "mpno": null}}},
+///     "version": {"db_ver": "1.0"}
+/// });
+/// assert_eq!(bson_documents(&db, BsonSplit::Single), vec![db.clone()]);
+/// let documents = bson_documents(&db, BsonSplit::Department);
+/// assert_eq!(documents.len(), 2);
+/// assert_eq!(documents[0]["_id"], "경영학부");
+/// assert_eq!(documents[0]["majors"], json!(["경영학과"]));
+/// assert_eq!(documents[1]["majors"], json!([]));
+/// assert_eq!(documents[1]["version"]["db_ver"], "1.0");
+/// ```
+pub fn bson_documents(db: &Value, split: BsonSplit) -> Vec<Value> {
+    match split {
+        BsonSplit::Single => vec![db.clone()],
+        BsonSplit::Department => subject_lists(db)
+            .into_iter()
+            .flatten()
+            .map(|(department, subjects)| {
+                json!({
+                    "_id": department,
+                    "majors": department_lists(db)
+                        .and_then(|lists| lists.get(department))
+                        .cloned()
+                        .unwrap_or_else(|| json!([])),
+                    "subjects": subjects,
+                    "contacts": db["contacts"].get(department).cloned().unwrap_or_else(|| json!({})),
+                    "version": db["version"],
+                })
+            })
+            .collect(),
+    }
+}
+
+/// DB를 BSON 파일로 저장하는 메서드
+///
+/// ## Arguments
+///
+/// * `db` - 저장할 DB의 내용
+/// * `path` - 저장할 파일의 경로
+/// * `split` - 도큐먼트를 나누는 기준
+///
+/// ## Examples
+/// ```
+/// use serde_json::json;
+/// use suwon_mate_admin_tool::output::bson::{write_bson, BsonSplit};
+/// let db = json!({"estbLectDtaiList": {"경영학부": [{"subjtCd": "1", "point": 3}]}, "version": {"db_ver": "1.0"}});
+/// let path = std::env::temp_dir().join("suwon_mate_write_bson_doctest.bson");
+/// write_bson(&db, &path, BsonSplit::Single).unwrap();
+/// let mut file = std::fs::File::open(&path).unwrap();
+/// let document = bson::Document::from_reader(&mut file).unwrap();
+/// assert_eq!(document.get_document("version").unwrap().get_str("db_ver").unwrap(), "1.0");
+/// ```
+///
+/// ## Errors
+/// * 파일을 만들거나 쓰는 과정에서 문제가 발생한 경우
+/// * DB의 내용을 BSON 도큐먼트로 바꿀 수 없는 경우
+pub fn write_bson(db: &Value, path: &Path, split: BsonSplit) -> Result<(), Box<dyn Error>> {
+    let mut writer = BufWriter::new(File::create(path)?);
+    for document in bson_documents(db, split) {
+        ::bson::to_document(&document)?.to_writer(&mut writer)?;
+    }
+    writer.flush()?;
+    Ok(())
+}
@@ -0,0 +1,90 @@
+//! # 열 지향 컴팩트 출력
+//!
+//! 과목마다 반복되는 필드명으로 인한 용량 낭비를 줄이기 위해, 과목 정보를 객체 대신 고정된 순서의 배열로 기록한다.
+//! 필드명 목록은 `fields`에 한 번만 기록되며, 과목 정보 외의 섹션은 JSON 출력과 같다.
+//!
+//! ```json
+//! {"estbLectDtaiList": {"fields": ["estbDpmjNm", "estbMjorNm", ...], "rows": {"경영학부": [["경영학부", "경영학과", ...]]}}, ...}
+//! ```
+use std::collections::BTreeSet;
+use std::error::Error;
+use std::path::Path;
+
+use serde_json::{json, Map, Value};
+
+use crate::output::{subject_lists, write_json};
+use crate::SUBJECT_FIELDS;
+
+/// DB를 열 지향 컴팩트 형식으로 바꾸는 메서드
+///
+/// 필드 순서는 [SUBJECT_FIELDS]를 따르며, `rating`과 같이 옵션에 따라 추가된 필드는 이름 순으로 그 뒤에 붙는다.
+/// 과목에 없는 필드(`--prune-null`로 생략된 필드 포함)는 `null`로 기록된다.
+///
+/// ## Examples
+/// ```
+/// use serde_json::json;
+/// use suwon_mate_admin_tool::output::columnar::to_columnar;
+/// let db = json!({
+///     "estbLectDtaiList_quick": {"경영학부": [
+///         {"estbDpmjNm": "경영학부", "subjtCd": "1", "rating": 4.5},
+///         {"estbDpmjNm": "경영학부", "subjtCd": "2"}
+///     ]},
+///     "version": {"db_ver": "1.0"}
+/// });
+/// let compact = to_columnar(&db);
+/// let fields = compact["estbLectDtaiList_quick"]["fields"].as_array().unwrap();
+/// assert_eq!(fields[0], "estbDpmjNm");
+/// assert_eq!(fields.last().unwrap(), "rating");
+/// let rows = &compact["estbLectDtaiList_quick"]["rows"]["경영학부"];
+/// let subjtcd = fields.iter().position(|field| field == "subjtCd").unwrap();
+/// assert_eq!(rows[1][subjtcd], "2");
+/// assert_eq!(rows[1][fields.len() - 1], json!(null));
+/// assert_eq!(compact["version"], db["version"]);
+/// ```
+pub fn to_columnar(db: &Value) -> Value {
+    let mut compact = db.clone();
+    let Some(lists) = subject_lists(db) else {
+        return compact;
+    };
+    let key = if db.get("estbLectDtaiList").is_some() {
+        "estbLectDtaiList"
+    } else {
+        "estbLectDtaiList_quick"
+    };
+    let subjects = || lists.values().filter_map(Value::as_array).flatten();
+    let extra_fields: BTreeSet<&str> = subjects()
+        .filter_map(Value::as_object)
+        .flat_map(|subject| subject.keys())
+        .map(String::as_str)
+        .filter(|field| !SUBJECT_FIELDS.contains(field))
+        .collect();
+    let fields: Vec<&str> = SUBJECT_FIELDS.into_iter().chain(extra_fields).collect();
+    let rows: Map<String, Value> = lists
+        .iter()
+        .map(|(department, subjects)| {
+            let rows: Vec<Value> = subjects
+                .as_array()
+                .into_iter()
+                .flatten()
+                .map(|subject| {
+                    fields
+                        .iter()
+                        .map(|field| subject.get(field).cloned().unwrap_or(Value::Null))
+                        .collect()
+                })
+                .collect();
+            (department.clone(), Value::Array(rows))
+        })
+        .collect();
+    compact[key] = json!({"fields": fields, "rows": rows});
+    compact
+}
+
+/// DB를 열 지향 컴팩트 형식의 JSON 파일로 저장하는 메서드
+///
+/// ## Errors
+/// 파일을 만들거나 쓰는 과정에서 문제가 발생한 경우 오류가 발생한다.
+pub fn write_columnar(db: &Value, path: &Path) -> Result<(), Box<dyn Error>> {
+    write_json(path, &to_columnar(db))?;
+    Ok(())
+}
@@ -0,0 +1,169 @@
+//! # 출력 파일 압축
+//!
+//! 저장이 끝난 DB 파일을 지정된 방식으로 압축한다. 압축된 파일은 원래 파일명 뒤에 압축 방식의 확장자가 붙는다.
+//! 압축 방식은 [Compression]으로 구분되며, 방식마다 지정할 수 있는 압축 레벨의 범위가 다르다.
+use std::error::Error;
+use std::fs::{self, File};
+use std::io::{self, BufReader, BufWriter, Write};
+use std::ops::RangeInclusive;
+use std::path::{Path, PathBuf};
+
+use clap::ValueEnum;
+use flate2::write::GzEncoder;
+
+/// Brotli 압축의 기본 레벨
+const BROTLI_DEFAULT_LEVEL: i32 = 11;
+
+/// Brotli 압축기의 버퍼 크기
+const BROTLI_BUFFER_SIZE: usize = 4096;
+
+/// Brotli 압축의 윈도우 크기 (2의 거듭제곱 지수)
+const BROTLI_WINDOW_BITS: u32 = 22;
+
+/// 출력 파일의 압축 방식
+#[derive(Copy, Clone, PartialEq, Eq, Debug, ValueEnum)]
+pub enum Compression {
+    /// 압축하지 않음
+    None,
+    /// gzip 압축 (`.gz`)
+    Gzip,
+    /// zstd 압축 (`.zst`)
+    Zstd,
+    /// Brotli 압축 (`.br`)
+    #[value(name = "br")]
+    Brotli,
+}
+
+impl Compression {
+    /// 압축 방식에 해당하는 확장자
+    ///
+    /// ## Examples
+    /// ```
+    /// use suwon_mate_admin_tool::output::compress::Compression;
+    /// assert_eq!(Compression::Gzip.extension(), Some("gz"));
+    /// assert_eq!(Compression::None.extension(), None);
+    /// ```
+    pub fn extension(&self) -> Option<&'static str> {
+        match self {
+            Compression::None => None,
+            Compression::Gzip => Some("gz"),
+            Compression::Zstd => Some("zst"),
+            Compression::Brotli => Some("br"),
+        }
+    }
+
+    /// 압축 방식에서 지정할 수 있는 압축 레벨의 범위
+    ///
+    /// ## Examples
+    /// ```
+    /// use suwon_mate_admin_tool::output::compress::Compression;
+    /// assert_eq!(Compression::Gzip.level_range(), 0..=9);
+    /// assert!(Compression::Zstd.level_range().contains(&19));
+    /// assert_eq!(Compression::Brotli.level_range(), 0..=11);
+    /// ```
+    pub fn level_range(&self) -> RangeInclusive<i32> {
+        match self {
+            Compression::None => 0..=0,
+            Compression::Gzip => 0..=9,
+            Compression::Zstd => zstd::compression_level_range(),
+            Compression::Brotli => 0..=11,
+        }
+    }
+}
+
+/// `path`의 파일을 압축하고 원본 파일을 지우는 메서드
+///
+/// 압축된 파일의 경로를 반환한다. `Compression::None`인 경우 아무 작업도 하지 않고 `path`를 그대로 반환한다.
+///
+/// ## Arguments
+///
+/// * `path` - 압축할 파일의 경로
+/// * `compression` - 압축 방식
+/// * `level` - 압축 레벨 (`None`인 경우 압축 방식의 기본 레벨)
+///
+/// ## Examples
+/// ```
+/// use std::io::Read;
+/// use flate2::read::GzDecoder;
+/// use suwon_mate_admin_tool::output::compress::{compress_file, Compression};
+/// let path = std::env::temp_dir().join("suwon_mate_compress_doctest.json");
+/// std::fs::write(&path, "{}").unwrap();
+/// let compressed = compress_file(&path, Compression::Gzip, None).unwrap();
+/// assert!(compressed.to_string_lossy().ends_with(".json.gz"));
+/// let mut content = String::new();
+/// GzDecoder::new(std::fs::File::open(&compressed).unwrap()).read_to_string(&mut content).unwrap();
+/// assert_eq!(content, "{}");
+///
+/// std::fs::write(&path, "{}").unwrap();
+/// let compressed = compress_file(&path, Compression::Zstd, Some(19)).unwrap();
+/// assert_eq!(zstd::decode_all(std::fs::File::open(&compressed).unwrap()).unwrap(), b"{}");
+///
+/// std::fs::write(&path, "{}").unwrap();
+/// let compressed = compress_file(&path, Compression::Brotli, Some(5)).unwrap();
+/// assert!(compressed.to_string_lossy().ends_with(".json.br"));
+/// let mut content = String::new();
+/// brotli::Decompressor::new(std::fs::File::open(&compressed).unwrap(), 4096).read_to_string(&mut content).unwrap();
+/// assert_eq!(content, "{}");
+/// assert!(compress_file(&path, Compression::Gzip, Some(10)).is_err());
+/// ```
+///
+/// ## Errors
+/// * 압축 레벨이 압축 방식에서 지정할 수 있는 범위를 벗어난 경우
+/// * 파일을 읽거나 압축된 파일을 쓰는 과정에서 문제가 발생한 경우
+pub fn compress_file(
+    path: &Path,
+    compression: Compression,
+    level: Option<i32>,
+) -> Result<PathBuf, Box<dyn Error>> {
+    let Some(extension) = compression.extension() else {
+        return Ok(path.to_path_buf());
+    };
+    if let Some(level) = level {
+        let range = compression.level_range();
+        if !range.contains(&level) {
+            return Err(format!(
+                "압축 레벨은 {}부터 {}까지 지정할 수 있습니다.",
+                range.start(),
+                range.end()
+            )
+            .into());
+        }
+    }
+    let mut compressed_path = path.as_os_str().to_os_string();
+    compressed_path.push(".");
+    compressed_path.push(extension);
+    let compressed_path = PathBuf::from(compressed_path);
+
+    let mut reader = BufReader::new(File::open(path)?);
+    let writer = BufWriter::new(File::create(&compressed_path)?);
+    match compression {
+        Compression::None => unreachable!(),
+        Compression::Gzip => {
+            let level = level.map_or(flate2::Compression::default(), |level| {
+                flate2::Compression::new(level as u32)
+            });
+            let mut encoder = GzEncoder::new(writer, level);
+            io::copy(&mut reader, &mut encoder)?;
+            encoder.finish()?.flush()?;
+        }
+        Compression::Zstd => {
+            let level = level.unwrap_or(zstd::DEFAULT_COMPRESSION_LEVEL);
+            let mut encoder = zstd::Encoder::new(writer, level)?;
+            io::copy(&mut reader, &mut encoder)?;
+            encoder.finish()?.flush()?;
+        }
+        Compression::Brotli => {
+            let level = level.unwrap_or(BROTLI_DEFAULT_LEVEL) as u32;
+            let mut encoder = brotli::CompressorWriter::new(
+                writer,
+                BROTLI_BUFFER_SIZE,
+                level,
+                BROTLI_WINDOW_BITS,
+            );
+            io::copy(&mut reader, &mut encoder)?;
+            encoder.into_inner().flush()?;
+        }
+    }
+    fs::remove_file(path)?;
+    Ok(compressed_path)
+}
@@ -0,0 +1,76 @@
+//! # CSV 출력
+//!
+//! 행정 부서와 공유하기 위해 과목 정보 중 일부 항목만 골라 CSV 파일로 저장한다.
+use std::error::Error;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+use serde_json::Value;
+
+use crate::output::subjects;
+
+/// CSV 파일의 머리글과 각 열에 기록되는 과목 정보의 필드
+pub const CSV_COLUMNS: [(&str, &str); 6] = [
+    ("학부", "estbDpmjNm"),
+    ("학과", "estbMjorNm"),
+    ("과목명", "subjtNm"),
+    ("교수", "ltrPrfsNm"),
+    ("학점", "point"),
+    ("시간표", "timtSmryCn"),
+];
+
+/// UTF-8 BOM
+pub(crate) const UTF8_BOM: &[u8] = b"\xEF\xBB\xBF";
+
+/// JSON 값을 CSV 칸에 기록할 문자열로 바꾸는 메서드
+///
+/// 문자열은 그대로, `null`은 빈 칸으로 기록하며 그 외의 값은 JSON 문자열로 기록한다.
+pub(crate) fn to_cell(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(text) => text.clone(),
+        _ => value.to_string(),
+    }
+}
+
+/// DB의 과목 목록을 CSV 파일로 저장하는 메서드
+///
+/// ## Arguments
+///
+/// * `db` - 저장할 DB의 내용
+/// * `path` - 저장할 CSV 파일의 경로
+/// * `bom` - 파일 앞에 UTF-8 BOM을 기록할지 여부 (한국어 Excel에서 글자가 깨지지 않도록 할 때 사용)
+///
+/// ## Examples
+/// ```
+/// use serde_json::json;
+/// use suwon_mate_admin_tool::output::csv::write_csv;
+/// let db = json!({"estbLectDtaiList": {"경영학부": [
+///     {"estbDpmjNm": "경영학부", "estbMjorNm": null, "subjtNm": "회계원리, 기초", "ltrPrfsNm": "홍길동", "point": 3, "timtSmryCn": "미래520(월1,2)"}
+/// ]}});
+/// let path = std::env::temp_dir().join("suwon_mate_csv_doctest.csv");
+/// write_csv(&db, &path, true).unwrap();
+/// let content = std::fs::read_to_string(&path).unwrap();
+/// assert_eq!(content, "\u{feff}학부,학과,과목명,교수,학점,시간표\n경영학부,,\"회계원리, 기초\",홍길동,3,\"미래520(월1,2)\"\n");
+/// ```
+///
+/// ## Errors
+/// CSV 파일을 만들거나 쓰는 과정에서 문제가 발생한 경우 오류가 발생한다.
+pub fn write_csv(db: &Value, path: &Path, bom: bool) -> Result<(), Box<dyn Error>> {
+    let mut writer = BufWriter::new(File::create(path)?);
+    if bom {
+        writer.write_all(UTF8_BOM)?;
+    }
+    let mut writer = ::csv::Writer::from_writer(writer);
+    writer.write_record(CSV_COLUMNS.iter().map(|(header, _)| header))?;
+    for subject in subjects(db) {
+        writer.write_record(
+            CSV_COLUMNS
+                .iter()
+                .map(|(_, field)| to_cell(&subject[field])),
+        )?;
+    }
+    writer.flush()?;
+    Ok(())
+}
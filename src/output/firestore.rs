@@ -0,0 +1,97 @@
+//! # Firestore 출력
+//!
+//! Firestore로 가져올 수 있도록 DB를 컬렉션/문서 구조로 바꾸어 저장한다.
+//! 구조는 `node-firestore-import-export` 등의 가져오기 도구가 사용하는 `__collections__` 형식을 따른다.
+//!
+//! * `departments/{학부명}` - 학과 목록(`majors`)과 교수 연락처(`contacts`)를 가진 학부 문서
+//! * `departments/{학부명}/subjects/{과목 키}` - 과목 정보 문서
+//! * `meta/version` - 버전 정보 문서
+use std::error::Error;
+use std::path::Path;
+
+use serde_json::{json, Map, Value};
+
+use crate::output::{department_lists, subject_lists, write_json};
+use crate::subject_key;
+
+/// 하위 컬렉션을 나타내는 키
+const COLLECTIONS_KEY: &str = "__collections__";
+
+/// 문자열을 Firestore 문서 ID로 사용할 수 있도록 바꾸는 메서드
+///
+/// 문서 ID에 사용할 수 없는 `/`는 `_`로 바꾸며, 빈 문자열이나 `.`, `..`, `__이름__` 형태의 ID 앞에는 `_`를 붙인다.
+///
+/// ## Examples
+/// ```
+/// use suwon_mate_admin_tool::output::firestore::document_id;
+/// assert_eq!(document_id("경영/회계학부"), "경영_회계학부");
+/// assert_eq!(document_id(".."), "_..");
+/// assert_eq!(document_id("__name__"), "___name__");
+/// ```
+pub fn document_id(text: &str) -> String {
+    let id = text.replace('/', "_");
+    let reserved = id.is_empty()
+        || id == "."
+        || id == ".."
+        || (id.len() > 4 && id.starts_with("__") && id.ends_with("__"));
+    if reserved {
+        format!("_{}", id)
+    } else {
+        id
+    }
+}
+
+/// DB를 Firestore 가져오기 형식으로 바꾸는 메서드
+///
+/// 학부 안에 과목 키가 같은 과목이 여럿인 경우 나중에 나온 과목이 기록된다.
+///
+/// ## Examples
+/// ```
+/// use suwon_mate_admin_tool::{build_db_content, BuildOptions};
+/// use suwon_mate_admin_tool::output::firestore::to_firestore;
+/// let sample = std::fs::read_to_string("sample/sample_todo_class.json").unwrap();
+/// let options = BuildOptions { db_version: String::from("test"), quick_mode: true, ..Default::default() };
+/// let (db, _) = build_db_content(&sample, &sample, &options).unwrap();
+/// let export = to_firestore(&db);
+/// let department = &export["__collections__"]["departments"]["경영학부"];
+/// assert_eq!(department["__collections__"]["subjects"]["11416-037"]["subjtNm"], "전공진로세미나2");
+/// assert_eq!(export["__collections__"]["meta"]["version"]["db_ver"], "test");
+/// ```
+pub fn to_firestore(db: &Value) -> Value {
+    let mut departments = Map::new();
+    for (department, subjects) in subject_lists(db).into_iter().flatten() {
+        let subjects: Map<String, Value> = subjects
+            .as_array()
+            .into_iter()
+            .flatten()
+            .map(|subject| (document_id(&subject_key(subject)), subject.clone()))
+            .collect();
+        departments.insert(
+            document_id(department),
+            json!({
+                "name": department,
+                "majors": department_lists(db)
+                    .and_then(|lists| lists.get(department))
+                    .cloned()
+                    .unwrap_or_else(|| json!([])),
+                "contacts": db["contacts"].get(department).cloned().unwrap_or_else(|| json!({})),
+                COLLECTIONS_KEY: {"subjects": subjects},
+            }),
+        );
+    }
+    json!({
+        COLLECTIONS_KEY: {
+            "departments": departments,
+            "meta": {"version": db["version"]},
+        }
+    })
+}
+
+/// DB를 Firestore 가져오기 형식의 JSON 파일로 저장하는 메서드
+///
+/// ## Errors
+/// 파일을 만들거나 쓰는 과정에서 문제가 발생한 경우 오류가 발생한다.
+pub fn write_firestore(db: &Value, path: &Path) -> Result<(), Box<dyn Error>> {
+    write_json(path, &to_firestore(db))?;
+    Ok(())
+}
@@ -0,0 +1,234 @@
+//! # FlatBuffers 출력 (실험적)
+//!
+//! 앱에서 역직렬화 없이(zero-copy) DB에 접근할 수 있도록 [fbs_schema]의 `Db` 테이블을 따르는 FlatBuffers 바이너리 파일로 저장한다.
+//! FlatBuffers에는 맵 타입이 없으므로 학부별 항목은 학부명을 가진 `Department` 테이블의 배열로 기록된다.
+//! 과목 정보의 필드 이름과 순서는 [SUBJECT_FIELDS]를 따른다.
+use std::collections::BTreeSet;
+use std::error::Error;
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+
+use ::flatbuffers::{FlatBufferBuilder, TableFinishedWIPOffset, VOffsetT, WIPOffset};
+use serde_json::Value;
+
+use crate::output::{department_lists, subject_lists};
+use crate::SUBJECT_FIELDS;
+
+/// FlatBuffers 파일 식별자
+pub const FILE_IDENTIFIER: &str = "SMDB";
+
+/// 테이블의 `index`번째 필드에 해당하는 vtable 위치
+const fn field(index: usize) -> VOffsetT {
+    (4 + 2 * index) as VOffsetT
+}
+
+/// FlatBuffers 출력에 대응하는 .fbs 스키마
+///
+/// `Subject` 테이블은 [SUBJECT_FIELDS]로부터 만들어지므로 출력되는 필드의 순서와 항상 일치한다.
+///
+/// ## Examples
+/// ```
+/// use suwon_mate_admin_tool::output::flatbuffers::fbs_schema;
+/// let schema = fbs_schema();
+/// assert!(schema.contains("  subjtCd:string;\n"));
+/// assert!(schema.contains("  point:double = null;\n"));
+/// ```
+pub fn fbs_schema() -> String {
+    let subject_fields: String = SUBJECT_FIELDS
+        .iter()
+        .map(|field| {
+            if *field == "point" {
+                format!("  {}:double = null;\n", field)
+            } else {
+                format!("  {}:string;\n", field)
+            }
+        })
+        .collect();
+    FBS_SCHEMA
+        .replace("{subject_fields}", &subject_fields)
+        .replace("{file_identifier}", FILE_IDENTIFIER)
+}
+
+/// .fbs 스키마의 틀 (`{subject_fields}` 자리에 과목 정보의 필드가 들어간다)
+const FBS_SCHEMA: &str = r#"// 수원 메이트 DB 스키마 (실험적)
+namespace SuwonMate;
+
+table Subject {
+{subject_fields}}
+
+table Contact {
+  name:string;
+  email:string;
+  mpno:string;
+}
+
+table Department {
+  name:string;
+  majors:[string];
+  subjects:[Subject];
+  contacts:[Contact];
+}
+
+table Version {
+  app_ver:string;
+  db_ver:string;
+  legacy_app_ver:string;
+}
+
+table Db {
+  departments:[Department];
+  version:Version;
+  // 불완전한 DB(departments_quick, estbLectDtaiList_quick)인 경우 true
+  quick:bool;
+}
+
+root_type Db;
+file_identifier "{file_identifier}";
+"#;
+
+/// JSON 값을 문자열로 기록하는 메서드
+///
+/// 문자열은 그대로, `null`은 기록하지 않으며 그 외의 값은 JSON 문자열로 기록한다.
+fn create_text<'fbb>(
+    builder: &mut FlatBufferBuilder<'fbb>,
+    value: &Value,
+) -> Option<WIPOffset<&'fbb str>> {
+    match value {
+        Value::Null => None,
+        Value::String(text) => Some(builder.create_string(text)),
+        _ => Some(builder.create_string(&value.to_string())),
+    }
+}
+
+/// 값이 있는 문자열 필드만 기록된 테이블을 만드는 메서드
+fn create_text_table(
+    builder: &mut FlatBufferBuilder,
+    fields: &[Option<WIPOffset<&str>>],
+) -> WIPOffset<TableFinishedWIPOffset> {
+    let start = builder.start_table();
+    for (index, offset) in fields.iter().enumerate() {
+        if let Some(offset) = offset {
+            builder.push_slot_always(field(index), *offset);
+        }
+    }
+    builder.end_table(start)
+}
+
+/// 과목 정보로 `Subject` 테이블을 만드는 메서드
+fn create_subject(
+    builder: &mut FlatBufferBuilder,
+    subject: &Value,
+) -> WIPOffset<TableFinishedWIPOffset> {
+    let texts: Vec<Option<WIPOffset<&str>>> = SUBJECT_FIELDS
+        .iter()
+        .map(|name| match *name {
+            "point" => None,
+            name => create_text(builder, &subject[name]),
+        })
+        .collect();
+    let start = builder.start_table();
+    for (index, name) in SUBJECT_FIELDS.iter().enumerate() {
+        match (*name, texts[index]) {
+            ("point", _) => {
+                if let Some(point) = subject["point"].as_f64() {
+                    builder.push_slot_always(field(index), point);
+                }
+            }
+            (_, Some(offset)) => builder.push_slot_always(field(index), offset),
+            (_, None) => {}
+        }
+    }
+    builder.end_table(start)
+}
+
+/// 학부 하나의 `Department` 테이블을 만드는 메서드
+fn create_department(
+    builder: &mut FlatBufferBuilder,
+    db: &Value,
+    name: &str,
+) -> WIPOffset<TableFinishedWIPOffset> {
+    let majors: Vec<WIPOffset<&str>> = department_lists(db)
+        .and_then(|lists| lists.get(name))
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(|major| create_text(builder, major))
+        .collect();
+    let majors = builder.create_vector(&majors);
+    let subjects: Vec<WIPOffset<TableFinishedWIPOffset>> = subject_lists(db)
+        .and_then(|lists| lists.get(name))
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .map(|subject| create_subject(builder, subject))
+        .collect();
+    let subjects = builder.create_vector(&subjects);
+    let contacts: Vec<WIPOffset<TableFinishedWIPOffset>> = db["contacts"][name]
+        .as_object()
+        .into_iter()
+        .flatten()
+        .map(|(professor, contact)| {
+            let fields = [
+                Some(builder.create_string(professor)),
+                create_text(builder, &contact["email"]),
+                create_text(builder, &contact["mpno"]),
+            ];
+            create_text_table(builder, &fields)
+        })
+        .collect();
+    let contacts = builder.create_vector(&contacts);
+    let name = builder.create_string(name);
+    let start = builder.start_table();
+    builder.push_slot_always(field(0), name);
+    builder.push_slot_always(field(1), majors);
+    builder.push_slot_always(field(2), subjects);
+    builder.push_slot_always(field(3), contacts);
+    builder.end_table(start)
+}
+
+/// JSON 형태의 DB를 FlatBuffers 바이너리로 바꾸는 메서드
+///
+/// ## Examples
+/// ```
+/// use suwon_mate_admin_tool::{build_db_content, BuildOptions};
+/// use suwon_mate_admin_tool::output::flatbuffers::{to_flatbuffer, FILE_IDENTIFIER};
+/// let sample = std::fs::read_to_string("sample/sample_todo_class.json").unwrap();
+/// let options = BuildOptions { db_version: String::from("test"), quick_mode: true, ..Default::default() };
+/// let (db, _) = build_db_content(&sample, &sample, &options).unwrap();
+/// let buffer = to_flatbuffer(&db);
+/// assert!(flatbuffers::buffer_has_identifier(&buffer, FILE_IDENTIFIER, false));
+/// ```
+pub fn to_flatbuffer(db: &Value) -> Vec<u8> {
+    let mut builder = FlatBufferBuilder::new();
+    let names: BTreeSet<&String> = subject_lists(db)
+        .into_iter()
+        .chain(department_lists(db))
+        .chain(db["contacts"].as_object())
+        .flat_map(|lists| lists.keys())
+        .collect();
+    let departments: Vec<WIPOffset<TableFinishedWIPOffset>> = names
+        .into_iter()
+        .map(|name| create_department(&mut builder, db, name))
+        .collect();
+    let departments = builder.create_vector(&departments);
+    let version_fields = ["app_ver", "db_ver", "legacy_app_ver"]
+        .map(|name| create_text(&mut builder, &db["version"][name]));
+    let version = create_text_table(&mut builder, &version_fields);
+    let start = builder.start_table();
+    builder.push_slot_always(field(0), departments);
+    builder.push_slot_always(field(1), version);
+    builder.push_slot_always(field(2), db.get("estbLectDtaiList").is_none());
+    let root = builder.end_table(start);
+    builder.finish(root, Some(FILE_IDENTIFIER));
+    builder.finished_data().to_vec()
+}
+
+/// DB를 FlatBuffers 바이너리 파일로 저장하는 메서드
+///
+/// ## Errors
+/// 파일을 만들거나 쓰는 과정에서 문제가 발생한 경우 오류가 발생한다.
+pub fn write_flatbuffers(db: &Value, path: &Path) -> Result<(), Box<dyn Error>> {
+    File::create(path)?.write_all(&to_flatbuffer(db))?;
+    Ok(())
+}
@@ -0,0 +1,270 @@
+//! # 출력 형식
+//!
+//! 제작된 DB를 여러 형식의 파일로 저장하는 출력 백엔드를 모아둔 모듈이다.
+//! 모든 백엔드는 [crate::make_db_content]가 만든 `Value` 구조를 입력으로 사용한다.
+use std::error::Error;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+use clap::ValueEnum;
+use serde_json::{Map, Value};
+
+use crate::canonical::canonical_json;
+
+pub mod bson;
+pub mod columnar;
+pub mod compress;
+pub mod csv;
+pub mod firestore;
+pub mod flatbuffers;
+pub mod parquet;
+pub mod protobuf;
+pub mod sql;
+pub mod sqlite;
+pub mod xlsx;
+
+/// DB 파일의 출력 형식
+#[derive(Copy, Clone, PartialEq, Eq, Debug, ValueEnum)]
+pub enum OutputFormat {
+    /// JSON 파일
+    Json,
+    /// departments, subjects, contacts, version 테이블을 가진 SQLite 파일
+    Sqlite,
+    /// JSON과 동일한 구조의 MessagePack 바이너리 파일
+    Msgpack,
+    /// JSON과 동일한 구조의 CBOR 바이너리 파일
+    Cbor,
+    /// 과목 하나당 한 줄의 JSON으로 기록한 NDJSON 파일 (과목 정보만 포함)
+    Ndjson,
+    /// 학부, 학과, 과목명, 교수, 학점, 시간표 열을 가진 CSV 파일 (과목 정보만 포함)
+    Csv,
+    /// 학부별 시트에 CSV와 같은 열을 기록한 엑셀 파일
+    Xlsx,
+    /// 과목 하나를 한 행으로 기록한 Parquet 파일 (과목 정보만 포함)
+    Parquet,
+    /// `generate-docs --format proto`로 만든 .proto 스키마의 `Db` 메세지를 따르는 Protobuf 바이너리 파일
+    Protobuf,
+    /// `generate-docs --format fbs`로 만든 .fbs 스키마의 `Db` 테이블을 따르는 FlatBuffers 바이너리 파일 (실험적)
+    Flatbuffers,
+    /// departments 컬렉션과 subjects 하위 컬렉션으로 구성된 Firestore 가져오기용 JSON 파일
+    Firestore,
+    /// SQLite 출력과 같은 테이블을 만드는 CREATE TABLE, INSERT 문으로 이루어진 SQL 덤프 파일
+    Sql,
+    /// MongoDB 적재용 BSON 파일 (`--bson-split`으로 도큐먼트 분할 기준 지정)
+    Bson,
+    /// 과목 정보를 필드명 목록과 고정된 순서의 배열로 기록한 열 지향 컴팩트 JSON 파일
+    Columnar,
+}
+
+impl OutputFormat {
+    /// 출력 형식에 해당하는 파일 확장자
+    ///
+    /// ## Examples
+    /// ```
+    /// use suwon_mate_admin_tool::output::OutputFormat;
+    /// assert_eq!(OutputFormat::Sqlite.extension(), "sqlite");
+    /// ```
+    pub fn extension(&self) -> &'static str {
+        match self {
+            OutputFormat::Json => "json",
+            OutputFormat::Sqlite => "sqlite",
+            OutputFormat::Msgpack => "msgpack",
+            OutputFormat::Cbor => "cbor",
+            OutputFormat::Ndjson => "ndjson",
+            OutputFormat::Csv => "csv",
+            OutputFormat::Xlsx => "xlsx",
+            OutputFormat::Parquet => "parquet",
+            OutputFormat::Protobuf => "pb",
+            OutputFormat::Flatbuffers => "bin",
+            OutputFormat::Firestore => "firestore.json",
+            OutputFormat::Sql => "sql",
+            OutputFormat::Bson => "bson",
+            OutputFormat::Columnar => "columnar.json",
+        }
+    }
+}
+
+/// 출력 형식별 세부 설정
+///
+/// 해당 설정을 사용하지 않는 출력 형식에서는 무시된다.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+pub struct OutputOptions {
+    /// CSV 파일 앞에 UTF-8 BOM을 기록할지 여부
+    pub csv_bom: bool,
+    /// SQL 덤프의 대상 데이터베이스
+    pub sql_dialect: sql::SqlDialect,
+    /// BSON 도큐먼트를 나누는 기준
+    pub bson_split: bson::BsonSplit,
+    /// JSON 파일을 RFC 8785 canonical JSON으로 기록할지 여부
+    pub canonical: bool,
+}
+
+/// DB를 `format` 형식으로 `path`에 저장하는 메서드
+///
+/// ## Arguments
+///
+/// * `db` - 저장할 DB의 내용
+/// * `format` - 출력 형식
+/// * `path` - 저장할 파일의 경로
+/// * `options` - 출력 형식별 세부 설정
+///
+/// ## Examples
+/// ```
+/// use serde_json::{json, Value};
+/// use suwon_mate_admin_tool::output::{write_db, OutputFormat, OutputOptions};
+/// let db = json!({"version": {"db_ver": "1.0"}});
+/// let path = std::env::temp_dir().join("suwon_mate_write_db_doctest.msgpack");
+/// write_db(&db, OutputFormat::Msgpack, &path, &OutputOptions::default()).unwrap();
+/// let decoded: Value = rmp_serde::from_slice(&std::fs::read(&path).unwrap()).unwrap();
+/// assert_eq!(decoded, db);
+///
+/// let path = std::env::temp_dir().join("suwon_mate_write_db_doctest.cbor");
+/// write_db(&db, OutputFormat::Cbor, &path, &OutputOptions::default()).unwrap();
+/// let decoded: Value = ciborium::from_reader(std::fs::File::open(&path).unwrap()).unwrap();
+/// assert_eq!(decoded, db);
+///
+/// let db = json!({"estbLectDtaiList": {"경영학부": [{"subjtCd": "1"}, {"subjtCd": "2"}]}});
+/// let path = std::env::temp_dir().join("suwon_mate_write_db_doctest.ndjson");
+/// write_db(&db, OutputFormat::Ndjson, &path, &OutputOptions::default()).unwrap();
+/// assert_eq!(std::fs::read_to_string(&path).unwrap(), "{\"subjtCd\":\"1\"}\n{\"subjtCd\":\"2\"}\n");
+/// ```
+///
+/// ## Errors
+/// * 파일 생성 또는 쓰기에 실패한 경우
+/// * 출력 백엔드에서 DB를 변환하지 못한 경우
+pub fn write_db(
+    db: &Value,
+    format: OutputFormat,
+    path: &Path,
+    options: &OutputOptions,
+) -> Result<(), Box<dyn Error>> {
+    match format {
+        OutputFormat::Json if options.canonical => {
+            File::create(path)?.write_all(canonical_json(db).as_bytes())?
+        }
+        OutputFormat::Json => write_json(path, db)?,
+        OutputFormat::Sqlite => sqlite::write_sqlite(db, path)?,
+        OutputFormat::Msgpack => {
+            let mut writer = BufWriter::new(File::create(path)?);
+            rmp_serde::encode::write(&mut writer, db)?;
+            writer.flush()?;
+        }
+        OutputFormat::Cbor => {
+            let mut writer = BufWriter::new(File::create(path)?);
+            ciborium::into_writer(db, &mut writer)?;
+            writer.flush()?;
+        }
+        OutputFormat::Ndjson => {
+            let mut writer = BufWriter::new(File::create(path)?);
+            for subject in subjects(db) {
+                serde_json::to_writer(&mut writer, subject)?;
+                writer.write_all(b"\n")?;
+            }
+            writer.flush()?;
+        }
+        OutputFormat::Csv => csv::write_csv(db, path, options.csv_bom)?,
+        OutputFormat::Xlsx => xlsx::write_xlsx(db, path)?,
+        OutputFormat::Parquet => parquet::write_parquet(db, path)?,
+        OutputFormat::Protobuf => protobuf::write_protobuf(db, path)?,
+        OutputFormat::Flatbuffers => flatbuffers::write_flatbuffers(db, path)?,
+        OutputFormat::Firestore => firestore::write_firestore(db, path)?,
+        OutputFormat::Sql => sql::write_sql(db, path, options.sql_dialect)?,
+        OutputFormat::Bson => bson::write_bson(db, path, options.bson_split)?,
+        OutputFormat::Columnar => columnar::write_columnar(db, path)?,
+    }
+    Ok(())
+}
+
+/// JSON 값을 파일로 저장하는 메서드
+///
+/// 값 전체를 문자열로 만들지 않고 버퍼를 거쳐 파일에 바로 직렬화하므로, 큰 DB를 저장할 때 메모리 사용량이 줄어든다.
+///
+/// ## Examples
+/// ```
+/// use serde_json::json;
+/// use suwon_mate_admin_tool::output::write_json;
+/// let path = std::env::temp_dir().join("suwon_mate_write_json_doctest.json");
+/// write_json(&path, &json!({"version": {"db_ver": "1.0"}})).unwrap();
+/// assert_eq!(std::fs::read_to_string(&path).unwrap(), r#"{"version":{"db_ver":"1.0"}}"#);
+/// ```
+///
+/// ## Errors
+/// 파일을 만들거나 기록할 수 없는 경우 오류가 발생한다.
+pub fn write_json(path: &Path, value: &Value) -> Result<(), Box<dyn Error>> {
+    let mut writer = BufWriter::new(File::create(path)?);
+    serde_json::to_writer(&mut writer, value)?;
+    writer.flush()?;
+    Ok(())
+}
+
+/// DB에서 학부별 과목 목록을 가져오는 메서드
+///
+/// 불완전한 DB의 경우 `estbLectDtaiList_quick` 키에서 목록을 가져온다.
+///
+/// ## Examples
+/// ```
+/// use serde_json::json;
+/// use suwon_mate_admin_tool::output::subject_lists;
+/// let db = json!({"estbLectDtaiList_quick": {"경영학부": []}});
+/// assert!(subject_lists(&db).unwrap().contains_key("경영학부"));
+/// ```
+pub fn subject_lists(db: &Value) -> Option<&Map<String, Value>> {
+    db.get("estbLectDtaiList")
+        .or_else(|| db.get("estbLectDtaiList_quick"))
+        .and_then(Value::as_object)
+}
+
+/// DB에서 학부별 학과 목록을 가져오는 메서드
+///
+/// 불완전한 DB의 경우 `departments_quick` 키에서 목록을 가져온다.
+pub fn department_lists(db: &Value) -> Option<&Map<String, Value>> {
+    db.get("departments")
+        .or_else(|| db.get("departments_quick"))
+        .and_then(Value::as_object)
+}
+
+/// DB에 기록된 모든 과목을 순회하는 메서드
+///
+/// ## Examples
+/// ```
+/// use serde_json::json;
+/// use suwon_mate_admin_tool::output::subjects;
+/// let db = json!({"estbLectDtaiList": {"경영학부": [{"subjtCd": "1"}], "컴퓨터학부": [{"subjtCd": "2"}]}});
+/// assert_eq!(subjects(&db).count(), 2);
+/// ```
+pub fn subjects(db: &Value) -> impl Iterator<Item = &Value> {
+    subject_lists(db)
+        .into_iter()
+        .flat_map(|lists| lists.values())
+        .filter_map(Value::as_array)
+        .flatten()
+}
+
+/// DB에 기록된 모든 과목을 수정할 수 있도록 순회하는 메서드
+///
+/// 과목 목록을 찾는 기준은 [subject_lists]와 같다.
+///
+/// ## Examples
+/// ```
+/// use serde_json::json;
+/// use suwon_mate_admin_tool::output::subjects_mut;
+/// let mut db = json!({"estbLectDtaiList_quick": {"경영학부": [{"subjtCd": "1"}]}});
+/// for subject in subjects_mut(&mut db) {
+///     subject["point"] = json!(3);
+/// }
+/// assert_eq!(db["estbLectDtaiList_quick"]["경영학부"][0]["point"], 3);
+/// ```
+pub fn subjects_mut(db: &mut Value) -> impl Iterator<Item = &mut Value> {
+    let key = if db.get("estbLectDtaiList").is_some() {
+        "estbLectDtaiList"
+    } else {
+        "estbLectDtaiList_quick"
+    };
+    db.get_mut(key)
+        .and_then(Value::as_object_mut)
+        .into_iter()
+        .flat_map(|lists| lists.values_mut())
+        .filter_map(Value::as_array_mut)
+        .flatten()
+}
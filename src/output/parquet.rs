@@ -0,0 +1,113 @@
+//! # Parquet 출력
+//!
+//! 데이터 분석 파이프라인에 적재할 수 있도록 과목 목록을 Parquet 파일로 저장한다.
+//! 과목 하나가 한 행이 되며, 열의 구성은 [subject_schema]의 arrow 스키마를 따른다.
+use std::error::Error;
+use std::fs::File;
+use std::path::Path;
+use std::sync::Arc;
+
+use ::parquet::arrow::ArrowWriter;
+use arrow_array::{ArrayRef, Float64Array, RecordBatch, StringArray};
+use arrow_schema::{DataType, Field, Schema};
+use serde_json::Value;
+
+use crate::output::subjects;
+use crate::SUBJECT_FIELDS;
+
+/// 과목 정보의 필드에 대응하는 arrow 타입
+///
+/// 학점(`point`)은 `Float64`, 나머지 필드는 모두 `Utf8`로 기록한다.
+///
+/// ## Examples
+/// ```
+/// use arrow_schema::DataType;
+/// use suwon_mate_admin_tool::output::parquet::data_type;
+/// assert_eq!(data_type("point"), DataType::Float64);
+/// assert_eq!(data_type("subjtNm"), DataType::Utf8);
+/// ```
+pub fn data_type(field: &str) -> DataType {
+    match field {
+        "point" => DataType::Float64,
+        _ => DataType::Utf8,
+    }
+}
+
+/// 과목 목록 Parquet 파일의 arrow 스키마
+///
+/// 열의 순서는 [SUBJECT_FIELDS]와 같으며 모든 열은 `null`을 허용한다.
+pub fn subject_schema() -> Schema {
+    Schema::new(
+        SUBJECT_FIELDS
+            .iter()
+            .map(|field| Field::new(*field, data_type(field), true))
+            .collect::<Vec<_>>(),
+    )
+}
+
+/// JSON 값을 `Float64` 열의 값으로 바꾸는 메서드
+///
+/// 숫자로 해석할 수 있는 문자열도 숫자로 기록하며, 그 외의 값은 `null`로 기록한다.
+fn to_number(value: &Value) -> Option<f64> {
+    match value {
+        Value::Number(number) => number.as_f64(),
+        Value::String(text) => text.trim().parse().ok(),
+        _ => None,
+    }
+}
+
+/// JSON 값을 `Utf8` 열의 값으로 바꾸는 메서드
+///
+/// 문자열은 그대로, `null`은 `null`로 기록하며 그 외의 값은 JSON 문자열로 기록한다.
+fn to_text(value: &Value) -> Option<String> {
+    match value {
+        Value::Null => None,
+        Value::String(text) => Some(text.clone()),
+        _ => Some(value.to_string()),
+    }
+}
+
+/// DB의 과목 목록을 Parquet 파일로 저장하는 메서드
+///
+/// ## Arguments
+///
+/// * `db` - 저장할 DB의 내용
+/// * `path` - 저장할 Parquet 파일의 경로
+///
+/// ## Examples
+/// ```
+/// use parquet::file::reader::{FileReader, SerializedFileReader};
+/// use suwon_mate_admin_tool::{build_db_content, BuildOptions};
+/// use suwon_mate_admin_tool::output::parquet::write_parquet;
+/// let sample = std::fs::read_to_string("sample/sample_todo_class.json").unwrap();
+/// let options = BuildOptions { db_version: String::from("test"), quick_mode: true, ..Default::default() };
+/// let (db, _) = build_db_content(&sample, &sample, &options).unwrap();
+/// let path = std::env::temp_dir().join("suwon_mate_parquet_doctest.parquet");
+/// write_parquet(&db, &path).unwrap();
+/// let reader = SerializedFileReader::new(std::fs::File::open(&path).unwrap()).unwrap();
+/// assert_eq!(reader.metadata().file_metadata().num_rows(), 5);
+/// ```
+///
+/// ## Errors
+/// Parquet 파일을 만들거나 쓰는 과정에서 문제가 발생한 경우 오류가 발생한다.
+pub fn write_parquet(db: &Value, path: &Path) -> Result<(), Box<dyn Error>> {
+    let subjects: Vec<&Value> = subjects(db).collect();
+    let columns: Vec<ArrayRef> = SUBJECT_FIELDS
+        .iter()
+        .map(|field| -> ArrayRef {
+            match data_type(field) {
+                DataType::Float64 => Arc::new(Float64Array::from_iter(
+                    subjects.iter().map(|subject| to_number(&subject[field])),
+                )),
+                _ => Arc::new(StringArray::from_iter(
+                    subjects.iter().map(|subject| to_text(&subject[field])),
+                )),
+            }
+        })
+        .collect();
+    let batch = RecordBatch::try_new(Arc::new(subject_schema()), columns)?;
+    let mut writer = ArrowWriter::try_new(File::create(path)?, batch.schema(), None)?;
+    writer.write(&batch)?;
+    writer.close()?;
+    Ok(())
+}
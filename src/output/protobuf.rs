@@ -0,0 +1,302 @@
+//! # Protobuf 출력
+//!
+//! DB를 [PROTO_SCHEMA]의 `Db` 메세지를 따르는 Protobuf 바이너리 파일로 저장한다.
+//! 과목 정보의 필드 이름은 JSON DB와 같으며, 필드 번호는 [SUBJECT_FIELDS]의 순서를 따른다.
+use std::collections::HashMap;
+use std::error::Error;
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+
+use prost::Message;
+use serde_json::Value;
+
+use crate::output::{department_lists, subject_lists};
+use crate::SUBJECT_FIELDS;
+
+/// 학과 목록
+#[derive(Clone, PartialEq, Message)]
+pub struct Majors {
+    #[prost(string, repeated, tag = "1")]
+    pub names: Vec<String>,
+}
+
+/// 과목 정보
+#[derive(Clone, PartialEq, Message)]
+pub struct Subject {
+    #[prost(string, optional, tag = "1")]
+    pub department: Option<String>,
+    #[prost(string, optional, tag = "2")]
+    pub major: Option<String>,
+    #[prost(string, optional, tag = "3")]
+    pub target_grade_code: Option<String>,
+    #[prost(string, optional, tag = "4")]
+    pub subject_name: Option<String>,
+    #[prost(string, optional, tag = "5")]
+    pub professor: Option<String>,
+    #[prost(string, optional, tag = "6")]
+    pub department_name: Option<String>,
+    #[prost(string, optional, tag = "7")]
+    pub division: Option<String>,
+    #[prost(string, optional, tag = "8")]
+    pub timetable: Option<String>,
+    #[prost(string, optional, tag = "9")]
+    pub language: Option<String>,
+    #[prost(string, optional, tag = "10")]
+    pub subject_code: Option<String>,
+    #[prost(string, optional, tag = "11")]
+    pub dicl_number: Option<String>,
+    #[prost(string, optional, tag = "12")]
+    pub year: Option<String>,
+    #[prost(double, optional, tag = "13")]
+    pub point: Option<f64>,
+    #[prost(string, optional, tag = "14")]
+    pub culture_area: Option<String>,
+    #[prost(string, optional, tag = "15")]
+    pub sex: Option<String>,
+    #[prost(string, optional, tag = "16")]
+    pub status: Option<String>,
+    #[prost(string, optional, tag = "17")]
+    pub classification: Option<String>,
+    #[prost(string, optional, tag = "18")]
+    pub approval_type: Option<String>,
+}
+
+/// 학부 하나의 과목 목록
+#[derive(Clone, PartialEq, Message)]
+pub struct SubjectList {
+    #[prost(message, repeated, tag = "1")]
+    pub subjects: Vec<Subject>,
+}
+
+/// 교수 연락처
+#[derive(Clone, PartialEq, Message)]
+pub struct Contact {
+    #[prost(string, optional, tag = "1")]
+    pub email: Option<String>,
+    #[prost(string, optional, tag = "2")]
+    pub mpno: Option<String>,
+}
+
+/// 학부 하나의 교수명별 연락처
+#[derive(Clone, PartialEq, Message)]
+pub struct ContactBook {
+    #[prost(map = "string, message", tag = "1")]
+    pub contacts: HashMap<String, Contact>,
+}
+
+/// 버전 정보
+#[derive(Clone, PartialEq, Message)]
+pub struct Version {
+    #[prost(string, tag = "1")]
+    pub app_ver: String,
+    #[prost(string, tag = "2")]
+    pub db_ver: String,
+    #[prost(string, tag = "3")]
+    pub legacy_app_ver: String,
+}
+
+/// DB 전체
+#[derive(Clone, PartialEq, Message)]
+pub struct Db {
+    #[prost(map = "string, message", tag = "1")]
+    pub departments: HashMap<String, Majors>,
+    #[prost(map = "string, message", tag = "2")]
+    pub subjects: HashMap<String, SubjectList>,
+    #[prost(map = "string, message", tag = "3")]
+    pub contacts: HashMap<String, ContactBook>,
+    #[prost(message, optional, tag = "4")]
+    pub version: Option<Version>,
+    #[prost(bool, tag = "5")]
+    pub quick: bool,
+}
+
+/// [Db] 메세지에 대응하는 .proto 스키마
+///
+/// `Subject` 메세지는 [SUBJECT_FIELDS]로부터 만들어지므로 [Subject] 구조체의 필드 번호와 항상 일치한다.
+///
+/// ## Examples
+/// ```
+/// use suwon_mate_admin_tool::output::protobuf::proto_schema;
+/// let schema = proto_schema();
+/// assert!(schema.contains("optional string subjtCd = 10;"));
+/// assert!(schema.contains("optional double point = 13;"));
+/// ```
+pub fn proto_schema() -> String {
+    let subject_fields: String = SUBJECT_FIELDS
+        .iter()
+        .enumerate()
+        .map(|(index, field)| {
+            let kind = if *field == "point" {
+                "double"
+            } else {
+                "string"
+            };
+            format!("  optional {} {} = {};\n", kind, field, index + 1)
+        })
+        .collect();
+    PROTO_SCHEMA.replace("{subject_fields}", &subject_fields)
+}
+
+/// .proto 스키마의 틀 (`{subject_fields}` 자리에 과목 정보의 필드가 들어간다)
+const PROTO_SCHEMA: &str = r#"// 수원 메이트 DB 스키마
+syntax = "proto3";
+
+package suwon_mate;
+
+message Majors {
+  repeated string names = 1;
+}
+
+message Subject {
+{subject_fields}}
+
+message SubjectList {
+  repeated Subject subjects = 1;
+}
+
+message Contact {
+  optional string email = 1;
+  optional string mpno = 2;
+}
+
+message ContactBook {
+  map<string, Contact> contacts = 1;
+}
+
+message Version {
+  string app_ver = 1;
+  string db_ver = 2;
+  string legacy_app_ver = 3;
+}
+
+message Db {
+  map<string, Majors> departments = 1;
+  map<string, SubjectList> subjects = 2;
+  map<string, ContactBook> contacts = 3;
+  Version version = 4;
+  // 불완전한 DB(departments_quick, estbLectDtaiList_quick)인 경우 true
+  bool quick = 5;
+}
+"#;
+
+/// JSON 값을 문자열 필드의 값으로 바꾸는 메서드
+fn to_text(value: &Value) -> Option<String> {
+    match value {
+        Value::Null => None,
+        Value::String(text) => Some(text.clone()),
+        _ => Some(value.to_string()),
+    }
+}
+
+/// JSON 형태의 과목 정보를 [Subject] 메세지로 바꾸는 메서드
+fn to_subject(subject: &Value) -> Subject {
+    let text = |field: &str| to_text(&subject[field]);
+    Subject {
+        department: text("estbDpmjNm"),
+        major: text("estbMjorNm"),
+        target_grade_code: text("trgtGrdeCd"),
+        subject_name: text("subjtNm"),
+        professor: text("ltrPrfsNm"),
+        department_name: text("deptNm"),
+        division: text("facDvnm"),
+        timetable: text("timtSmryCn"),
+        language: text("lssnLangNm"),
+        subject_code: text("subjtCd"),
+        dicl_number: text("diclNo"),
+        year: text("subjtEstbYear"),
+        point: subject["point"].as_f64(),
+        culture_area: text("cltTerrNm"),
+        sex: text("sexCdNm"),
+        status: text("hffcStatNm"),
+        classification: text("clsfNm"),
+        approval_type: text("capprTypeNm"),
+    }
+}
+
+/// JSON 형태의 DB를 [Db] 메세지로 바꾸는 메서드
+///
+/// ## Examples
+/// ```
+/// use suwon_mate_admin_tool::{build_db_content, BuildOptions};
+/// use suwon_mate_admin_tool::output::protobuf::to_message;
+/// let sample = std::fs::read_to_string("sample/sample_todo_class.json").unwrap();
+/// let options = BuildOptions { db_version: String::from("test"), quick_mode: true, ..Default::default() };
+/// let (db, _) = build_db_content(&sample, &sample, &options).unwrap();
+/// let message = to_message(&db);
+/// assert!(message.quick);
+/// assert_eq!(message.subjects["경영학부"].subjects.len(), 5);
+/// assert_eq!(message.version.unwrap().db_ver, "test");
+/// ```
+pub fn to_message(db: &Value) -> Db {
+    let departments = department_lists(db)
+        .into_iter()
+        .flatten()
+        .map(|(department, majors)| {
+            let names = majors
+                .as_array()
+                .into_iter()
+                .flatten()
+                .filter_map(to_text)
+                .collect();
+            (department.clone(), Majors { names })
+        })
+        .collect();
+    let subjects = subject_lists(db)
+        .into_iter()
+        .flatten()
+        .map(|(department, subjects)| {
+            let subjects = subjects
+                .as_array()
+                .into_iter()
+                .flatten()
+                .map(to_subject)
+                .collect();
+            (department.clone(), SubjectList { subjects })
+        })
+        .collect();
+    let contacts = db["contacts"]
+        .as_object()
+        .into_iter()
+        .flatten()
+        .map(|(department, contacts)| {
+            let contacts = contacts
+                .as_object()
+                .into_iter()
+                .flatten()
+                .map(|(name, contact)| {
+                    let contact = Contact {
+                        email: to_text(&contact["email"]),
+                        mpno: to_text(&contact["mpno"]),
+                    };
+                    (name.clone(), contact)
+                })
+                .collect();
+            (department.clone(), ContactBook { contacts })
+        })
+        .collect();
+    let version = &db["version"];
+    Db {
+        departments,
+        subjects,
+        contacts,
+        version: Some(Version {
+            app_ver: version["app_ver"].as_str().unwrap_or_default().to_string(),
+            db_ver: version["db_ver"].as_str().unwrap_or_default().to_string(),
+            legacy_app_ver: version["legacy_app_ver"]
+                .as_str()
+                .unwrap_or_default()
+                .to_string(),
+        }),
+        quick: db.get("estbLectDtaiList").is_none(),
+    }
+}
+
+/// DB를 Protobuf 바이너리 파일로 저장하는 메서드
+///
+/// ## Errors
+/// 파일을 만들거나 쓰는 과정에서 문제가 발생한 경우 오류가 발생한다.
+pub fn write_protobuf(db: &Value, path: &Path) -> Result<(), Box<dyn Error>> {
+    File::create(path)?.write_all(&to_message(db).encode_to_vec())?;
+    Ok(())
+}
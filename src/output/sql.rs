@@ -0,0 +1,207 @@
+//! # SQL 덤프 출력
+//!
+//! 다른 RDB 기반 서비스에 바로 적재할 수 있도록 DB를 `CREATE TABLE`과 `INSERT` 문으로 이루어진 SQL 덤프 파일로 저장한다.
+//! 테이블 구성은 SQLite 출력과 같으며, 식별자와 문자열의 표기는 [SqlDialect]에 따라 달라진다.
+use std::error::Error;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+use clap::ValueEnum;
+use serde_json::Value;
+
+use crate::output::{department_lists, subject_lists};
+use crate::SUBJECT_FIELDS;
+
+/// SQL 덤프의 대상 데이터베이스
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default, ValueEnum)]
+pub enum SqlDialect {
+    /// PostgreSQL (식별자를 큰따옴표로 감쌈)
+    #[default]
+    Postgres,
+    /// MySQL, MariaDB (식별자를 백틱으로 감싸고 역슬래시를 이스케이프함)
+    Mysql,
+}
+
+impl SqlDialect {
+    /// 식별자를 감싸는 메서드
+    ///
+    /// ## Examples
+    /// ```
+    /// use suwon_mate_admin_tool::output::sql::SqlDialect;
+    /// assert_eq!(SqlDialect::Postgres.identifier("subjtNm"), "\"subjtNm\"");
+    /// assert_eq!(SqlDialect::Mysql.identifier("subjtNm"), "`subjtNm`");
+    /// ```
+    pub fn identifier(&self, name: &str) -> String {
+        match self {
+            SqlDialect::Postgres => format!("\"{}\"", name.replace('"', "\"\"")),
+            SqlDialect::Mysql => format!("`{}`", name.replace('`', "``")),
+        }
+    }
+
+    /// JSON 값을 SQL 리터럴로 바꾸는 메서드
+    ///
+    /// `null`은 `NULL`, 숫자는 그대로, 논리값은 `1`과 `0`으로 기록하며 그 외의 값은 문자열 리터럴로 기록한다.
+    ///
+    /// ## Examples
+    /// ```
+    /// use serde_json::json;
+    /// use suwon_mate_admin_tool::output::sql::SqlDialect;
+    /// assert_eq!(SqlDialect::Postgres.literal(&json!("O'Neil\\")), "'O''Neil\\'");
+    /// assert_eq!(SqlDialect::Mysql.literal(&json!("O'Neil\\")), "'O''Neil\\\\'");
+    /// assert_eq!(SqlDialect::Mysql.literal(&json!(null)), "NULL");
+    /// assert_eq!(SqlDialect::Mysql.literal(&json!(3)), "3");
+    /// ```
+    pub fn literal(&self, value: &Value) -> String {
+        let text = match value {
+            Value::Null => return String::from("NULL"),
+            Value::Bool(value) => return String::from(if *value { "1" } else { "0" }),
+            Value::Number(number) => return number.to_string(),
+            Value::String(text) => text.clone(),
+            _ => value.to_string(),
+        };
+        let text = match self {
+            SqlDialect::Postgres => text.replace('\'', "''"),
+            SqlDialect::Mysql => text.replace('\\', "\\\\").replace('\'', "''"),
+        };
+        format!("'{}'", text)
+    }
+}
+
+/// DB를 SQL 덤프 파일로 저장하는 메서드
+///
+/// 덤프는 하나의 트랜잭션으로 묶이며, 같은 이름의 테이블이 이미 존재하는 경우 지우고 새로 만든다.
+///
+/// ## Arguments
+///
+/// * `db` - 저장할 DB의 내용
+/// * `path` - 저장할 SQL 파일의 경로
+/// * `dialect` - SQL 덤프의 대상 데이터베이스
+///
+/// ## Examples
+/// ```
+/// use suwon_mate_admin_tool::{build_db_content, BuildOptions};
+/// use suwon_mate_admin_tool::output::sql::{write_sql, SqlDialect};
+/// let sample = std::fs::read_to_string("sample/sample_todo_class.json").unwrap();
+/// let options = BuildOptions { db_version: String::from("test"), quick_mode: true, ..Default::default() };
+/// let (db, _) = build_db_content(&sample, &sample, &options).unwrap();
+/// let path = std::env::temp_dir().join("suwon_mate_sql_doctest.sql");
+/// write_sql(&db, &path, SqlDialect::Mysql).unwrap();
+/// let dump = std::fs::read_to_string(&path).unwrap();
+/// assert_eq!(dump.matches("INSERT INTO `subjects`").count(), 5);
+/// assert!(dump.ends_with("COMMIT;\n"));
+/// ```
+///
+/// ## Errors
+/// 파일을 만들거나 쓰는 과정에서 문제가 발생한 경우 오류가 발생한다.
+pub fn write_sql(db: &Value, path: &Path, dialect: SqlDialect) -> Result<(), Box<dyn Error>> {
+    let mut writer = BufWriter::new(File::create(path)?);
+    let id = |name: &str| dialect.identifier(name);
+    let subject_columns = SUBJECT_FIELDS
+        .iter()
+        .map(|field| id(field))
+        .collect::<Vec<_>>();
+    let subject_definitions = SUBJECT_FIELDS
+        .iter()
+        .map(|field| match *field {
+            "point" => format!("{} DOUBLE PRECISION", id(field)),
+            _ => format!("{} TEXT", id(field)),
+        })
+        .collect::<Vec<_>>();
+    writeln!(writer, "BEGIN;")?;
+    for table in ["departments", "subjects", "contacts", "version"] {
+        writeln!(writer, "DROP TABLE IF EXISTS {};", id(table))?;
+    }
+    writeln!(
+        writer,
+        "CREATE TABLE {} ({} TEXT NOT NULL, {} TEXT NOT NULL);",
+        id("departments"),
+        id("department"),
+        id("major")
+    )?;
+    writeln!(
+        writer,
+        "CREATE TABLE {} ({});",
+        id("subjects"),
+        subject_definitions.join(", ")
+    )?;
+    writeln!(
+        writer,
+        "CREATE TABLE {} ({} TEXT NOT NULL, {} TEXT NOT NULL, {} TEXT, {} TEXT);",
+        id("contacts"),
+        id("department"),
+        id("name"),
+        id("email"),
+        id("mpno")
+    )?;
+    writeln!(
+        writer,
+        "CREATE TABLE {} ({} VARCHAR(255) PRIMARY KEY, {} TEXT);",
+        id("version"),
+        id("key"),
+        id("value")
+    )?;
+
+    for (department, majors) in department_lists(db).into_iter().flatten() {
+        for major in majors.as_array().into_iter().flatten() {
+            writeln!(
+                writer,
+                "INSERT INTO {} ({}, {}) VALUES ({}, {});",
+                id("departments"),
+                id("department"),
+                id("major"),
+                dialect.literal(&Value::String(department.clone())),
+                dialect.literal(major)
+            )?;
+        }
+    }
+    for subject in subject_lists(db)
+        .into_iter()
+        .flat_map(|lists| lists.values())
+        .filter_map(Value::as_array)
+        .flatten()
+    {
+        let values = SUBJECT_FIELDS
+            .iter()
+            .map(|field| dialect.literal(&subject[*field]))
+            .collect::<Vec<_>>();
+        writeln!(
+            writer,
+            "INSERT INTO {} ({}) VALUES ({});",
+            id("subjects"),
+            subject_columns.join(", "),
+            values.join(", ")
+        )?;
+    }
+    for (department, professors) in db["contacts"].as_object().into_iter().flatten() {
+        for (name, contact) in professors.as_object().into_iter().flatten() {
+            writeln!(
+                writer,
+                "INSERT INTO {} ({}, {}, {}, {}) VALUES ({}, {}, {}, {});",
+                id("contacts"),
+                id("department"),
+                id("name"),
+                id("email"),
+                id("mpno"),
+                dialect.literal(&Value::String(department.clone())),
+                dialect.literal(&Value::String(name.clone())),
+                dialect.literal(&contact["email"]),
+                dialect.literal(&contact["mpno"])
+            )?;
+        }
+    }
+    for (key, value) in db["version"].as_object().into_iter().flatten() {
+        writeln!(
+            writer,
+            "INSERT INTO {} ({}, {}) VALUES ({}, {});",
+            id("version"),
+            id("key"),
+            id("value"),
+            dialect.literal(&Value::String(key.clone())),
+            dialect.literal(value)
+        )?;
+    }
+    writeln!(writer, "COMMIT;")?;
+    writer.flush()?;
+    Ok(())
+}
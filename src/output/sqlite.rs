@@ -0,0 +1,122 @@
+//! # SQLite 출력
+//!
+//! DB를 departments, subjects, contacts, version 테이블을 가진 SQLite 파일로 저장한다.
+use std::error::Error;
+use std::fs;
+use std::path::Path;
+
+use rusqlite::types::Value as SqlValue;
+use rusqlite::{params, params_from_iter, Connection};
+use serde_json::Value;
+
+use crate::output::{department_lists, subject_lists};
+use crate::SUBJECT_FIELDS;
+
+/// JSON 값을 SQLite 값으로 바꾸는 메서드
+///
+/// 문자열과 숫자는 그대로, `null`은 `NULL`로 저장하며 그 외의 값은 JSON 문자열로 저장한다.
+fn to_sql_value(value: &Value) -> SqlValue {
+    match value {
+        Value::Null => SqlValue::Null,
+        Value::Bool(value) => SqlValue::Integer(i64::from(*value)),
+        Value::Number(number) => match number.as_i64() {
+            Some(integer) => SqlValue::Integer(integer),
+            None => SqlValue::Real(number.as_f64().unwrap_or_default()),
+        },
+        Value::String(text) => SqlValue::Text(text.clone()),
+        _ => SqlValue::Text(value.to_string()),
+    }
+}
+
+/// DB를 SQLite 파일로 저장하는 메서드
+///
+/// 같은 경로에 파일이 존재하는 경우 기존 파일을 지우고 새로 만든다.
+///
+/// ## Arguments
+///
+/// * `db` - 저장할 DB의 내용
+/// * `path` - 저장할 SQLite 파일의 경로
+///
+/// ## Examples
+/// ```
+/// use suwon_mate_admin_tool::{build_db_content, BuildOptions};
+/// use suwon_mate_admin_tool::output::sqlite::write_sqlite;
+/// let sample = std::fs::read_to_string("sample/sample_todo_class.json").unwrap();
+/// let options = BuildOptions { db_version: String::from("test"), quick_mode: true, ..Default::default() };
+/// let (db, _) = build_db_content(&sample, &sample, &options).unwrap();
+/// let path = std::env::temp_dir().join("suwon_mate_sqlite_doctest.sqlite");
+/// write_sqlite(&db, &path).unwrap();
+/// let connection = rusqlite::Connection::open(&path).unwrap();
+/// let count: i64 = connection.query_row("SELECT COUNT(*) FROM subjects", [], |row| row.get(0)).unwrap();
+/// assert_eq!(count, 5);
+/// ```
+///
+/// ## Errors
+/// SQLite 파일을 만들거나 쓰는 과정에서 문제가 발생한 경우 오류가 발생한다.
+pub fn write_sqlite(db: &Value, path: &Path) -> Result<(), Box<dyn Error>> {
+    if path.exists() {
+        fs::remove_file(path)?;
+    }
+    let mut connection = Connection::open(path)?;
+    let transaction = connection.transaction()?;
+    let subject_columns = SUBJECT_FIELDS
+        .iter()
+        .map(|field| format!("\"{}\"", field))
+        .collect::<Vec<_>>();
+    transaction.execute_batch(&format!(
+        "CREATE TABLE departments (department TEXT NOT NULL, major TEXT NOT NULL);
+         CREATE TABLE subjects ({});
+         CREATE TABLE contacts (department TEXT NOT NULL, name TEXT NOT NULL, email TEXT, mpno TEXT);
+         CREATE TABLE version (key TEXT PRIMARY KEY, value);",
+        subject_columns.join(", ")
+    ))?;
+
+    if let Some(departments) = department_lists(db) {
+        let mut statement =
+            transaction.prepare("INSERT INTO departments (department, major) VALUES (?1, ?2)")?;
+        for (department, majors) in departments {
+            for major in majors.as_array().into_iter().flatten() {
+                statement.execute(params![department, major.as_str()])?;
+            }
+        }
+    }
+    if let Some(subject_lists) = subject_lists(db) {
+        let placeholders = vec!["?"; SUBJECT_FIELDS.len()].join(", ");
+        let mut statement = transaction.prepare(&format!(
+            "INSERT INTO subjects ({}) VALUES ({})",
+            subject_columns.join(", "),
+            placeholders
+        ))?;
+        for subject in subject_lists.values().filter_map(Value::as_array).flatten() {
+            statement.execute(params_from_iter(
+                SUBJECT_FIELDS
+                    .iter()
+                    .map(|field| to_sql_value(&subject[*field])),
+            ))?;
+        }
+    }
+    if let Some(contacts) = db["contacts"].as_object() {
+        let mut statement = transaction.prepare(
+            "INSERT INTO contacts (department, name, email, mpno) VALUES (?1, ?2, ?3, ?4)",
+        )?;
+        for (department, professors) in contacts {
+            for (name, contact) in professors.as_object().into_iter().flatten() {
+                statement.execute(params![
+                    department,
+                    name,
+                    to_sql_value(&contact["email"]),
+                    to_sql_value(&contact["mpno"])
+                ])?;
+            }
+        }
+    }
+    if let Some(version) = db["version"].as_object() {
+        let mut statement =
+            transaction.prepare("INSERT INTO version (key, value) VALUES (?1, ?2)")?;
+        for (key, value) in version {
+            statement.execute(params![key, to_sql_value(value)])?;
+        }
+    }
+    transaction.commit()?;
+    Ok(())
+}
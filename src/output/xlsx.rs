@@ -0,0 +1,118 @@
+//! # XLSX 출력
+//!
+//! 학과 사무실에서 바로 열어볼 수 있도록 과목 목록을 학부별 시트로 나눈 엑셀 파일로 저장한다.
+//! 각 시트의 열은 CSV 출력과 같다.
+use std::collections::HashSet;
+use std::error::Error;
+use std::path::Path;
+
+use rust_xlsxwriter::{Format, Workbook};
+use serde_json::Value;
+
+use crate::output::csv::CSV_COLUMNS;
+use crate::output::subject_lists;
+
+/// 엑셀 시트 이름의 최대 길이
+const SHEET_NAME_MAX_LENGTH: usize = 31;
+
+/// 학부명을 엑셀 시트 이름으로 사용할 수 있도록 바꾸는 메서드
+///
+/// 시트 이름에 사용할 수 없는 문자는 `_`로 바꾸고 31자를 넘는 부분은 자른다.
+/// 빈 이름이거나 이미 사용된 이름인 경우 뒤에 번호를 붙인다.
+///
+/// ## Examples
+/// ```
+/// use std::collections::HashSet;
+/// use suwon_mate_admin_tool::output::xlsx::sheet_name;
+/// let mut used = HashSet::new();
+/// assert_eq!(sheet_name("경영/회계학부", &mut used), "경영_회계학부");
+/// assert_eq!(sheet_name("경영:회계학부", &mut used), "경영_회계학부 (2)");
+/// assert_eq!(sheet_name("", &mut used), "학부 미지정");
+/// ```
+pub fn sheet_name(department: &str, used: &mut HashSet<String>) -> String {
+    let base: String = department
+        .chars()
+        .map(|character| match character {
+            '[' | ']' | ':' | '*' | '?' | '/' | '\\' => '_',
+            _ => character,
+        })
+        .take(SHEET_NAME_MAX_LENGTH)
+        .collect();
+    let base = match base.trim_matches('\'') {
+        "" => String::from("학부 미지정"),
+        base => base.to_string(),
+    };
+    let mut name = base.clone();
+    let mut number = 2;
+    while used.contains(&name.to_lowercase()) {
+        let suffix = format!(" ({})", number);
+        let prefix: String = base
+            .chars()
+            .take(SHEET_NAME_MAX_LENGTH - suffix.chars().count())
+            .collect();
+        name = format!("{}{}", prefix, suffix);
+        number += 1;
+    }
+    used.insert(name.to_lowercase());
+    name
+}
+
+/// DB의 과목 목록을 학부별 시트로 나눈 XLSX 파일로 저장하는 메서드
+///
+/// 학점처럼 숫자인 값은 숫자 칸으로, 문자열은 문자열 칸으로 기록하며 `null`은 빈 칸으로 남긴다.
+///
+/// ## Arguments
+///
+/// * `db` - 저장할 DB의 내용
+/// * `path` - 저장할 XLSX 파일의 경로
+///
+/// ## Examples
+/// ```
+/// use suwon_mate_admin_tool::{build_db_content, BuildOptions};
+/// use suwon_mate_admin_tool::output::xlsx::write_xlsx;
+/// let sample = std::fs::read_to_string("sample/sample_todo_class.json").unwrap();
+/// let options = BuildOptions { db_version: String::from("test"), quick_mode: true, ..Default::default() };
+/// let (db, _) = build_db_content(&sample, &sample, &options).unwrap();
+/// let path = std::env::temp_dir().join("suwon_mate_xlsx_doctest.xlsx");
+/// write_xlsx(&db, &path).unwrap();
+/// assert!(std::fs::read(&path).unwrap().starts_with(b"PK"));
+/// ```
+///
+/// ## Errors
+/// XLSX 파일을 만들거나 쓰는 과정에서 문제가 발생한 경우 오류가 발생한다.
+pub fn write_xlsx(db: &Value, path: &Path) -> Result<(), Box<dyn Error>> {
+    let mut workbook = Workbook::new();
+    let header_format = Format::new().set_bold();
+    let mut used_names = HashSet::new();
+    for (department, subjects) in subject_lists(db).into_iter().flatten() {
+        let worksheet = workbook.add_worksheet();
+        worksheet.set_name(sheet_name(department, &mut used_names))?;
+        for (column, (header, _)) in CSV_COLUMNS.iter().enumerate() {
+            worksheet.write_string_with_format(0, column as u16, *header, &header_format)?;
+        }
+        for (row, subject) in subjects.as_array().into_iter().flatten().enumerate() {
+            let row = row as u32 + 1;
+            for (column, (_, field)) in CSV_COLUMNS.iter().enumerate() {
+                let column = column as u16;
+                match &subject[field] {
+                    Value::Null => {}
+                    Value::Number(number) => {
+                        worksheet.write_number(row, column, number.as_f64().unwrap_or_default())?;
+                    }
+                    Value::String(text) => {
+                        worksheet.write_string(row, column, text)?;
+                    }
+                    value => {
+                        worksheet.write_string(row, column, value.to_string())?;
+                    }
+                }
+            }
+        }
+        worksheet.autofit();
+    }
+    if workbook.worksheets().is_empty() {
+        workbook.add_worksheet().set_name("학부 미지정")?;
+    }
+    workbook.save(path)?;
+    Ok(())
+}
@@ -0,0 +1,119 @@
+//! # 수동 보정 파일
+//!
+//! 강의계획서에 정보가 없거나 잘못 기록되어 자동으로 학부를 찾을 수 없는 과목의 학부, 학과, 연락처를 직접 지정한다.
+//!
+//! 보정 파일(`overrides.json`)은 과목 키(`subjtCd-diclNo`)별로 바꿀 항목만 적는다.
+//!
+//! ```json
+//! {
+//!     "11416-038": {"department": "경영학부", "major": "경영학과"},
+//!     "20001-001": {"department": "컴퓨터학부", "email": "prof@suwon.ac.kr", "mpno": null}
+//! }
+//! ```
+//!
+//! 보정 내용은 DB 제작 전에 강의계획서의 과목 정보에 덮어써지므로, 자동 매칭 결과보다 항상 우선 적용된다.
+//! 강의계획서에 없는 과목은 보정 내용으로 과목 정보를 새로 만들어 추가하며, 이 경우 `department`는 반드시 지정해야 한다.
+use std::error::Error;
+
+use serde_json::{json, Value};
+
+use crate::input::SUBJECT_LIST_KEY;
+use crate::subject_key;
+
+/// 기본 보정 파일
+pub const OVERRIDES_FILE: &str = "overrides.json";
+
+/// 보정 파일의 항목 이름과 강의계획서 과목 정보의 필드 이름
+const OVERRIDE_FIELDS: [(&str, &str); 4] = [
+    ("department", "estbDpmjNm"),
+    ("major", "estbMjorNm"),
+    ("email", "email"),
+    ("mpno", "mpno"),
+];
+
+/// 강의계획서 내용에 보정 파일의 내용을 덮어쓰는 메서드
+///
+/// 보정한 과목의 수를 반환한다.
+///
+/// ## Arguments
+///
+/// * `class_todo` - 강의계획서 DB 파일의 내용
+/// * `overrides` - 과목 키별 보정 내용
+///
+/// ## Examples
+/// ```
+/// use serde_json::json;
+/// use suwon_mate_admin_tool::overrides::apply_overrides;
+/// let mut class_todo = json!({"estbLectDtaiList": [
+///     {"subjtCd": "1", "diclNo": "001", "estbDpmjNm": "경영힉부", "estbMjorNm": null, "email": "a@suwon.ac.kr"}
+/// ]});
+/// let overrides = json!({
+///     "1-001": {"department": "경영학부", "major": "경영학과"},
+///     "2-001": {"department": "컴퓨터학부"}
+/// });
+/// assert_eq!(apply_overrides(&mut class_todo, &overrides).unwrap(), 2);
+/// let subjects = class_todo["estbLectDtaiList"].as_array().unwrap();
+/// assert_eq!(subjects[0]["subjtCd"], "2");
+/// assert_eq!(subjects[0]["estbDpmjNm"], "컴퓨터학부");
+/// assert_eq!(subjects[1]["estbDpmjNm"], "경영학부");
+/// assert_eq!(subjects[1]["estbMjorNm"], "경영학과");
+/// assert_eq!(subjects[1]["email"], "a@suwon.ac.kr");
+///
+/// assert!(apply_overrides(&mut class_todo, &json!({"3-001": {"major": "경영학과"}})).is_err());
+/// assert!(apply_overrides(&mut class_todo, &json!({"1-001": {"dept": "경영학부"}})).is_err());
+/// assert!(apply_overrides(&mut class_todo, &json!({"1001": {}})).is_err());
+/// ```
+///
+/// ## Errors
+/// * 강의계획서에 과목 목록이 없거나 보정 파일이 과목 키별 객체가 아닌 경우
+/// * 과목 키가 `subjtCd-diclNo` 형태가 아니거나 알 수 없는 항목이 있는 경우
+/// * 강의계획서에 없는 과목의 보정 내용에 `department`가 없는 경우
+pub fn apply_overrides(class_todo: &mut Value, overrides: &Value) -> Result<usize, Box<dyn Error>> {
+    let overrides = overrides
+        .as_object()
+        .ok_or("보정 파일은 과목 키별 보정 내용을 담은 객체여야 합니다.")?;
+    let subjects = class_todo[SUBJECT_LIST_KEY]
+        .as_array_mut()
+        .ok_or("강의계획서에 과목 목록이 없습니다.")?;
+    for (key, fields) in overrides {
+        let (code, number) = key
+            .split_once('-')
+            .ok_or_else(|| format!("과목 키는 subjtCd-diclNo 형태여야 합니다: {}", key))?;
+        let fields = fields
+            .as_object()
+            .ok_or_else(|| format!("{}의 보정 내용이 객체가 아닙니다.", key))?;
+        if let Some(field) = fields
+            .keys()
+            .find(|field| OVERRIDE_FIELDS.iter().all(|(name, _)| name != field))
+        {
+            return Err(format!(
+                "{}의 보정 내용에 알 수 없는 항목이 있습니다: {}",
+                key, field
+            )
+            .into());
+        }
+        let index = match subjects
+            .iter()
+            .position(|subject| subject_key(subject) == *key)
+        {
+            Some(index) => index,
+            None => {
+                if !fields.contains_key("department") {
+                    return Err(format!(
+                        "강의계획서에 없는 과목({})의 보정 내용에는 department가 있어야 합니다.",
+                        key
+                    )
+                    .into());
+                }
+                subjects.insert(0, json!({"subjtCd": code, "diclNo": number}));
+                0
+            }
+        };
+        for (name, field) in OVERRIDE_FIELDS {
+            if let Some(value) = fields.get(name) {
+                subjects[index][field] = value.clone();
+            }
+        }
+    }
+    Ok(overrides.len())
+}
@@ -0,0 +1,62 @@
+//! # 플랫폼 지원 계층
+//!
+//! 운영체제별로 다르게 처리해야 하는 콘솔 관련 기능을 모아둔 모듈이다.
+//! 행정 PC(Windows)에서 한글 경로와 콘솔 출력이 깨지지 않도록 하는 처리가 포함된다.
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// 안내 메세지를 표준에러로 출력하는지 여부
+static MESSAGES_TO_STDERR: AtomicBool = AtomicBool::new(false);
+
+#[cfg(windows)]
+mod windows {
+    /// UTF-8 코드 페이지 번호
+    pub const CP_UTF8: u32 = 65001;
+
+    #[link(name = "kernel32")]
+    extern "system" {
+        pub fn SetConsoleOutputCP(code_page_id: u32) -> i32;
+        pub fn SetConsoleCP(code_page_id: u32) -> i32;
+    }
+}
+
+/// 콘솔 입출력 인코딩을 UTF-8로 설정하는 메서드
+///
+/// Windows의 경우 기본 콘솔 코드 페이지가 CP949이므로 한글 메세지가 깨져 보일 수 있다.
+/// 프로그램 시작 시 해당 메서드를 호출하면 콘솔 코드 페이지를 UTF-8로 변경한다.
+/// Windows 이외의 운영체제에서는 아무런 동작도 하지 않는다.
+///
+/// ## Examples
+/// ```
+/// suwon_mate_admin_tool::platform::init_console();
+/// ```
+pub fn init_console() {
+    #[cfg(windows)]
+    // SAFETY: 코드 페이지 번호만 전달하는 Win32 API 호출이며 반환값만 확인한다.
+    unsafe {
+        if windows::SetConsoleOutputCP(windows::CP_UTF8) == 0
+            || windows::SetConsoleCP(windows::CP_UTF8) == 0
+        {
+            eprintln!("주의: 콘솔 인코딩을 UTF-8로 변경하지 못했습니다.");
+        }
+    }
+}
+
+/// 안내 메세지([crate::notice])를 표준에러로 출력할지 설정하는 메서드
+///
+/// 표준출력을 기계가 읽는 결과 전용으로 사용하는 경우(`--json-summary`) `true`로 설정한다.
+///
+/// ## Examples
+/// ```
+/// use suwon_mate_admin_tool::platform::{messages_to_stderr, set_messages_to_stderr};
+/// assert!(!messages_to_stderr());
+/// set_messages_to_stderr(true);
+/// assert!(messages_to_stderr());
+/// ```
+pub fn set_messages_to_stderr(enabled: bool) {
+    MESSAGES_TO_STDERR.store(enabled, Ordering::Relaxed);
+}
+
+/// 안내 메세지를 표준에러로 출력하는지 확인하는 메서드
+pub fn messages_to_stderr() -> bool {
+    MESSAGES_TO_STDERR.load(Ordering::Relaxed)
+}
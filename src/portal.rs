@@ -0,0 +1,335 @@
+//! # 포털 개설강좌 API 수집
+//!
+//! 수원대학교 포털의 개설강좌 조회 API는 한 번에 한 페이지씩만 응답하므로, 모든 페이지를 차례로 요청하여
+//! 하나의 `estbLectDtaiList` 목록으로 합친다. `fetch` 서브커맨드 등 포털에서 입력 파일을 직접 받아오는 기능의 기반이 된다.
+//!
+//! 요청이 일시적으로 실패하면 [RetryPolicy]에 따라 재시도하며, 페이지 사이에는 지연 시간을 둔다.
+//! 페이지는 1부터 차례로 요청하며, 과목 목록이 비어있거나 페이지 크기보다 적은 페이지를 받으면 마지막 페이지로 보고 수집을 마친다.
+//!
+//! 로그인해야 받을 수 있는 API는 [PortalAuth]로 세션 쿠키나 토큰을 요청에 포함한다. 자격 증명은 명령줄 인자로 받지 않고
+//! 환경 변수나 키링에서 읽는다.
+use std::error::Error;
+use std::fmt;
+
+use clap::ValueEnum;
+use serde_json::Value;
+
+use crate::input::{normalize_root_key, SUBJECT_LIST_KEY};
+use crate::network::{agent, is_retryable, retry, RetryPolicy};
+use crate::secret::keyring_secret;
+
+/// 기본 페이지 크기
+pub const DEFAULT_PAGE_SIZE: usize = 100;
+/// 요청할 수 있는 최대 페이지 수의 기본값
+pub const DEFAULT_MAX_PAGES: u32 = 1000;
+
+/// 포털 인증 방식
+#[derive(Copy, Clone, PartialEq, Eq, Debug, ValueEnum)]
+pub enum PortalAuthKind {
+    /// `Authorization: Bearer` 헤더로 보내는 토큰 (환경 변수 `PORTAL_TOKEN` 또는 키링 항목 `portal-token`)
+    Token,
+    /// `Cookie` 헤더로 보내는 세션 쿠키 (환경 변수 `PORTAL_COOKIE` 또는 키링 항목 `portal-cookie`)
+    Cookie,
+}
+
+impl PortalAuthKind {
+    /// 자격 증명을 읽을 환경 변수 이름
+    pub fn env_name(&self) -> &'static str {
+        match self {
+            PortalAuthKind::Token => "PORTAL_TOKEN",
+            PortalAuthKind::Cookie => "PORTAL_COOKIE",
+        }
+    }
+
+    /// 자격 증명을 읽을 키링 항목 이름
+    pub fn keyring_entry(&self) -> &'static str {
+        match self {
+            PortalAuthKind::Token => "portal-token",
+            PortalAuthKind::Cookie => "portal-cookie",
+        }
+    }
+}
+
+/// 포털 요청에 포함할 자격 증명
+///
+/// 실수로 로그에 남지 않도록 [Debug] 출력에서는 값을 가린다.
+#[derive(Clone, PartialEq, Eq)]
+pub struct PortalAuth {
+    /// 인증 방식
+    pub kind: PortalAuthKind,
+    /// 토큰 또는 쿠키 값
+    pub secret: String,
+}
+
+impl fmt::Debug for PortalAuth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PortalAuth")
+            .field("kind", &self.kind)
+            .field("secret", &"***")
+            .finish()
+    }
+}
+
+impl PortalAuth {
+    /// 환경 변수나 키링에서 `kind`의 자격 증명을 읽는 메서드
+    ///
+    /// 환경 변수가 비어있지 않으면 환경 변수를, 그렇지 않으면 키링의 값을 사용한다.
+    ///
+    /// ## Examples
+    /// ```
+    /// use suwon_mate_admin_tool::portal::{PortalAuth, PortalAuthKind};
+    /// std::env::set_var("PORTAL_COOKIE", " JSESSIONID=abc ");
+    /// let auth = PortalAuth::load(PortalAuthKind::Cookie).unwrap();
+    /// assert_eq!(auth.secret, "JSESSIONID=abc");
+    /// assert!(!format!("{:?}", auth).contains("abc"));
+    /// ```
+    ///
+    /// ## Errors
+    /// 환경 변수가 없고 키링에서도 값을 읽을 수 없는 경우 오류가 발생한다.
+    pub fn load(kind: PortalAuthKind) -> Result<Self, Box<dyn Error>> {
+        let secret = match std::env::var(kind.env_name()) {
+            Ok(secret) if !secret.trim().is_empty() => secret.trim().to_string(),
+            _ => keyring_secret(kind.keyring_entry()).map_err(|error| {
+                format!(
+                    "포털 자격 증명이 없습니다. {} 환경 변수를 지정하거나 `secret set {}`으로 저장해주세요. ({})",
+                    kind.env_name(),
+                    kind.keyring_entry(),
+                    error
+                )
+            })?,
+        };
+        Ok(Self { kind, secret })
+    }
+
+    /// 자격 증명을 보낼 HTTP 헤더의 이름과 값
+    ///
+    /// ## Examples
+    /// ```
+    /// use suwon_mate_admin_tool::portal::{PortalAuth, PortalAuthKind};
+    /// let auth = PortalAuth { kind: PortalAuthKind::Token, secret: "abc".into() };
+    /// assert_eq!(auth.header(), ("Authorization", "Bearer abc".to_string()));
+    /// ```
+    pub fn header(&self) -> (&'static str, String) {
+        match self.kind {
+            PortalAuthKind::Token => ("Authorization", format!("Bearer {}", self.secret)),
+            PortalAuthKind::Cookie => ("Cookie", self.secret.clone()),
+        }
+    }
+}
+
+/// 포털 API 요청 설정
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct PortalRequest {
+    /// API의 URL
+    pub url: String,
+    /// 페이지 번호와 페이지 크기 외에 함께 보낼 폼 인자 (학년도, 학기 등)
+    pub params: Vec<(String, String)>,
+    /// 페이지 번호를 보낼 인자 이름
+    pub page_param: String,
+    /// 페이지 크기를 보낼 인자 이름
+    pub size_param: String,
+    /// 한 페이지에 요청할 과목 수
+    pub page_size: usize,
+    /// 응답에서 과목 목록이 들어있는 키 ([normalize_root_key] 참고)
+    pub root_key: Option<String>,
+    /// 요청할 수 있는 최대 페이지 수 (API가 페이지 번호를 무시하는 경우 무한히 요청하지 않기 위함)
+    pub max_pages: u32,
+    /// 요청 재시도 정책과 페이지 사이의 지연 시간
+    pub retry: RetryPolicy,
+    /// 요청에 포함할 자격 증명 (캐시의 출처 문자열에는 포함하지 않음)
+    pub auth: Option<PortalAuth>,
+}
+
+impl Default for PortalRequest {
+    fn default() -> Self {
+        Self {
+            url: String::new(),
+            params: vec![],
+            page_param: String::from("pageNo"),
+            size_param: String::from("pageSize"),
+            page_size: DEFAULT_PAGE_SIZE,
+            root_key: None,
+            max_pages: DEFAULT_MAX_PAGES,
+            retry: RetryPolicy::default(),
+            auth: None,
+        }
+    }
+}
+
+impl PortalRequest {
+    /// 요청의 URL과 폼 인자를 캐시에서 원본을 구분하는 출처 문자열로 만드는 메서드
+    ///
+    /// ## Examples
+    /// ```
+    /// use suwon_mate_admin_tool::portal::PortalRequest;
+    /// let request = PortalRequest {
+    ///     url: "https://example.com/api".into(),
+    ///     params: vec![("sYear".into(), "2024".into()), ("sTerm".into(), "10".into())],
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(request.source(), "https://example.com/api?sYear=2024&sTerm=10&pageSize=100");
+    /// ```
+    pub fn source(&self) -> String {
+        let params: Vec<String> = self
+            .params
+            .iter()
+            .map(|(key, value)| format!("{}={}", key, value))
+            .chain(std::iter::once(format!(
+                "{}={}",
+                self.size_param, self.page_size
+            )))
+            .collect();
+        format!("{}?{}", self.url, params.join("&"))
+    }
+}
+
+/// 페이지를 가져오는 `fetch_page`로 모든 페이지를 수집하여 하나의 응답으로 합치는 메서드
+///
+/// 각 페이지의 과목 목록을 순서대로 이어 붙이며, 그 외의 최상위 항목은 첫 페이지의 값을 사용한다.
+///
+/// ## Arguments
+///
+/// * `request` - 페이지 크기, 과목 목록 키, 최대 페이지 수를 담은 요청 설정
+/// * `fetch_page` - 1부터 시작하는 페이지 번호를 받아 해당 페이지의 응답을 반환하는 함수
+///
+/// ## Examples
+/// ```
+/// use serde_json::json;
+/// use suwon_mate_admin_tool::portal::{collect_pages, PortalRequest};
+/// let request = PortalRequest { page_size: 2, ..Default::default() };
+/// let pages = [
+///     json!({"estbLectDtaiList": [{"subjtCd": "1"}, {"subjtCd": "2"}], "totalCount": 3}),
+///     json!({"estbLectDtaiList": [{"subjtCd": "3"}], "totalCount": 3}),
+/// ];
+/// let collected = collect_pages(&request, |page| Ok(pages[page as usize - 1].clone())).unwrap();
+/// assert_eq!(collected["estbLectDtaiList"].as_array().unwrap().len(), 3);
+/// assert_eq!(collected["totalCount"], 3);
+///
+/// let repeated = collect_pages(&request, |_| Ok(pages[0].clone()));
+/// assert!(repeated.is_err());
+/// ```
+///
+/// ## Errors
+/// * `fetch_page`에서 오류가 발생한 경우
+/// * 응답에서 과목 목록을 찾지 못한 경우
+/// * 직전 페이지와 같은 내용의 페이지를 받았거나 최대 페이지 수를 넘은 경우
+pub fn collect_pages<F>(request: &PortalRequest, mut fetch_page: F) -> Result<Value, Box<dyn Error>>
+where
+    F: FnMut(u32) -> Result<Value, Box<dyn Error>>,
+{
+    let mut collected: Option<Value> = None;
+    let mut previous: Vec<Value> = vec![];
+    for page in 1..=request.max_pages {
+        let mut response = normalize_root_key(fetch_page(page)?, request.root_key.as_deref())?;
+        let subjects = response[SUBJECT_LIST_KEY]
+            .as_array_mut()
+            .map(std::mem::take)
+            .ok_or_else(|| format!("{}번째 페이지에 과목 목록이 없습니다.", page))?;
+        if !subjects.is_empty() && subjects == previous {
+            return Err(format!(
+                "{}번째 페이지가 직전 페이지와 같습니다. 페이지 번호 인자({})를 확인해주세요.",
+                page, request.page_param
+            )
+            .into());
+        }
+        let last = subjects.len() < request.page_size;
+        match collected.as_mut() {
+            None => {
+                response[SUBJECT_LIST_KEY] = Value::Array(subjects.clone());
+                collected = Some(response);
+            }
+            Some(collected) => collected[SUBJECT_LIST_KEY]
+                .as_array_mut()
+                .expect("첫 페이지의 과목 목록은 배열이다.")
+                .extend(subjects.iter().cloned()),
+        }
+        if last {
+            return collected.ok_or_else(|| "수집한 페이지가 없습니다.".into());
+        }
+        previous = subjects;
+    }
+    Err(format!(
+        "최대 페이지 수({})를 넘었습니다. 페이지 크기를 늘리거나 최대 페이지 수를 조정해주세요.",
+        request.max_pages
+    )
+    .into())
+}
+
+/// 포털 API에 한 페이지를 요청하는 메서드
+///
+/// `request.params`와 페이지 번호, 페이지 크기를 폼 인자로 보내고 응답을 JSON으로 해독한다.
+/// `request.auth`가 있으면 자격 증명을 헤더에 포함하며, 일시적인 오류는 `request.retry`에 따라 재시도한다.
+///
+/// ## Errors
+/// 재시도 후에도 요청에 실패하였거나 응답의 JSON 해독이 불가능한 경우 오류가 발생한다. 인증에 실패한 경우(`401`, `403`)에는
+/// 자격 증명을 확인하라는 안내를 덧붙인다.
+pub fn fetch_page(request: &PortalRequest, page: u32) -> Result<Value, Box<dyn Error>> {
+    let page = page.to_string();
+    let page_size = request.page_size.to_string();
+    let mut form: Vec<(&str, &str)> = request
+        .params
+        .iter()
+        .map(|(key, value)| (key.as_str(), value.as_str()))
+        .collect();
+    form.push((&request.page_param, &page));
+    form.push((&request.size_param, &page_size));
+    let description = format!("{}의 {}번째 페이지 요청", request.url, page);
+    let mut unauthorized = false;
+    let response = retry(&request.retry, &description, is_retryable, || {
+        let mut http_request = agent().post(&request.url);
+        if let Some(auth) = &request.auth {
+            let (name, value) = auth.header();
+            http_request = http_request.set(name, &value);
+        }
+        let response = http_request.send_form(&form);
+        unauthorized = matches!(response, Err(ureq::Error::Status(401 | 403, _)));
+        response.map_err(Box::new)
+    })
+    .map_err(|error| -> Box<dyn Error> {
+        if unauthorized {
+            format!(
+                "{} 포털 인증 정보(--auth 옵션과 세션 쿠키 또는 토큰)가 올바르고 만료되지 않았는지 확인해주세요.",
+                error
+            )
+            .into()
+        } else {
+            error
+        }
+    })?;
+    Ok(serde_json::from_reader(response.into_reader())?)
+}
+
+/// 포털 API의 모든 페이지를 수집하여 하나의 `estbLectDtaiList`로 합치는 메서드
+///
+/// [fetch_page]로 페이지를 요청하며, 수집 방식은 [collect_pages]와 같다. 두 번째 페이지부터는 요청 전에
+/// `request.retry.request_delay`만큼 기다린다.
+///
+/// ## Errors
+/// 페이지를 가져오지 못하였거나 [collect_pages]에서 오류가 발생한 경우
+pub fn collect(request: &PortalRequest) -> Result<Value, Box<dyn Error>> {
+    collect_pages(request, |page| {
+        if page > 1 {
+            std::thread::sleep(request.retry.request_delay);
+        }
+        fetch_page(request, page)
+    })
+}
+
+/// `KEY=VALUE` 형태의 폼 인자를 해독하는 메서드
+///
+/// ## Examples
+/// ```
+/// use suwon_mate_admin_tool::portal::parse_param;
+/// assert_eq!(parse_param("sYear=2024").unwrap(), ("sYear".to_string(), "2024".to_string()));
+/// assert_eq!(parse_param("q=a=b").unwrap(), ("q".to_string(), "a=b".to_string()));
+/// assert!(parse_param("sYear").is_err());
+/// ```
+///
+/// ## Errors
+/// `=`가 없거나 인자 이름이 비어있는 경우 오류가 발생한다.
+pub fn parse_param(param: &str) -> Result<(String, String), String> {
+    match param.split_once('=') {
+        Some((key, value)) if !key.is_empty() => Ok((key.to_string(), value.to_string())),
+        _ => Err(format!("폼 인자는 KEY=VALUE 형태여야 합니다: {}", param)),
+    }
+}
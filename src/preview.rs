@@ -0,0 +1,105 @@
+//! # HTML 미리보기
+//!
+//! 제작된 DB를 학부 → 학과 → 과목 트리로 탐색할 수 있는 단일 HTML 파일로 만든다.
+//! 외부 스크립트나 스타일시트 없이 `<details>` 요소만 사용하므로 파일 하나만 전달하면 브라우저에서 바로 열어볼 수 있다.
+use std::collections::BTreeMap;
+
+use serde_json::Value;
+
+use crate::output::subject_lists;
+use crate::schema::escape_html;
+use crate::subject_key;
+
+/// 학과가 지정되지 않은 학부 공통 과목을 묶는 항목의 이름
+pub const COMMON_MAJOR: &str = "학부 공통";
+
+/// 미리보기의 과목 표에 표시할 열 목록 (표시 이름, 필드 이름)
+pub const PREVIEW_COLUMNS: [(&str, &str); 7] = [
+    ("과목 키", ""),
+    ("과목명", "subjtNm"),
+    ("교수", "ltrPrfsNm"),
+    ("이수 구분", "facDvnm"),
+    ("학점", "point"),
+    ("시간표", "timtSmryCn"),
+    ("강의 언어", "lssnLangNm"),
+];
+
+/// 표의 셀에 표시할 문자열을 만드는 메서드
+fn cell(subject: &Value, field: &str) -> String {
+    let text = match &subject[field] {
+        _ if field.is_empty() => subject_key(subject),
+        Value::String(text) => text.clone(),
+        Value::Null => String::new(),
+        value => value.to_string(),
+    };
+    escape_html(&text)
+}
+
+/// DB로 미리보기 HTML 문서를 만드는 메서드
+///
+/// 학부와 학과는 이름 순으로 정렬되며, 학과가 없는 과목은 [COMMON_MAJOR] 항목으로 묶인다.
+///
+/// ## Examples
+/// ```
+/// use serde_json::json;
+/// use suwon_mate_admin_tool::preview::preview_html;
+/// let db = json!({
+///     "estbLectDtaiList": {"경영학부": [
+///         {"subjtCd": "1", "diclNo": "001", "subjtNm": "회계<원리>", "estbMjorNm": "경영학과"},
+///         {"subjtCd": "2", "diclNo": "001", "subjtNm": "경영학원론", "estbMjorNm": null}
+///     ]},
+///     "version": {"db_ver": "1.0"}
+/// });
+/// let html = preview_html(&db);
+/// assert!(html.contains("<summary>경영학부 (2)</summary>"));
+/// assert!(html.contains("<summary>학부 공통 (1)</summary>"));
+/// assert!(html.contains("<td>회계&lt;원리&gt;</td>"));
+/// ```
+pub fn preview_html(db: &Value) -> String {
+    let db_version = escape_html(db["version"]["db_ver"].as_str().unwrap_or_default());
+    let mut html = format!(
+        "<!DOCTYPE html>\n<html lang=\"ko\">\n<head>\n<meta charset=\"utf-8\">\n\
+         <title>수원 메이트 DB 미리보기 {0}</title>\n\
+         <style>details {{ margin-left: 1em; }} table {{ border-collapse: collapse; }} \
+         th, td {{ border: 1px solid #ccc; padding: 2px 6px; }}</style>\n\
+         </head>\n<body>\n<h1>수원 메이트 DB 미리보기 {0}</h1>\n",
+        db_version
+    );
+    for (department, subjects) in subject_lists(db).into_iter().flatten() {
+        let subjects = subjects.as_array().map(Vec::as_slice).unwrap_or_default();
+        let mut majors: BTreeMap<&str, Vec<&Value>> = BTreeMap::new();
+        for subject in subjects {
+            majors
+                .entry(subject["estbMjorNm"].as_str().unwrap_or(COMMON_MAJOR))
+                .or_default()
+                .push(subject);
+        }
+        html.push_str(&format!(
+            "<details>\n<summary>{} ({})</summary>\n",
+            escape_html(department),
+            subjects.len()
+        ));
+        for (major, subjects) in majors {
+            html.push_str(&format!(
+                "<details>\n<summary>{} ({})</summary>\n<table>\n<tr>",
+                escape_html(major),
+                subjects.len()
+            ));
+            for (name, _) in PREVIEW_COLUMNS {
+                html.push_str(&format!("<th>{}</th>", name));
+            }
+            html.push_str("</tr>\n");
+            for subject in subjects {
+                html.push_str("<tr>");
+                for (_, field) in PREVIEW_COLUMNS {
+                    html.push_str(&format!("<td>{}</td>", cell(subject, field)));
+                }
+                html.push_str("</tr>\n");
+            }
+            html.push_str("</table>\n</details>\n");
+        }
+        html.push_str("</details>\n");
+    }
+    html.push_str("</body>\n</html>\n");
+    html
+}
@@ -0,0 +1,158 @@
+//! # 진행 상태 파일
+//!
+//! 웹 백오피스 등 다른 프로세스가 빌드 진행 상황을 폴링할 수 있도록 단계, 백분율, 경고 수를 JSON 파일에 기록한다.
+//!
+//! 상태 파일은 임시 파일에 먼저 기록한 뒤 이름을 바꾸는 방식으로 교체되므로, 읽는 쪽에서 일부만 기록된 파일을 읽지 않는다.
+//! 상태 파일을 기록하지 못하더라도 빌드는 중단하지 않는다.
+use std::cell::RefCell;
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde_json::{json, Value};
+
+use crate::history::kst_timestamp;
+use crate::template::now;
+
+/// 기본 진행 상태 파일
+pub const PROGRESS_FILE: &str = "progress.json";
+
+/// 빌드 진행 상태
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum ProgressStatus {
+    /// 진행 중
+    Running,
+    /// 완료
+    Done,
+    /// 실패
+    Failed,
+}
+
+impl ProgressStatus {
+    /// 상태 파일에 기록할 이름
+    fn name(&self) -> &'static str {
+        match self {
+            ProgressStatus::Running => "running",
+            ProgressStatus::Done => "done",
+            ProgressStatus::Failed => "failed",
+        }
+    }
+}
+
+/// 진행 상태 한 번의 내용을 만드는 메서드
+///
+/// ## Examples
+/// ```
+/// use suwon_mate_admin_tool::progress::{progress_entry, ProgressStatus};
+/// let entry = progress_entry(ProgressStatus::Running, "DB 제작", 40, 2, 0);
+/// assert_eq!(entry["status"], "running");
+/// assert_eq!(entry["stage"], "DB 제작");
+/// assert_eq!(entry["percent"], 40);
+/// assert_eq!(entry["warning_count"], 2);
+/// assert_eq!(entry["updated_at"], "1970-01-01T09:00:00+09:00");
+/// ```
+pub fn progress_entry(
+    status: ProgressStatus,
+    stage: &str,
+    percent: u8,
+    warning_count: usize,
+    updated_at: i64,
+) -> Value {
+    json!({
+        "status": status.name(),
+        "stage": stage,
+        "percent": percent.min(100),
+        "warning_count": warning_count,
+        "pid": std::process::id(),
+        "updated_at": kst_timestamp(updated_at),
+    })
+}
+
+/// 진행 상태 파일을 교체하는 메서드
+///
+/// ## Examples
+/// ```
+/// use serde_json::json;
+/// use suwon_mate_admin_tool::progress::write_progress;
+/// let path = std::env::temp_dir().join("suwon_mate_progress_doctest.json");
+/// write_progress(&path, &json!({"percent": 10})).unwrap();
+/// write_progress(&path, &json!({"percent": 20})).unwrap();
+/// assert_eq!(std::fs::read_to_string(&path).unwrap(), "{\"percent\":20}");
+/// ```
+///
+/// ## Errors
+/// 임시 파일을 기록하거나 이름을 바꾸지 못한 경우 오류가 발생한다.
+pub fn write_progress(path: &Path, entry: &Value) -> Result<(), Box<dyn Error>> {
+    let mut temporary = path.as_os_str().to_os_string();
+    temporary.push(".tmp");
+    fs::write(&temporary, entry.to_string())?;
+    fs::rename(&temporary, path)?;
+    Ok(())
+}
+
+/// 빌드 진행 상태를 기록하는 구조체
+///
+/// 경로가 지정되지 않은 경우 아무것도 기록하지 않는다. 실패를 기록할 때 실패한 단계를 함께 남길 수 있도록 마지막으로 기록한 단계를 기억한다.
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct Progress {
+    /// 진행 상태 파일의 경로
+    path: Option<PathBuf>,
+    /// 마지막으로 기록한 단계, 백분율, 경고 수
+    last: RefCell<(String, u8, usize)>,
+}
+
+impl Progress {
+    /// `path`에 진행 상태를 기록하는 [Progress]를 생성한다.
+    pub fn new(path: Option<PathBuf>) -> Self {
+        Self {
+            path,
+            ..Default::default()
+        }
+    }
+
+    /// 진행 상태를 기록하는 메서드
+    fn record(&self, status: ProgressStatus, error: Option<&str>) {
+        let Some(path) = &self.path else {
+            return;
+        };
+        let (stage, percent, warning_count) = self.last.borrow().clone();
+        let mut entry = progress_entry(status, &stage, percent, warning_count, now());
+        if let Some(error) = error {
+            entry["error"] = json!(error);
+        }
+        if let Err(error) = write_progress(path, &entry) {
+            notice!("주의: 진행 상태 파일을 기록하지 못했습니다. ({})", error);
+        }
+    }
+
+    /// 빌드가 `stage` 단계에 들어섰음을 기록하는 메서드
+    pub fn update(&self, stage: &str, percent: u8, warning_count: usize) {
+        *self.last.borrow_mut() = (stage.to_string(), percent, warning_count);
+        self.record(ProgressStatus::Running, None);
+    }
+
+    /// 빌드가 완료되었음을 기록하는 메서드
+    pub fn finish(&self, warning_count: usize) {
+        *self.last.borrow_mut() = (String::from("완료"), 100, warning_count);
+        self.record(ProgressStatus::Done, None);
+    }
+
+    /// 빌드가 실패하였음을 오류 메세지와 함께 기록하는 메서드
+    ///
+    /// ## Examples
+    /// ```
+    /// use suwon_mate_admin_tool::progress::Progress;
+    /// let path = std::env::temp_dir().join("suwon_mate_progress_fail_doctest.json");
+    /// let progress = Progress::new(Some(path.clone()));
+    /// progress.update("DB 제작", 20, 1);
+    /// progress.fail("입력 파일을 찾을 수 없습니다.");
+    /// let entry: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+    /// assert_eq!(entry["status"], "failed");
+    /// assert_eq!(entry["stage"], "DB 제작");
+    /// assert_eq!(entry["percent"], 20);
+    /// assert_eq!(entry["error"], "입력 파일을 찾을 수 없습니다.");
+    /// ```
+    pub fn fail(&self, error: &str) {
+        self.record(ProgressStatus::Failed, Some(error));
+    }
+}
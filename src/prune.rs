@@ -0,0 +1,37 @@
+//! # null 필드 생략
+//!
+//! 강의계획서에 없는 과목은 대부분의 필드가 null로 기록되므로, 값이 null인 필드를 생략하여 DB 용량을 줄인다.
+//! 앱은 생략된 필드를 null과 같이 취급해야 한다.
+use serde_json::Value;
+
+/// 객체에서 값이 null인 필드를 재귀적으로 제거하고 제거한 필드의 수를 반환하는 메서드
+///
+/// 배열의 원소는 위치에 의미가 있으므로 null이어도 제거하지 않는다.
+///
+/// ## Examples
+/// ```
+/// use serde_json::json;
+/// use suwon_mate_admin_tool::prune::prune_null;
+/// let mut db = json!({
+///     "estbLectDtaiList": {"경영학부": [{"subjtCd": "1", "estbMjorNm": null, "point": 3}]},
+///     "contacts": {"경영학부": {"홍길동": {"email": null, "mpno": null}}},
+///     "list": [null, 1]
+/// });
+/// assert_eq!(prune_null(&mut db), 3);
+/// assert_eq!(db, json!({
+///     "estbLectDtaiList": {"경영학부": [{"subjtCd": "1", "point": 3}]},
+///     "contacts": {"경영학부": {"홍길동": {}}},
+///     "list": [null, 1]
+/// }));
+/// ```
+pub fn prune_null(value: &mut Value) -> usize {
+    match value {
+        Value::Object(map) => {
+            let before = map.len();
+            map.retain(|_, value| !value.is_null());
+            before - map.len() + map.values_mut().map(prune_null).sum::<usize>()
+        }
+        Value::Array(values) => values.iter_mut().map(prune_null).sum(),
+        _ => 0,
+    }
+}
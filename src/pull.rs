@@ -0,0 +1,231 @@
+//! # 분할 DB 내려받기
+//!
+//! 앱 서버 운영자가 `--split-output`으로 배포된 최신 DB를 내려받아 로컬에 배치하는 소비 측 도구이다.
+//!
+//! 매니페스트(`manifest.json`)를 먼저 내려받은 뒤 매니페스트에 기록된 색인 파일과 학부별 과목 파일을 내려받는다.
+//! 검증을 요청한 경우 모든 파일의 SHA-256 해시와 크기를 매니페스트와 비교하고, 공개키가 주어지면 `sign` 명령으로
+//! 서명된 매니페스트의 서명도 확인한다. 매니페스트에는 모든 파일의 해시가 기록되어 있으므로 매니페스트의 서명만으로
+//! 모든 파일의 위변조 여부를 확인할 수 있다.
+//!
+//! 모든 파일을 내려받고 검증한 뒤에만 파일을 기록하며, 매니페스트는 가장 마지막에 기록된다.
+use std::error::Error;
+use std::fs;
+use std::io::Read;
+use std::path::{Component, Path, PathBuf};
+
+use ed25519_dalek::VerifyingKey;
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+
+use crate::network::{agent, is_retryable, retry, RetryPolicy};
+use crate::signature::{to_hex, verify_db};
+use crate::split::MANIFEST_FILE;
+
+/// 내려받을 수 있는 파일의 최대 크기 (바이트)
+const MAX_DOWNLOAD_SIZE: u64 = 256 * 1024 * 1024;
+
+/// 내려받기 설정
+#[derive(Clone, Debug, Default)]
+pub struct PullOptions {
+    /// 파일의 해시와 크기를 매니페스트와 비교할지 여부
+    pub verify: bool,
+    /// 매니페스트의 서명을 확인할 공개키
+    pub public_key: Option<VerifyingKey>,
+}
+
+/// URL 또는 `file://` 경로의 내용을 가져오는 메서드
+///
+/// 일시적인 오류는 기본 [RetryPolicy]에 따라 재시도한다.
+///
+/// ## Errors
+/// 재시도 후에도 요청에 실패하였거나 내용이 [MAX_DOWNLOAD_SIZE]보다 큰 경우 오류가 발생한다.
+pub fn fetch(url: &str) -> Result<Vec<u8>, Box<dyn Error>> {
+    if let Some(path) = url.strip_prefix("file://") {
+        return Ok(fs::read(path)?);
+    }
+    let description = format!("{} 내려받기", url);
+    let response = retry(&RetryPolicy::default(), &description, is_retryable, || {
+        agent().get(url).call().map_err(Box::new)
+    })?;
+    let mut content = vec![];
+    response
+        .into_reader()
+        .take(MAX_DOWNLOAD_SIZE + 1)
+        .read_to_end(&mut content)?;
+    if content.len() as u64 > MAX_DOWNLOAD_SIZE {
+        return Err(format!("{}의 크기가 너무 큽니다.", url).into());
+    }
+    Ok(content)
+}
+
+/// 매니페스트 항목의 파일을 내려받을 URL을 구하는 메서드
+///
+/// 항목에 `url`이 기록되어 있으면 그대로 사용하고, 없으면 매니페스트 URL의 위치를 기준으로 `file`을 이어 붙인다.
+///
+/// ## Examples
+/// ```
+/// use serde_json::json;
+/// use suwon_mate_admin_tool::pull::entry_url;
+/// let manifest_url = "https://example.com/db/manifest.json";
+/// assert_eq!(
+///     entry_url(manifest_url, &json!({"file": "subjects/경영학부.json", "url": null})).unwrap(),
+///     "https://example.com/db/subjects/경영학부.json"
+/// );
+/// assert_eq!(
+///     entry_url(manifest_url, &json!({"file": "index.json", "url": "https://cdn.example.com/index.json"})).unwrap(),
+///     "https://cdn.example.com/index.json"
+/// );
+/// assert!(entry_url(manifest_url, &json!({})).is_err());
+/// ```
+///
+/// ## Errors
+/// 항목에 `file`이 없는 경우 오류가 발생한다.
+pub fn entry_url(manifest_url: &str, entry: &Value) -> Result<String, Box<dyn Error>> {
+    if let Some(url) = entry["url"].as_str() {
+        return Ok(url.to_string());
+    }
+    let file = entry["file"]
+        .as_str()
+        .ok_or_else(|| format!("매니페스트 항목에 파일 경로(file)가 없습니다: {}", entry))?;
+    let base = manifest_url
+        .rfind('/')
+        .map_or("", |index| &manifest_url[..=index]);
+    Ok(format!("{}{}", base, file))
+}
+
+/// 매니페스트 항목의 파일 경로를 로컬에 저장할 상대 경로로 검사하는 메서드
+///
+/// ## Examples
+/// ```
+/// use std::path::PathBuf;
+/// use suwon_mate_admin_tool::pull::local_path;
+/// assert_eq!(local_path("subjects/경영학부.json").unwrap(), PathBuf::from("subjects/경영학부.json"));
+/// assert!(local_path("../etc/passwd").is_err());
+/// assert!(local_path("/etc/passwd").is_err());
+/// assert!(local_path("").is_err());
+/// ```
+///
+/// ## Errors
+/// 경로가 비어있거나 절대 경로 또는 상위 디렉토리(`..`)를 포함한 경우 오류가 발생한다.
+pub fn local_path(file: &str) -> Result<PathBuf, Box<dyn Error>> {
+    let path = PathBuf::from(file);
+    let normal = path
+        .components()
+        .all(|component| matches!(component, Component::Normal(_)));
+    if file.is_empty() || !normal {
+        return Err(format!("매니페스트의 파일 경로가 올바르지 않습니다: {}", file).into());
+    }
+    Ok(path)
+}
+
+/// 내려받은 파일의 해시와 크기를 매니페스트 항목과 비교하는 메서드
+///
+/// ## Examples
+/// ```
+/// use serde_json::json;
+/// use suwon_mate_admin_tool::pull::verify_entry;
+/// let entry = json!({
+///     "file": "index.json",
+///     "sha256": "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad",
+///     "size": 3
+/// });
+/// assert!(verify_entry(&entry, b"abc").is_ok());
+/// assert!(verify_entry(&entry, b"abd").is_err());
+/// assert!(verify_entry(&json!({"file": "index.json"}), b"abc").is_err());
+/// ```
+///
+/// ## Errors
+/// 항목에 해시가 없거나 해시 또는 크기가 일치하지 않는 경우 오류가 발생한다.
+pub fn verify_entry(entry: &Value, content: &[u8]) -> Result<(), Box<dyn Error>> {
+    let file = entry["file"].as_str().unwrap_or_default();
+    let sha256 = entry["sha256"]
+        .as_str()
+        .ok_or_else(|| format!("매니페스트에 {}의 해시가 없습니다.", file))?;
+    if entry["size"].as_u64() != Some(content.len() as u64) {
+        return Err(format!("{}의 크기가 매니페스트와 일치하지 않습니다.", file).into());
+    }
+    if !sha256.eq_ignore_ascii_case(&to_hex(&Sha256::digest(content))) {
+        return Err(format!("{}의 해시가 매니페스트와 일치하지 않습니다.", file).into());
+    }
+    Ok(())
+}
+
+/// 매니페스트와 매니페스트에 기록된 파일을 내려받아 `directory`에 배치하는 메서드
+///
+/// 배치한 파일의 수(매니페스트 제외)와 경고 메세지 목록을 반환한다.
+///
+/// ## Arguments
+///
+/// * `manifest_url` - 매니페스트의 URL (`file://` 경로 가능)
+/// * `directory` - 파일을 배치할 디렉토리 (없으면 새로 만든다)
+/// * `options` - 검증 설정
+///
+/// ## Examples
+/// ```
+/// use serde_json::json;
+/// use suwon_mate_admin_tool::pull::{pull, PullOptions};
+/// use suwon_mate_admin_tool::split::write_split;
+/// let db = json!({"estbLectDtaiList": {"경영학부": [{"subjtCd": "1"}]}, "version": {"db_ver": "1.0"}});
+/// let source = std::env::temp_dir().join("suwon_mate_pull_doctest_source");
+/// let target = std::env::temp_dir().join("suwon_mate_pull_doctest_target");
+/// write_split(&db, &source, None).unwrap();
+/// let manifest_url = format!("file://{}/manifest.json", source.display());
+/// let options = PullOptions { verify: true, ..Default::default() };
+/// let (count, _) = pull(&manifest_url, &target, &options).unwrap();
+/// assert_eq!(count, 2);
+/// assert!(target.join("subjects/경영학부.json").exists());
+///
+/// std::fs::write(source.join("index.json"), "{}").unwrap();
+/// assert!(pull(&manifest_url, &target, &options).is_err());
+/// ```
+///
+/// ## Errors
+/// * 매니페스트나 파일을 내려받지 못한 경우
+/// * 매니페스트의 형식이 올바르지 않거나 파일 경로가 안전하지 않은 경우
+/// * 검증을 요청하였으나 해시, 크기 또는 서명이 일치하지 않는 경우
+/// * 파일을 기록하는 과정에서 문제가 발생한 경우
+pub fn pull(
+    manifest_url: &str,
+    directory: &Path,
+    options: &PullOptions,
+) -> Result<(usize, Vec<String>), Box<dyn Error>> {
+    let manifest_content = fetch(manifest_url)?;
+    let manifest: Value = serde_json::from_slice(&manifest_content)?;
+    let mut warnings = vec![];
+    if options.verify {
+        match &options.public_key {
+            Some(key) => verify_db(&manifest, key)?,
+            None if manifest["version"]["signature"].is_object() => warnings.push(String::from(
+                "매니페스트가 서명되어 있지만 공개키가 지정되지 않아 서명을 확인하지 않았습니다.",
+            )),
+            None => warnings.push(String::from(
+                "매니페스트가 서명되어 있지 않아 해시와 크기만 확인하였습니다.",
+            )),
+        }
+    }
+    let entries = std::iter::once(&manifest["index"]).chain(
+        manifest["departments"]
+            .as_object()
+            .ok_or("매니페스트에 학부별 파일 목록(departments)이 없습니다.")?
+            .values(),
+    );
+    let mut files = vec![];
+    for entry in entries {
+        let path = local_path(entry["file"].as_str().unwrap_or_default())?;
+        let content = fetch(&entry_url(manifest_url, entry)?)?;
+        if options.verify {
+            verify_entry(entry, &content)?;
+        }
+        files.push((path, content));
+    }
+    fs::create_dir_all(directory)?;
+    for (path, content) in files.iter() {
+        let path = directory.join(path);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, content)?;
+    }
+    fs::write(directory.join(MANIFEST_FILE), &manifest_content)?;
+    Ok((files.len(), warnings))
+}
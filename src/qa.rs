@@ -0,0 +1,150 @@
+//! # 무작위 샘플 검수
+//!
+//! 빌드가 끝난 DB에서 학부별로 무작위 과목을 뽑아 사람이 원본 자료와 대조할 수 있는 검수 시트(CSV)를 만든다.
+use std::error::Error;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+use serde_json::Value;
+
+use crate::output::csv::{to_cell, CSV_COLUMNS, UTF8_BOM};
+use crate::output::subject_lists;
+use crate::subject_key;
+
+/// 검수 시트의 파일명에서 결과 파일명 뒤에 붙는 부분
+pub const QA_SAMPLE_SUFFIX: &str = "_qa_sample.csv";
+
+/// 검수자가 채워 넣는 열의 머리글
+pub const REVIEW_COLUMNS: [&str; 2] = ["확인 결과", "비고"];
+
+/// 검수 샘플 추출에 사용하는 간단한 의사 난수 생성기 (SplitMix64)
+///
+/// 같은 시드로 만든 생성기는 항상 같은 순서의 값을 만든다.
+///
+/// ## Examples
+/// ```
+/// use suwon_mate_admin_tool::qa::SampleRng;
+/// let mut left = SampleRng::new(42);
+/// let mut right = SampleRng::new(42);
+/// assert_eq!(left.next_u64(), right.next_u64());
+/// assert!(left.below(10) < 10);
+/// ```
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct SampleRng(u64);
+
+impl SampleRng {
+    /// `seed`로 생성기를 만드는 메서드
+    pub fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    /// 다음 난수를 만드는 메서드
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut value = self.0;
+        value = (value ^ (value >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        value = (value ^ (value >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        value ^ (value >> 31)
+    }
+
+    /// `0` 이상 `bound` 미만의 난수를 만드는 메서드
+    pub fn below(&mut self, bound: usize) -> usize {
+        (self.next_u64() % bound as u64) as usize
+    }
+}
+
+/// 학부별로 최대 `count`개의 과목을 무작위로 뽑는 메서드
+///
+/// 과목 수가 `count`보다 적은 학부는 모든 과목이 포함된다. 뽑힌 과목은 학부 안에서 원래 순서를 유지한다.
+///
+/// ## Arguments
+///
+/// * `db` - 샘플을 뽑을 DB의 내용
+/// * `count` - 학부별로 뽑을 과목의 수
+/// * `rng` - 난수 생성기
+///
+/// ## Examples
+/// ```
+/// use serde_json::json;
+/// use suwon_mate_admin_tool::qa::{qa_sample, SampleRng};
+/// let db = json!({"estbLectDtaiList": {
+///     "경영학부": [{"subjtCd": "1"}, {"subjtCd": "2"}, {"subjtCd": "3"}],
+///     "컴퓨터학부": [{"subjtCd": "4"}]
+/// }});
+/// let sample = qa_sample(&db, 2, &mut SampleRng::new(1));
+/// assert_eq!(sample.len(), 3);
+/// assert_eq!(sample.iter().filter(|(department, _)| *department == "경영학부").count(), 2);
+/// ```
+pub fn qa_sample<'a>(
+    db: &'a Value,
+    count: usize,
+    rng: &mut SampleRng,
+) -> Vec<(&'a str, &'a Value)> {
+    let mut sample = vec![];
+    for (department, subjects) in subject_lists(db).into_iter().flatten() {
+        let subjects = subjects.as_array().map(Vec::as_slice).unwrap_or_default();
+        let mut indexes: Vec<usize> = (0..subjects.len()).collect();
+        let picked = count.min(indexes.len());
+        for index in 0..picked {
+            let swap = index + rng.below(indexes.len() - index);
+            indexes.swap(index, swap);
+        }
+        indexes.truncate(picked);
+        indexes.sort_unstable();
+        sample.extend(
+            indexes
+                .into_iter()
+                .map(|index| (department.as_str(), &subjects[index])),
+        );
+    }
+    sample
+}
+
+/// 학부별 무작위 샘플을 검수 시트(CSV)로 저장하는 메서드
+///
+/// 각 행에는 과목 키와 [CSV_COLUMNS]의 항목이 기록되고, 검수자가 채울 [REVIEW_COLUMNS] 열은 비워둔다.
+///
+/// ## Arguments
+///
+/// * `db` - 샘플을 뽑을 DB의 내용
+/// * `path` - 검수 시트를 저장할 경로
+/// * `count` - 학부별로 뽑을 과목의 수
+/// * `seed` - 난수 생성기의 시드
+/// * `bom` - 파일 앞에 UTF-8 BOM을 기록할지 여부
+///
+/// ## Errors
+/// CSV 파일을 만들거나 쓰는 과정에서 문제가 발생한 경우 오류가 발생한다.
+pub fn write_qa_sample(
+    db: &Value,
+    path: &Path,
+    count: usize,
+    seed: u64,
+    bom: bool,
+) -> Result<(), Box<dyn Error>> {
+    let mut writer = BufWriter::new(File::create(path)?);
+    if bom {
+        writer.write_all(UTF8_BOM)?;
+    }
+    let mut writer = ::csv::Writer::from_writer(writer);
+    writer.write_record(
+        ["과목 키"]
+            .into_iter()
+            .chain(CSV_COLUMNS.iter().map(|(header, _)| *header))
+            .chain(REVIEW_COLUMNS),
+    )?;
+    for (_, subject) in qa_sample(db, count, &mut SampleRng::new(seed)) {
+        writer.write_record(
+            [subject_key(subject)]
+                .into_iter()
+                .chain(
+                    CSV_COLUMNS
+                        .iter()
+                        .map(|(_, field)| to_cell(&subject[field])),
+                )
+                .chain(REVIEW_COLUMNS.iter().map(|_| String::new())),
+        )?;
+    }
+    writer.flush()?;
+    Ok(())
+}
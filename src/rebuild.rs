@@ -0,0 +1,135 @@
+//! # 동일 입력 재빌드 감지
+//!
+//! 입력 파일의 내용과 빌드 옵션으로 빌드 지문(fingerprint)을 만들어 직전 빌드의 지문과 비교한다.
+//! 스케줄 빌드에서 입력이 바뀌지 않았는데도 DB 버전만 올라가는 것을 막기 위해 사용한다.
+//!
+//! 빌드 정보는 결과 파일과 같은 디렉토리의 [BUILD_STATE_FILE]에 출력 키별로 기록한다. 출력 키는 DB 버전 등의 자리 표시자를
+//! 치환하기 전의 결과 파일명 템플릿이므로, DB 버전이 바뀌어 결과 파일명이 달라져도 같은 출력의 직전 빌드로 인식한다.
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde_json::{json, Map, Value};
+use sha2::{Digest, Sha256};
+
+use crate::signature::to_hex;
+
+/// 직전 빌드의 정보를 기록하는 파일 이름
+pub const BUILD_STATE_FILE: &str = ".suwon_mate_build_state.json";
+
+/// 결과 파일과 같은 디렉토리에 있는 빌드 정보 파일의 경로를 구하는 메서드
+///
+/// ## Examples
+/// ```
+/// use std::path::Path;
+/// use suwon_mate_admin_tool::rebuild::state_file_for;
+/// assert_eq!(state_file_for(Path::new("out/result_1.0.json")), Path::new("out/.suwon_mate_build_state.json"));
+/// assert_eq!(state_file_for(Path::new("result_1.0.json")), Path::new(".suwon_mate_build_state.json"));
+/// ```
+pub fn state_file_for(result_file: &Path) -> PathBuf {
+    result_file
+        .parent()
+        .unwrap_or(Path::new(""))
+        .join(BUILD_STATE_FILE)
+}
+
+/// 해독된 입력, 추가 입력 파일의 내용과 빌드 옵션으로 빌드 지문을 만드는 메서드
+///
+/// 해독된 입력은 문자열로 만들어 두지 않고 직렬화하면서 바로 해시한다.
+/// 추가 입력 파일 사이의 경계가 섞이지 않도록 각 파일의 길이를 함께 해시한다.
+///
+/// ## Examples
+/// ```
+/// use serde_json::json;
+/// use suwon_mate_admin_tool::rebuild::build_fingerprint;
+/// let input = json!({"estbLectDtaiList": []});
+/// let options = json!({"app_version": "1.0"});
+/// let fingerprint = build_fingerprint(&[&input], &[b"ab", b"c"], &options);
+/// assert_eq!(fingerprint, build_fingerprint(&[&input], &[b"ab", b"c"], &options));
+/// assert_ne!(fingerprint, build_fingerprint(&[&input], &[b"a", b"bc"], &options));
+/// assert_ne!(fingerprint, build_fingerprint(&[&json!({})], &[b"ab", b"c"], &options));
+/// assert_ne!(fingerprint, build_fingerprint(&[&input], &[b"ab", b"c"], &json!({"app_version": "1.1"})));
+/// ```
+pub fn build_fingerprint(inputs: &[&Value], files: &[&[u8]], options: &Value) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(options.to_string().as_bytes());
+    for input in inputs {
+        // 메모리에 쓰는 것과 같으므로 직렬화는 실패하지 않는다.
+        let _ = serde_json::to_writer(&mut hasher, input);
+    }
+    for file in files {
+        hasher.update((file.len() as u64).to_le_bytes());
+        hasher.update(file);
+    }
+    to_hex(&hasher.finalize())
+}
+
+/// 빌드 정보 파일의 내용을 읽는 메서드 (파일이 없거나 해독할 수 없으면 빈 객체)
+fn read_build_states(state_file: &Path) -> Map<String, Value> {
+    fs::read_to_string(state_file)
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+/// 같은 출력의 직전 빌드와 지문이 같은 경우 직전 빌드의 결과 파일 경로를 반환하는 메서드
+///
+/// 직전 빌드의 정보가 없거나, 지문이 다르거나, 결과 파일이 지워진 경우 `None`을 반환한다.
+///
+/// ## Arguments
+///
+/// * `state_file` - 직전 빌드의 정보를 기록한 파일
+/// * `output_key` - 자리 표시자를 치환하기 전의 결과 파일명 템플릿
+/// * `fingerprint` - 이번 빌드의 지문
+pub fn unchanged_result(state_file: &Path, output_key: &str, fingerprint: &str) -> Option<PathBuf> {
+    let states = read_build_states(state_file);
+    let state = states.get(output_key)?;
+    if state["fingerprint"].as_str()? != fingerprint {
+        return None;
+    }
+    Some(PathBuf::from(state["result_file"].as_str()?)).filter(|path| path.is_file())
+}
+
+/// 이번 빌드의 정보를 `output_key` 항목에 기록하는 메서드
+///
+/// 다른 출력 키의 빌드 정보는 그대로 유지한다.
+///
+/// ## Examples
+/// ```
+/// use std::path::Path;
+/// use suwon_mate_admin_tool::rebuild::{save_build_state, unchanged_result};
+/// let directory = std::env::temp_dir().join("suwon_mate_rebuild_doctest");
+/// let _ = std::fs::remove_dir_all(&directory);
+/// std::fs::create_dir_all(&directory).unwrap();
+/// let result_file = directory.join("result_1.0.json");
+/// std::fs::write(&result_file, "{}").unwrap();
+/// let db_file = directory.join("db_1.0.sqlite");
+/// std::fs::write(&db_file, "").unwrap();
+/// let state_file = directory.join("state.json");
+/// save_build_state(&state_file, "result_{db_version}.json", "abcd", &result_file).unwrap();
+/// save_build_state(&state_file, "db_{db_version}.sqlite", "ef01", &db_file).unwrap();
+/// assert_eq!(unchanged_result(&state_file, "result_{db_version}.json", "abcd"), Some(result_file));
+/// assert_eq!(unchanged_result(&state_file, "result_{db_version}.json", "ef01"), None);
+/// assert_eq!(unchanged_result(&state_file, "db_{db_version}.sqlite", "ef01"), Some(db_file));
+/// ```
+///
+/// ## Errors
+/// 파일을 쓰는 과정에서 문제가 발생한 경우 오류가 발생한다.
+pub fn save_build_state(
+    state_file: &Path,
+    output_key: &str,
+    fingerprint: &str,
+    result_file: &Path,
+) -> Result<(), Box<dyn Error>> {
+    let mut states = read_build_states(state_file);
+    states.retain(|_, state| state.is_object());
+    states.insert(
+        output_key.to_string(),
+        json!({
+            "fingerprint": fingerprint,
+            "result_file": result_file.to_string_lossy(),
+        }),
+    );
+    fs::write(state_file, Value::Object(states).to_string())?;
+    Ok(())
+}
@@ -0,0 +1,85 @@
+//! # 기준 출력 회귀 검사
+//!
+//! 리팩터링 후에도 기준 입력에 대해 이전과 같은 DB가 만들어지는지 기준 출력(golden) 파일과 비교하여 확인한다.
+//!
+//! 비교는 의미론적으로 진행한다. 학과 목록과 학부별 과목 목록은 순서를 무시하며(과목은 과목 키 순서로 정렬하여 비교),
+//! 빌드 시각 등 빌드마다 달라지는 `version` 항목은 비교하지 않는다.
+use serde_json::Value;
+
+use crate::shadow::diff_db;
+use crate::{subject_key, BuildOptions};
+
+/// 화면에 출력할 다른 위치의 최대 개수
+pub const MAX_REPORTED_DIFFERENCES: usize = 20;
+
+/// 비교 전에 학부별 과목 목록을 과목 키 순서로 정렬한 사본을 만드는 메서드
+fn sort_subjects(db: &Value) -> Value {
+    let mut db = db.clone();
+    for key in ["estbLectDtaiList", "estbLectDtaiList_quick"] {
+        for subjects in db[key]
+            .as_object_mut()
+            .into_iter()
+            .flat_map(|map| map.values_mut())
+        {
+            if let Some(subjects) = subjects.as_array_mut() {
+                subjects.sort_by_cached_key(|subject| (subject_key(subject), subject.to_string()));
+            }
+        }
+    }
+    db
+}
+
+/// 기준 출력과 현재 결과가 다른 위치(JSON Pointer) 목록을 구하는 메서드
+///
+/// ## Examples
+/// ```
+/// use serde_json::json;
+/// use suwon_mate_admin_tool::regress::regress_differences;
+/// let golden = json!({
+///     "estbLectDtaiList": {"경영학부": [{"subjtCd": "1", "diclNo": "001"}, {"subjtCd": "2", "diclNo": "001"}]},
+///     "version": {"db_ver": "1.0", "built_at": "2024-03-01T09:00:00+09:00"}
+/// });
+/// let current = json!({
+///     "estbLectDtaiList": {"경영학부": [{"subjtCd": "2", "diclNo": "001"}, {"subjtCd": "1", "diclNo": "001"}]},
+///     "version": {"db_ver": "1.0"}
+/// });
+/// assert!(regress_differences(&golden, &current).is_empty());
+///
+/// let current = json!({"estbLectDtaiList": {"경영학부": [{"subjtCd": "1", "diclNo": "002"}]}});
+/// assert_eq!(regress_differences(&golden, &current), vec!["/estbLectDtaiList/경영학부"]);
+/// ```
+pub fn regress_differences(golden: &Value, current: &Value) -> Vec<String> {
+    diff_db(&sort_subjects(golden), &sort_subjects(current))
+        .into_iter()
+        .filter(|path| path != "/version" && !path.starts_with("/version/"))
+        .collect()
+}
+
+/// 기준 출력과 같은 버전 정보로 DB를 만들 수 있도록 기준 출력의 `version` 항목을 [BuildOptions]에 옮기는 메서드
+///
+/// 기준 출력에 없는 항목은 기본값을 사용한다.
+///
+/// ## Examples
+/// ```
+/// use serde_json::json;
+/// use suwon_mate_admin_tool::regress::golden_options;
+/// let options = golden_options(&json!({"version": {"app_ver": "2.0", "db_ver": "3.0"}}), false);
+/// assert_eq!(options.latest_app_version, "2.0");
+/// assert_eq!(options.db_version, "3.0");
+/// assert_eq!(options.legacy_app_version, "1.0");
+/// ```
+pub fn golden_options(golden: &Value, auto_fuzzy_merge: bool) -> BuildOptions {
+    let defaults = BuildOptions::default();
+    let version = |key: &str, default: String| {
+        golden["version"][key]
+            .as_str()
+            .map_or(default, str::to_string)
+    };
+    BuildOptions {
+        latest_app_version: version("app_ver", defaults.latest_app_version),
+        db_version: version("db_ver", defaults.db_version),
+        legacy_app_version: version("legacy_app_ver", defaults.legacy_app_version),
+        auto_fuzzy_merge,
+        ..defaults
+    }
+}
@@ -0,0 +1,174 @@
+//! # 원격 설정 DB 버전 동기화
+//!
+//! 빌드가 끝난 뒤 앱이 새 DB를 바로 인지할 수 있도록 원격 설정의 DB 버전 값을 갱신한다.
+//!
+//! 갱신 대상은 다음 두 가지 중 하나로 지정한다.
+//!
+//! * `firebase:<프로젝트 ID>` - Firebase Remote Config 템플릿의 매개변수 기본값을 갱신한다.
+//!   템플릿을 내려받아 매개변수만 바꾼 뒤 ETag를 `If-Match`로 보내 다른 곳에서 동시에 수정한 내용을 덮어쓰지 않는다.
+//! * `http://` 또는 `https://` URL - `{"<매개변수 이름>": "<DB 버전>"}`을 JSON 본문으로 POST 요청한다.
+//!
+//! 인증 토큰은 [TOKEN_ENV] 환경 변수에서 먼저 찾고, 없으면 키링의 [TOKEN_ENTRY] 항목에서 읽는다.
+use std::error::Error;
+
+use serde_json::{json, Value};
+
+use crate::network::agent;
+use crate::secret::keyring_secret;
+
+/// DB 버전을 기록할 기본 매개변수 이름
+pub const DEFAULT_PARAMETER: &str = "db_version";
+/// 원격 설정 인증 토큰을 담은 환경 변수
+pub const TOKEN_ENV: &str = "REMOTE_CONFIG_TOKEN";
+/// 원격 설정 인증 토큰이 저장되는 키링 항목 이름
+pub const TOKEN_ENTRY: &str = "remote-config-token";
+/// Firebase Remote Config REST API의 주소
+const FIREBASE_REMOTE_CONFIG_URL: &str = "https://firebaseremoteconfig.googleapis.com/v1/projects";
+
+/// 원격 설정 갱신 대상
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum RemoteConfigTarget {
+    /// Firebase Remote Config (프로젝트 ID)
+    Firebase(String),
+    /// JSON 본문을 POST로 받는 API (URL)
+    Api(String),
+}
+
+impl RemoteConfigTarget {
+    /// `firebase:<프로젝트 ID>` 또는 URL 형식의 문자열에서 갱신 대상을 구하는 메서드
+    ///
+    /// ## Examples
+    /// ```
+    /// use suwon_mate_admin_tool::remote_config::RemoteConfigTarget;
+    /// assert_eq!(
+    ///     RemoteConfigTarget::parse("firebase:suwon-mate").unwrap(),
+    ///     RemoteConfigTarget::Firebase("suwon-mate".into())
+    /// );
+    /// assert_eq!(
+    ///     RemoteConfigTarget::parse("https://example.com/config").unwrap(),
+    ///     RemoteConfigTarget::Api("https://example.com/config".into())
+    /// );
+    /// assert!(RemoteConfigTarget::parse("firebase:").is_err());
+    /// assert!(RemoteConfigTarget::parse("example.com").is_err());
+    /// ```
+    ///
+    /// ## Errors
+    /// 프로젝트 ID가 비어있거나 형식을 알 수 없는 경우 오류가 발생한다.
+    pub fn parse(target: &str) -> Result<Self, Box<dyn Error>> {
+        if let Some(project) = target.strip_prefix("firebase:") {
+            if project.is_empty() || project.contains('/') {
+                return Err(format!("올바르지 않은 Firebase 프로젝트 ID입니다: {}", target).into());
+            }
+            return Ok(RemoteConfigTarget::Firebase(project.to_string()));
+        }
+        if target.starts_with("http://") || target.starts_with("https://") {
+            return Ok(RemoteConfigTarget::Api(target.to_string()));
+        }
+        Err(format!(
+            "원격 설정 대상은 firebase:<프로젝트 ID> 또는 URL이어야 합니다: {}",
+            target
+        )
+        .into())
+    }
+}
+
+/// Remote Config 템플릿에서 `parameter` 매개변수의 기본값을 `value`로 바꾸는 메서드
+///
+/// 매개변수가 없으면 문자열 매개변수로 새로 만들며, 조건별 값(`conditionalValues`)은 그대로 둔다.
+/// 기본값이 이미 `value`였는지 여부를 반환한다.
+///
+/// ## Examples
+/// ```
+/// use serde_json::json;
+/// use suwon_mate_admin_tool::remote_config::set_parameter;
+/// let mut template = json!({"parameters": {"app_version": {"defaultValue": {"value": "1.0"}}}});
+/// assert!(!set_parameter(&mut template, "db_version", "1.2"));
+/// assert_eq!(template["parameters"]["db_version"]["defaultValue"]["value"], "1.2");
+/// assert_eq!(template["parameters"]["db_version"]["valueType"], "STRING");
+/// assert!(set_parameter(&mut template, "db_version", "1.2"));
+/// assert_eq!(template["parameters"]["app_version"]["defaultValue"]["value"], "1.0");
+/// ```
+pub fn set_parameter(template: &mut Value, parameter: &str, value: &str) -> bool {
+    if !template["parameters"].is_object() {
+        template["parameters"] = json!({});
+    }
+    let entry = &mut template["parameters"][parameter];
+    if entry.is_null() {
+        *entry = json!({"valueType": "STRING"});
+    }
+    let unchanged = entry["defaultValue"]["value"] == value;
+    entry["defaultValue"] = json!({ "value": value });
+    unchanged
+}
+
+/// 원격 설정 인증 토큰을 구하는 메서드
+///
+/// ## Errors
+/// 환경 변수가 없고 키링에서도 토큰을 읽을 수 없는 경우 오류가 발생한다.
+pub fn access_token() -> Result<String, Box<dyn Error>> {
+    match std::env::var(TOKEN_ENV) {
+        Ok(token) if !token.trim().is_empty() => Ok(token.trim().to_string()),
+        _ => keyring_secret(TOKEN_ENTRY).map_err(|error| {
+            format!(
+                "원격 설정 인증 토큰이 없습니다. {} 환경 변수를 지정하거나 `secret set {}`으로 저장해주세요. ({})",
+                TOKEN_ENV, TOKEN_ENTRY, error
+            )
+            .into()
+        }),
+    }
+}
+
+/// 원격 설정의 `parameter` 값을 `db_version`으로 갱신하는 메서드
+///
+/// Firebase Remote Config의 기본값이 이미 `db_version`이면 템플릿을 다시 게시하지 않는다.
+/// 값을 갱신하였는지 여부를 반환한다.
+///
+/// ## Errors
+/// * 인증 토큰을 구할 수 없는 경우 ([RemoteConfigTarget::Firebase]만 해당)
+/// * 요청에 실패하였거나 응답의 JSON 해독이 불가능한 경우
+pub fn sync_db_version(
+    target: &RemoteConfigTarget,
+    parameter: &str,
+    db_version: &str,
+) -> Result<bool, Box<dyn Error>> {
+    match target {
+        RemoteConfigTarget::Firebase(project) => {
+            let url = format!("{}/{}/remoteConfig", FIREBASE_REMOTE_CONFIG_URL, project);
+            let authorization = format!("Bearer {}", access_token()?);
+            let response = agent()
+                .get(&url)
+                .set("Authorization", &authorization)
+                .call()
+                .map_err(|error| {
+                    format!("Remote Config 템플릿을 가져오지 못하였습니다: {}", error)
+                })?;
+            let etag = response
+                .header("ETag")
+                .ok_or("Remote Config 응답에 ETag가 없습니다.")?
+                .to_string();
+            let mut template: Value = serde_json::from_reader(response.into_reader())?;
+            if set_parameter(&mut template, parameter, db_version) {
+                return Ok(false);
+            }
+            agent()
+                .put(&url)
+                .set("Authorization", &authorization)
+                .set("Content-Type", "application/json; UTF-8")
+                .set("If-Match", &etag)
+                .send_string(&template.to_string())
+                .map_err(|error| {
+                    format!("Remote Config 템플릿을 게시하지 못하였습니다: {}", error)
+                })?;
+        }
+        RemoteConfigTarget::Api(url) => {
+            let mut request = agent().post(url).set("Content-Type", "application/json");
+            if let Ok(token) = access_token() {
+                request = request.set("Authorization", &format!("Bearer {}", token));
+            }
+            request
+                .send_string(&json!({ parameter: db_version }).to_string())
+                .map_err(|error| format!("{}에 DB 버전을 갱신하지 못하였습니다: {}", url, error))?;
+        }
+    }
+    Ok(true)
+}
@@ -0,0 +1,138 @@
+//! # 빌드 리포트
+//!
+//! DB 제작 결과를 GitHub 이슈 등에 그대로 붙여넣을 수 있는 Markdown 문서로 정리한다.
+//! 리포트에는 학부별 과목 수, 분류에 실패한 학부 목록, 연락처가 누락된 교수 목록이 포함된다.
+use std::collections::BTreeMap;
+
+use serde_json::Value;
+
+use crate::output::subject_lists;
+use crate::BuildSummary;
+
+/// Markdown 표의 셀에 들어갈 문자열을 이스케이프하는 메서드
+///
+/// 빈 문자열은 `(빈 값)`으로 표시한다.
+///
+/// ## Examples
+/// ```
+/// use suwon_mate_admin_tool::report::escape_cell;
+/// assert_eq!(escape_cell("a|b\nc"), "a\\|b c");
+/// assert_eq!(escape_cell(""), "(빈 값)");
+/// ```
+pub fn escape_cell(text: &str) -> String {
+    if text.trim().is_empty() {
+        return String::from("(빈 값)");
+    }
+    text.replace('|', "\\|").replace(['\r', '\n'], " ")
+}
+
+/// 연락처가 하나도 기록되지 않은 교수를 학부별로 찾는 메서드
+///
+/// 이메일과 휴대전화 번호가 모두 비어있는 경우 연락처가 누락된 것으로 본다.
+///
+/// ## Examples
+/// ```
+/// use serde_json::json;
+/// use suwon_mate_admin_tool::report::missing_contacts;
+/// let db = json!({"contacts": {"경영학부": {
+///     "홍길동": {"email": null, "mpno": ""},
+///     "김철수": {"email": "kim@suwon.ac.kr", "mpno": null}
+/// }}});
+/// assert_eq!(missing_contacts(&db), vec![(String::from("경영학부"), String::from("홍길동"))]);
+/// ```
+pub fn missing_contacts(db: &Value) -> Vec<(String, String)> {
+    let is_empty = |value: &Value| value.as_str().is_none_or(|text| text.trim().is_empty());
+    db["contacts"]
+        .as_object()
+        .into_iter()
+        .flatten()
+        .flat_map(|(department, contacts)| {
+            contacts
+                .as_object()
+                .into_iter()
+                .flatten()
+                .filter(|(_, contact)| is_empty(&contact["email"]) && is_empty(&contact["mpno"]))
+                .map(move |(name, _)| (department.clone(), name.clone()))
+        })
+        .collect()
+}
+
+/// DB와 제작 결과 요약으로 Markdown 리포트를 만드는 메서드
+///
+/// ## Arguments
+///
+/// * `db` - 제작된 DB의 내용
+/// * `summary` - DB 제작 결과 요약
+///
+/// ## Examples
+/// ```
+/// use serde_json::json;
+/// use suwon_mate_admin_tool::report::build_report;
+/// use suwon_mate_admin_tool::BuildSummary;
+/// let db = json!({
+///     "estbLectDtaiList": {"경영학부": [{"subjtCd": "1"}, {"subjtCd": "2"}]},
+///     "contacts": {"경영학부": {"홍길동": {"email": null, "mpno": null}}},
+///     "version": {"db_ver": "1.0", "app_ver": "1.0"}
+/// });
+/// let summary = BuildSummary {
+///     subject_count: 2,
+///     unclassified_departments: vec![String::from("교양대학"), String::from("교양대학")],
+///     ..Default::default()
+/// };
+/// let report = build_report(&db, &summary);
+/// assert!(report.contains("| 경영학부 | 2 |"));
+/// assert!(report.contains("| 교양대학 | 2 |"));
+/// assert!(report.contains("| 경영학부 | 홍길동 |"));
+/// ```
+pub fn build_report(db: &Value, summary: &BuildSummary) -> String {
+    let mut report = format!(
+        "# 수원 메이트 DB 빌드 리포트\n\n\
+         - DB 버전: {}\n\
+         - 앱 버전: {}\n\
+         - 과목 수: {}\n\
+         - 경고 수: {}\n",
+        db["version"]["db_ver"].as_str().unwrap_or_default(),
+        db["version"]["app_ver"].as_str().unwrap_or_default(),
+        summary.subject_count,
+        summary.warnings.len()
+    );
+
+    report.push_str("\n## 학부별 과목 수\n\n| 학부 | 과목 수 |\n| --- | ---: |\n");
+    for (department, subjects) in subject_lists(db).into_iter().flatten() {
+        report.push_str(&format!(
+            "| {} | {} |\n",
+            escape_cell(department),
+            subjects.as_array().map_or(0, Vec::len)
+        ));
+    }
+
+    report.push_str("\n## 분류 실패 목록\n\n");
+    let mut unclassified: BTreeMap<&str, usize> = BTreeMap::new();
+    for department in summary.unclassified_departments.iter() {
+        *unclassified.entry(department).or_default() += 1;
+    }
+    if unclassified.is_empty() {
+        report.push_str("분류에 실패한 과목이 없습니다.\n");
+    } else {
+        report.push_str("| 학부 | 과목 수 |\n| --- | ---: |\n");
+        for (department, count) in unclassified {
+            report.push_str(&format!("| {} | {} |\n", escape_cell(department), count));
+        }
+    }
+
+    report.push_str("\n## 연락처 누락 목록\n\n");
+    let missing = missing_contacts(db);
+    if missing.is_empty() {
+        report.push_str("연락처가 누락된 교수가 없습니다.\n");
+    } else {
+        report.push_str("| 학부 | 교수 |\n| --- | --- |\n");
+        for (department, name) in missing {
+            report.push_str(&format!(
+                "| {} | {} |\n",
+                escape_cell(&department),
+                escape_cell(&name)
+            ));
+        }
+    }
+    report
+}
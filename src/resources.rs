@@ -0,0 +1,89 @@
+//! # 강의계획서 첨부 자료 수집
+//!
+//! 강의계획서 DB 파일의 과목 정보에 포함된 첨부파일 및 URL 항목을 모아 DB의 `resources` 섹션에 과목 키별로 기록한다.
+use std::collections::HashSet;
+
+use serde_json::{json, Map, Value};
+
+use crate::output::subjects;
+use crate::subject_key;
+
+/// 첨부 자료 항목으로 취급하는 필드 이름의 일부 (대소문자 구분 없음)
+pub const RESOURCE_FIELD_HINTS: [&str; 4] = ["url", "link", "atch", "file"];
+
+/// 과목 정보의 필드가 첨부 자료인지 확인하는 메서드
+///
+/// 값이 비어 있지 않은 문자열이면서 필드 이름에 [RESOURCE_FIELD_HINTS] 중 하나가 포함되어 있거나
+/// 값이 `http://` 또는 `https://`로 시작하는 경우 첨부 자료로 취급한다.
+///
+/// ## Examples
+/// ```
+/// use serde_json::json;
+/// use suwon_mate_admin_tool::resources::is_resource;
+/// assert!(is_resource("atchFileNm", &json!("강의계획서.pdf")));
+/// assert!(is_resource("etc", &json!("https://example.com")));
+/// assert!(!is_resource("lssnUrl", &json!("")));
+/// assert!(!is_resource("subjtNm", &json!("회계원리")));
+/// ```
+pub fn is_resource(field: &str, value: &Value) -> bool {
+    let Some(value) = value
+        .as_str()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+    else {
+        return false;
+    };
+    let field = field.to_lowercase();
+    RESOURCE_FIELD_HINTS.iter().any(|hint| field.contains(hint))
+        || value.starts_with("http://")
+        || value.starts_with("https://")
+}
+
+/// 과목 키별 첨부 자료 목록을 만드는 메서드
+///
+/// DB에 기록된 과목에 대해서만 수집하며, 첨부 자료가 없는 과목은 목록에 포함되지 않는다.
+/// 각 첨부 자료는 원래 필드 이름(`field`)과 값(`value`)으로 기록된다.
+///
+/// ## Arguments
+///
+/// * `db` - 첨부 자료를 수집할 과목이 기록된 DB의 내용
+/// * `class_todo` - 강의계획서 DB 파일의 내용
+///
+/// ## Examples
+/// ```
+/// use serde_json::json;
+/// use suwon_mate_admin_tool::resources::collect_resources;
+/// let db = json!({"estbLectDtaiList": {"경영학부": [{"subjtCd": "1", "diclNo": "001"}]}});
+/// let class_todo = json!({"estbLectDtaiList": [
+///     {"subjtCd": "1", "diclNo": "001", "lssnUrl": "https://example.com/1"},
+///     {"subjtCd": "2", "diclNo": "001", "lssnUrl": "https://example.com/2"}
+/// ]});
+/// let resources = collect_resources(&db, &class_todo);
+/// assert_eq!(resources["1-001"], json!([{"field": "lssnUrl", "value": "https://example.com/1"}]));
+/// assert!(resources.get("2-001").is_none());
+/// ```
+pub fn collect_resources(db: &Value, class_todo: &Value) -> Value {
+    let keys: HashSet<String> = subjects(db).map(subject_key).collect();
+    let mut resources = Map::new();
+    for todo in class_todo["estbLectDtaiList"]
+        .as_array()
+        .into_iter()
+        .flatten()
+    {
+        let key = subject_key(todo);
+        if resources.contains_key(&key) || !keys.contains(&key) {
+            continue;
+        }
+        let items: Vec<Value> = todo
+            .as_object()
+            .into_iter()
+            .flatten()
+            .filter(|(field, value)| is_resource(field, value))
+            .map(|(field, value)| json!({"field": field, "value": value}))
+            .collect();
+        if !items.is_empty() {
+            resources.insert(key, Value::Array(items));
+        }
+    }
+    Value::Object(resources)
+}
@@ -0,0 +1,366 @@
+//! # 과목 포함/제외 규칙
+//!
+//! `rules.yaml`에 선언한 필드 조건에 따라 빌드된 DB에서 과목을 걸러낸다.
+//!
+//! 규칙 파일은 `include`와 `exclude` 조건식 목록으로 이루어진다. `include`가 비어있지 않으면 하나 이상의 조건을 만족하는
+//! 과목만 남기고, 그 뒤 `exclude` 조건 중 하나라도 만족하는 과목을 제외한다.
+//!
+//! ```yaml
+//! exclude:
+//!   - point == 0 && clsfNm == "교양"
+//!   - subjtNm == null
+//! ```
+//!
+//! 조건식은 `필드 연산자 값` 형태의 비교를 `&&`, `||`, `!`, 괄호로 묶은 것이다.
+//! 연산자는 `==`, `!=`, `<`, `<=`, `>`, `>=`이며 값에는 큰따옴표로 감싼 문자열, 숫자, `null`, `true`, `false`를 사용할 수 있다.
+//! 숫자와 비교하는 경우 숫자로 된 문자열 필드도 숫자로 비교한다.
+use std::cmp::Ordering;
+use std::error::Error;
+
+use serde::Deserialize;
+use serde_json::Value;
+
+/// 비교 연산자
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum CompareOp {
+    /// `==`
+    Eq,
+    /// `!=`
+    Ne,
+    /// `<`
+    Lt,
+    /// `<=`
+    Le,
+    /// `>`
+    Gt,
+    /// `>=`
+    Ge,
+}
+
+/// 조건식
+#[derive(Clone, PartialEq, Debug)]
+pub enum Condition {
+    /// 필드와 값의 비교
+    Compare(String, CompareOp, Value),
+    /// 두 조건을 모두 만족
+    And(Box<Condition>, Box<Condition>),
+    /// 두 조건 중 하나 이상을 만족
+    Or(Box<Condition>, Box<Condition>),
+    /// 조건을 만족하지 않음
+    Not(Box<Condition>),
+}
+
+/// 조건식을 이루는 토큰
+#[derive(Clone, PartialEq, Debug)]
+enum Token {
+    Field(String),
+    Literal(Value),
+    Op(CompareOp),
+    And,
+    Or,
+    Not,
+    Open,
+    Close,
+}
+
+/// 조건식을 토큰으로 나누는 메서드
+fn tokenize(text: &str) -> Result<Vec<Token>, Box<dyn Error>> {
+    let chars: Vec<char> = text.chars().collect();
+    let mut tokens = vec![];
+    let mut index = 0;
+    while index < chars.len() {
+        let current = chars[index];
+        let next = chars.get(index + 1).copied();
+        let (token, length) = match (current, next) {
+            (' ' | '\t', _) => {
+                index += 1;
+                continue;
+            }
+            ('&', Some('&')) => (Token::And, 2),
+            ('|', Some('|')) => (Token::Or, 2),
+            ('=', Some('=')) => (Token::Op(CompareOp::Eq), 2),
+            ('!', Some('=')) => (Token::Op(CompareOp::Ne), 2),
+            ('<', Some('=')) => (Token::Op(CompareOp::Le), 2),
+            ('>', Some('=')) => (Token::Op(CompareOp::Ge), 2),
+            ('<', _) => (Token::Op(CompareOp::Lt), 1),
+            ('>', _) => (Token::Op(CompareOp::Gt), 1),
+            ('!', _) => (Token::Not, 1),
+            ('(', _) => (Token::Open, 1),
+            (')', _) => (Token::Close, 1),
+            ('"', _) => {
+                let mut literal = String::new();
+                let mut end = index + 1;
+                loop {
+                    match chars.get(end) {
+                        None => {
+                            return Err(format!("닫히지 않은 문자열이 있습니다: {}", text).into())
+                        }
+                        Some('"') => break,
+                        Some('\\') if end + 1 < chars.len() => {
+                            literal.push(chars[end + 1]);
+                            end += 2;
+                        }
+                        Some(character) => {
+                            literal.push(*character);
+                            end += 1;
+                        }
+                    }
+                }
+                (Token::Literal(Value::String(literal)), end + 1 - index)
+            }
+            _ if current == '-' || current.is_ascii_digit() => {
+                let length = chars[index + 1..]
+                    .iter()
+                    .take_while(|character| character.is_ascii_digit() || **character == '.')
+                    .count()
+                    + 1;
+                let number: String = chars[index..index + length].iter().collect();
+                let number: f64 = number
+                    .parse()
+                    .map_err(|_| format!("올바르지 않은 숫자입니다: {}", number))?;
+                (Token::Literal(Value::from(number)), length)
+            }
+            _ if current.is_alphabetic() || current == '_' => {
+                let length = chars[index..]
+                    .iter()
+                    .take_while(|character| character.is_alphanumeric() || **character == '_')
+                    .count();
+                let word: String = chars[index..index + length].iter().collect();
+                let token = match word.as_str() {
+                    "null" => Token::Literal(Value::Null),
+                    "true" => Token::Literal(Value::Bool(true)),
+                    "false" => Token::Literal(Value::Bool(false)),
+                    _ => Token::Field(word),
+                };
+                (token, length)
+            }
+            _ => return Err(format!("알 수 없는 문자 '{}'가 있습니다: {}", current, text).into()),
+        };
+        tokens.push(token);
+        index += length;
+    }
+    Ok(tokens)
+}
+
+/// 토큰 목록을 조건식으로 해석하는 파서
+struct Parser {
+    tokens: Vec<Token>,
+    position: usize,
+}
+
+impl Parser {
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.position).cloned();
+        self.position += 1;
+        token
+    }
+
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position)
+    }
+
+    fn or(&mut self) -> Result<Condition, Box<dyn Error>> {
+        let mut condition = self.and()?;
+        while self.peek() == Some(&Token::Or) {
+            self.position += 1;
+            condition = Condition::Or(Box::new(condition), Box::new(self.and()?));
+        }
+        Ok(condition)
+    }
+
+    fn and(&mut self) -> Result<Condition, Box<dyn Error>> {
+        let mut condition = self.unary()?;
+        while self.peek() == Some(&Token::And) {
+            self.position += 1;
+            condition = Condition::And(Box::new(condition), Box::new(self.unary()?));
+        }
+        Ok(condition)
+    }
+
+    fn unary(&mut self) -> Result<Condition, Box<dyn Error>> {
+        match self.next() {
+            Some(Token::Not) => Ok(Condition::Not(Box::new(self.unary()?))),
+            Some(Token::Open) => {
+                let condition = self.or()?;
+                match self.next() {
+                    Some(Token::Close) => Ok(condition),
+                    _ => Err("닫는 괄호가 없습니다.".into()),
+                }
+            }
+            Some(Token::Field(field)) => match (self.next(), self.next()) {
+                (Some(Token::Op(op)), Some(Token::Literal(value))) => {
+                    Ok(Condition::Compare(field, op, value))
+                }
+                _ => Err(format!("{} 뒤에는 비교 연산자와 값이 와야 합니다.", field).into()),
+            },
+            Some(_) => {
+                Err("비교식, !, 여는 괄호 중 하나가 와야 할 자리에 다른 내용이 있습니다.".into())
+            }
+            None => Err("조건식이 중간에 끝났습니다.".into()),
+        }
+    }
+}
+
+/// 필드 값과 규칙의 값을 비교하는 메서드
+///
+/// 규칙의 값이 숫자이면 숫자로 된 문자열 필드도 숫자로 비교하며, 비교할 수 없는 경우 `None`을 반환한다.
+fn compare(field: &Value, literal: &Value) -> Option<Ordering> {
+    match (field, literal) {
+        (Value::String(field), Value::Number(literal)) => field
+            .trim()
+            .parse::<f64>()
+            .ok()?
+            .partial_cmp(&literal.as_f64()?),
+        (Value::Number(field), Value::Number(literal)) => {
+            field.as_f64()?.partial_cmp(&literal.as_f64()?)
+        }
+        (Value::String(field), Value::String(literal)) => Some(field.cmp(literal)),
+        (Value::Bool(field), Value::Bool(literal)) => Some(field.cmp(literal)),
+        (Value::Null, Value::Null) => Some(Ordering::Equal),
+        _ => None,
+    }
+}
+
+impl Condition {
+    /// 조건식 문자열을 해석하는 메서드
+    ///
+    /// ## Examples
+    /// ```
+    /// use serde_json::json;
+    /// use suwon_mate_admin_tool::rules::Condition;
+    /// let condition = Condition::parse("point == 0 && clsfNm == \"교양\"").unwrap();
+    /// assert!(condition.matches(&json!({"point": 0, "clsfNm": "교양"})));
+    /// assert!(!condition.matches(&json!({"point": 3, "clsfNm": "교양"})));
+    /// let condition = Condition::parse("!(point >= 2) || subjtNm == null").unwrap();
+    /// assert!(condition.matches(&json!({"point": "1"})));
+    /// assert!(condition.matches(&json!({"point": 3})));
+    /// assert!(Condition::parse("point ==").is_err());
+    /// ```
+    ///
+    /// ## Errors
+    /// 조건식의 문법이 올바르지 않은 경우 오류가 발생한다.
+    pub fn parse(text: &str) -> Result<Self, Box<dyn Error>> {
+        let mut parser = Parser {
+            tokens: tokenize(text)?,
+            position: 0,
+        };
+        let condition = parser
+            .or()
+            .map_err(|error| format!("조건식을 해석할 수 없습니다. ({}): {}", text, error))?;
+        if parser.position < parser.tokens.len() {
+            return Err(format!("조건식의 끝에 해석할 수 없는 내용이 있습니다: {}", text).into());
+        }
+        Ok(condition)
+    }
+
+    /// 과목이 조건을 만족하는지 확인하는 메서드
+    ///
+    /// 과목에 없는 필드는 `null`로 취급한다.
+    pub fn matches(&self, subject: &Value) -> bool {
+        match self {
+            Condition::Compare(field, op, literal) => {
+                let ordering = compare(&subject[field.as_str()], literal);
+                match op {
+                    CompareOp::Eq => ordering == Some(Ordering::Equal),
+                    CompareOp::Ne => ordering != Some(Ordering::Equal),
+                    CompareOp::Lt => ordering == Some(Ordering::Less),
+                    CompareOp::Le => matches!(ordering, Some(Ordering::Less | Ordering::Equal)),
+                    CompareOp::Gt => ordering == Some(Ordering::Greater),
+                    CompareOp::Ge => {
+                        matches!(ordering, Some(Ordering::Greater | Ordering::Equal))
+                    }
+                }
+            }
+            Condition::And(left, right) => left.matches(subject) && right.matches(subject),
+            Condition::Or(left, right) => left.matches(subject) || right.matches(subject),
+            Condition::Not(condition) => !condition.matches(subject),
+        }
+    }
+}
+
+/// 규칙 파일의 내용
+#[derive(Deserialize)]
+struct RuleFile {
+    #[serde(default)]
+    include: Vec<String>,
+    #[serde(default)]
+    exclude: Vec<String>,
+}
+
+/// 포함/제외 규칙 목록
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct RuleSet {
+    /// 하나 이상 만족해야 포함되는 조건 목록 (비어있으면 모든 과목 포함)
+    pub include: Vec<Condition>,
+    /// 하나라도 만족하면 제외되는 조건 목록
+    pub exclude: Vec<Condition>,
+}
+
+impl RuleSet {
+    /// YAML 형식의 규칙 파일 내용을 해석하는 메서드
+    ///
+    /// ## Errors
+    /// * YAML 해독이 불가능하거나 `include`, `exclude`가 문자열 목록이 아닌 경우
+    /// * 조건식의 문법이 올바르지 않은 경우
+    pub fn from_yaml(content: &str) -> Result<Self, Box<dyn Error>> {
+        let file: RuleFile = serde_yaml::from_str(content)?;
+        let parse = |conditions: Vec<String>| {
+            conditions
+                .iter()
+                .map(|condition| Condition::parse(condition))
+                .collect::<Result<Vec<_>, _>>()
+        };
+        Ok(Self {
+            include: parse(file.include)?,
+            exclude: parse(file.exclude)?,
+        })
+    }
+
+    /// 과목이 규칙에 따라 DB에 남는지 확인하는 메서드
+    pub fn keeps(&self, subject: &Value) -> bool {
+        (self.include.is_empty()
+            || self
+                .include
+                .iter()
+                .any(|condition| condition.matches(subject)))
+            && !self
+                .exclude
+                .iter()
+                .any(|condition| condition.matches(subject))
+    }
+}
+
+/// DB의 과목 목록에 규칙을 적용하고 제외된 과목의 수를 반환하는 메서드
+///
+/// ## Examples
+/// ```
+/// use serde_json::json;
+/// use suwon_mate_admin_tool::rules::{apply_rules, RuleSet};
+/// let rules = RuleSet::from_yaml("exclude:\n  - point == 0 && clsfNm == \"교양\"\n").unwrap();
+/// let mut db = json!({"estbLectDtaiList": {"교양대학": [
+///     {"subjtCd": "1", "point": 0, "clsfNm": "교양"},
+///     {"subjtCd": "2", "point": 3, "clsfNm": "교양"}
+/// ]}});
+/// assert_eq!(apply_rules(&mut db, &rules), 1);
+/// assert_eq!(db["estbLectDtaiList"]["교양대학"], json!([{"subjtCd": "2", "point": 3, "clsfNm": "교양"}]));
+/// ```
+pub fn apply_rules(db: &mut Value, rules: &RuleSet) -> usize {
+    let key = if db.get("estbLectDtaiList").is_some() {
+        "estbLectDtaiList"
+    } else {
+        "estbLectDtaiList_quick"
+    };
+    let mut removed = 0;
+    for subjects in db
+        .get_mut(key)
+        .and_then(Value::as_object_mut)
+        .into_iter()
+        .flat_map(|lists| lists.values_mut())
+        .filter_map(Value::as_array_mut)
+    {
+        let before = subjects.len();
+        subjects.retain(|subject| rules.keeps(subject));
+        removed += before - subjects.len();
+    }
+    removed
+}
@@ -0,0 +1,575 @@
+//! # 출력 스키마 문서
+//!
+//! 결과 DB의 구조와 각 필드의 설명을 코드에 정의하고, 이를 바탕으로 앱 팀과 공유할 스키마 문서(JSON, HTML)를 생성한다.
+//! DB 구조가 바뀌면 이 모듈의 정의도 함께 바뀌어야 한다.
+use clap::ValueEnum;
+use serde_json::{json, Map, Value};
+
+/// 필드 하나에 대한 설명
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct FieldDoc {
+    /// 필드 이름
+    pub name: &'static str,
+    /// 값의 형태
+    pub kind: &'static str,
+    /// 필드 설명
+    pub description: &'static str,
+}
+
+impl FieldDoc {
+    const fn new(name: &'static str, kind: &'static str, description: &'static str) -> Self {
+        Self {
+            name,
+            kind,
+            description,
+        }
+    }
+
+    /// JSON 형태의 필드 설명
+    pub fn to_json(&self) -> Value {
+        json!({
+            "name": self.name,
+            "kind": self.kind,
+            "description": self.description,
+        })
+    }
+}
+
+/// DB의 최상위 항목 설명
+///
+/// 불완전한 DB의 경우 `departments`와 `estbLectDtaiList`는 각각 `departments_quick`, `estbLectDtaiList_quick`으로 기록된다.
+pub const SECTION_DOCS: [FieldDoc; 9] = [
+    FieldDoc::new(
+        "departments",
+        "object<string, string[]>",
+        "학부명을 키로 하는 학과명 목록",
+    ),
+    FieldDoc::new(
+        "estbLectDtaiList",
+        "object<string, subject[]>",
+        "학부명을 키로 하는 과목 정보 목록",
+    ),
+    FieldDoc::new(
+        "contacts",
+        "object<string, object<string, contact>>",
+        "학부명과 교수명을 키로 하는 교수 연락처",
+    ),
+    FieldDoc::new("version", "version", "DB 및 앱 버전 정보"),
+    FieldDoc::new(
+        "resources",
+        "object<string, {field, value}[]> (선택)",
+        "--collect-resources 옵션으로 수집된 과목 키별 강의계획서 첨부파일 및 URL 목록",
+    ),
+    FieldDoc::new(
+        "academic_calendar",
+        "event[] (선택)",
+        "--academic-calendar 옵션으로 포함된 학사일정 (시작일 순)",
+    ),
+    FieldDoc::new(
+        "department_contacts",
+        "object<string, object<string, office>> (선택)",
+        "--department-contacts 옵션으로 포함된 학부명과 학과명(학부 사무실은 학부명)을 키로 하는 사무실 연락처",
+    ),
+    FieldDoc::new(
+        "department_codes",
+        "object<string, object<string, string | null>> (선택)",
+        "--dept-codes 옵션으로 포함된 학부명과 학과명(학부 자체는 학부명)을 키로 하는 공식 코드",
+    ),
+    FieldDoc::new(
+        "departments_by_campus",
+        "object<string, object<string, string[]>> (선택)",
+        "--campus-departments 옵션으로 포함된 캠퍼스명과 학부명을 키로 하는 학과명 목록",
+    ),
+];
+
+/// 과목 정보(`subject`)의 필드 설명
+///
+/// 순서는 [crate::SUBJECT_FIELDS]와 같다.
+///
+/// ## Examples
+/// ```
+/// use suwon_mate_admin_tool::schema::SUBJECT_FIELD_DOCS;
+/// use suwon_mate_admin_tool::SUBJECT_FIELDS;
+/// let names: Vec<&str> = SUBJECT_FIELD_DOCS.iter().map(|field| field.name).collect();
+/// assert_eq!(names, SUBJECT_FIELDS);
+/// ```
+pub const SUBJECT_FIELD_DOCS: [FieldDoc; 18] = [
+    FieldDoc::new("estbDpmjNm", "string", "강의 계획서 기준 개설 학부명"),
+    FieldDoc::new(
+        "estbMjorNm",
+        "string | null",
+        "강의 계획서 기준 개설 학과명 (학부 공통 과목은 null)",
+    ),
+    FieldDoc::new("trgtGrdeCd", "string | null", "수강 대상 학년 코드"),
+    FieldDoc::new("subjtNm", "string | null", "과목명"),
+    FieldDoc::new("ltrPrfsNm", "string | null", "강의 교수명"),
+    FieldDoc::new("deptNm", "string | null", "개설 부서명"),
+    FieldDoc::new(
+        "facDvnm",
+        "string | null",
+        "이수 구분 (전핵, 전선, 교양 등)",
+    ),
+    FieldDoc::new("timtSmryCn", "string | null", "강의실 및 강의 시간"),
+    FieldDoc::new("lssnLangNm", "string | null", "강의 언어"),
+    FieldDoc::new("subjtCd", "string", "과목 코드"),
+    FieldDoc::new("diclNo", "string", "분반 번호"),
+    FieldDoc::new("subjtEstbYear", "string | null", "개설 연도"),
+    FieldDoc::new("point", "number | null", "학점"),
+    FieldDoc::new("cltTerrNm", "string | null", "교양 영역"),
+    FieldDoc::new("sexCdNm", "string | null", "교수 성별"),
+    FieldDoc::new("hffcStatNm", "string | null", "교수 재직 상태"),
+    FieldDoc::new("clsfNm", "string | null", "과목 분류"),
+    FieldDoc::new("capprTypeNm", "string | null", "강의 유형"),
+];
+
+/// 옵션에 따라 과목 정보(`subject`)에 추가되는 필드 설명
+pub const OPTIONAL_SUBJECT_FIELD_DOCS: [FieldDoc; 3] = [
+    FieldDoc::new(
+        "rating",
+        "number (선택)",
+        "--evaluation-file 옵션으로 병합된 강의평가 평점",
+    ),
+    FieldDoc::new(
+        "dept_code",
+        "string | null (선택)",
+        "--dept-codes 옵션으로 기록된 개설 학과(없으면 개설 학부)의 공식 코드",
+    ),
+    FieldDoc::new(
+        "campus",
+        "string (선택)",
+        "--campus 옵션으로 기록된 개설 캠퍼스",
+    ),
+];
+
+/// 교수 연락처(`contact`)의 필드 설명
+pub const CONTACT_FIELD_DOCS: [FieldDoc; 2] = [
+    FieldDoc::new("email", "string | null", "강의자의 이메일 주소"),
+    FieldDoc::new("mpno", "string | null", "강의자의 휴대전화 번호"),
+];
+
+/// 버전 정보(`version`)의 필드 설명
+pub const VERSION_FIELD_DOCS: [FieldDoc; 9] = [
+    FieldDoc::new("app_ver", "string", "DB를 사용할 최신 앱 버전"),
+    FieldDoc::new("db_ver", "string", "DB 버전"),
+    FieldDoc::new("legacy_app_ver", "string", "레거시 앱 버전"),
+    FieldDoc::new(
+        "built_at",
+        "string",
+        "ISO 8601 형태의 빌드 시각 (--timezone 옵션의 시간대 기준, 기본값: KST)",
+    ),
+    FieldDoc::new(
+        "content_sha256",
+        "string (선택)",
+        "--checksum 옵션으로 기록된 DB 내용의 SHA-256 해시 (이 항목과 signature를 제외하고 계산)",
+    ),
+    FieldDoc::new(
+        "signature",
+        "object (선택)",
+        "sign 명령으로 기록된 ed25519 서명 정보",
+    ),
+    FieldDoc::new(
+        "previous",
+        "object (선택)",
+        "--previous-db 옵션으로 기록된 직전 버전의 db_ver와 sha256 해시",
+    ),
+    FieldDoc::new(
+        "compatibility",
+        "{db_ver, min_app_ver}[] (선택)",
+        "--compatibility-matrix 옵션으로 기록된 DB 버전별 최소 앱 버전 (db_ver 이상의 DB에 적용)",
+    ),
+    FieldDoc::new(
+        "min_app_ver",
+        "string | null (선택)",
+        "호환성 매트릭스에 따라 이 DB를 사용하는 데 필요한 최소 앱 버전",
+    ),
+];
+
+/// 학사일정(`event`)의 필드 설명
+pub const EVENT_FIELD_DOCS: [FieldDoc; 4] = [
+    FieldDoc::new("title", "string", "일정 제목"),
+    FieldDoc::new("start", "string", "시작일 (YYYY-MM-DD)"),
+    FieldDoc::new(
+        "end",
+        "string",
+        "종료일 (YYYY-MM-DD, 하루 일정은 시작일과 같음)",
+    ),
+    FieldDoc::new("holiday", "boolean", "공휴일 여부"),
+];
+
+/// 학과 사무실 연락처(`office`)의 필드 설명
+pub const OFFICE_FIELD_DOCS: [FieldDoc; 3] = [
+    FieldDoc::new("phone", "string | null", "사무실 전화번호"),
+    FieldDoc::new("email", "string | null", "사무실 이메일 주소"),
+    FieldDoc::new("location", "string | null", "사무실 위치"),
+];
+
+/// 스키마 문서의 형식
+#[derive(Copy, Clone, PartialEq, Eq, Debug, ValueEnum)]
+pub enum DocsFormat {
+    /// JSON 문서
+    Json,
+    /// 브라우저로 볼 수 있는 HTML 문서
+    Html,
+    /// Protobuf 출력에 대응하는 .proto 스키마
+    Proto,
+    /// FlatBuffers 출력에 대응하는 .fbs 스키마
+    Fbs,
+}
+
+/// HTML 특수 문자를 이스케이프하는 메서드
+///
+/// ## Examples
+/// ```
+/// use suwon_mate_admin_tool::schema::escape_html;
+/// assert_eq!(escape_html("<a href=\"x\">&</a>"), "&lt;a href=&quot;x&quot;&gt;&amp;&lt;/a&gt;");
+/// ```
+pub fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+}
+
+/// 스키마 문서에 포함되는 표 목록
+fn tables() -> [(&'static str, &'static [FieldDoc]); 7] {
+    [
+        ("DB", &SECTION_DOCS),
+        ("subject", &SUBJECT_FIELD_DOCS),
+        ("subject_optional", &OPTIONAL_SUBJECT_FIELD_DOCS),
+        ("contact", &CONTACT_FIELD_DOCS),
+        ("version", &VERSION_FIELD_DOCS),
+        ("event", &EVENT_FIELD_DOCS),
+        ("office", &OFFICE_FIELD_DOCS),
+    ]
+}
+
+/// JSON 형태의 스키마 문서를 만드는 메서드
+///
+/// ## Examples
+/// ```
+/// use suwon_mate_admin_tool::schema::schema_document;
+/// let document = schema_document();
+/// assert_eq!(document["subject"][0]["name"], "estbDpmjNm");
+/// assert_eq!(document["quick_mode_keys"]["departments"], "departments_quick");
+/// ```
+pub fn schema_document() -> Value {
+    let mut document = json!({
+        "quick_mode_keys": {
+            "departments": "departments_quick",
+            "estbLectDtaiList": "estbLectDtaiList_quick",
+        },
+    });
+    for (name, fields) in tables() {
+        document[name] = fields.iter().map(FieldDoc::to_json).collect();
+    }
+    document
+}
+
+/// HTML 형태의 스키마 문서를 만드는 메서드
+///
+/// ## Examples
+/// ```
+/// use suwon_mate_admin_tool::schema::schema_html;
+/// assert!(schema_html().contains("<td>subjtCd</td>"));
+/// ```
+pub fn schema_html() -> String {
+    let mut html = String::from(
+        "<!DOCTYPE html>\n<html lang=\"ko\">\n<head>\n<meta charset=\"utf-8\">\n\
+         <title>수원 메이트 DB 스키마</title>\n</head>\n<body>\n<h1>수원 메이트 DB 스키마</h1>\n\
+         <p>불완전한 DB의 경우 <code>departments</code>와 <code>estbLectDtaiList</code>는 각각 \
+         <code>departments_quick</code>, <code>estbLectDtaiList_quick</code>으로 기록됩니다.</p>\n",
+    );
+    for (name, fields) in tables() {
+        html.push_str(&format!(
+            "<h2>{}</h2>\n<table border=\"1\">\n<tr><th>필드</th><th>형태</th><th>설명</th></tr>\n",
+            escape_html(name)
+        ));
+        for field in fields {
+            html.push_str(&format!(
+                "<tr><td>{}</td><td>{}</td><td>{}</td></tr>\n",
+                escape_html(field.name),
+                escape_html(field.kind),
+                escape_html(field.description)
+            ));
+        }
+        html.push_str("</table>\n");
+    }
+    html.push_str("</body>\n</html>\n");
+    html
+}
+
+/// 필드 형태 표기에서 선택 필드 표시를 떼어내는 메서드
+fn strip_optional(kind: &str) -> (&str, bool) {
+    match kind.strip_suffix(" (선택)") {
+        Some(kind) => (kind, true),
+        None => (kind, false),
+    }
+}
+
+/// 필드 형태 표기(`string | null`, `object<string, subject[]>` 등)를 JSON Schema로 바꾸는 메서드
+///
+/// `subject`, `contact`와 같이 다른 표에서 정의된 형태는 `$defs`의 정의를 참조한다.
+fn kind_schema(kind: &str) -> Value {
+    let (kind, _) = strip_optional(kind);
+    if let Some((_, value)) = kind
+        .strip_prefix("object<")
+        .and_then(|kind| kind.strip_suffix('>'))
+        .and_then(|kind| kind.split_once(", "))
+    {
+        return json!({"type": "object", "additionalProperties": kind_schema(value)});
+    }
+    if kind.contains(" | ") {
+        let variants: Vec<Value> = kind.split(" | ").map(kind_schema).collect();
+        let types: Option<Vec<Value>> = variants
+            .iter()
+            .map(|variant| {
+                variant
+                    .as_object()
+                    .filter(|variant| variant.len() == 1)
+                    .and_then(|variant| variant.get("type").cloned())
+            })
+            .collect();
+        return match types {
+            Some(types) => json!({ "type": types }),
+            None => json!({ "anyOf": variants }),
+        };
+    }
+    if let Some(item) = kind.strip_suffix("[]") {
+        return json!({"type": "array", "items": kind_schema(item)});
+    }
+    if let Some(fields) = kind
+        .strip_prefix('{')
+        .and_then(|kind| kind.strip_suffix('}'))
+    {
+        return json!({"type": "object", "required": fields.split(", ").collect::<Vec<&str>>()});
+    }
+    match kind {
+        "string" | "number" | "boolean" | "object" | "null" => json!({ "type": kind }),
+        _ => json!({ "$ref": format!("#/$defs/{}", kind) }),
+    }
+}
+
+/// 필드 설명 목록을 객체의 JSON Schema로 바꾸는 메서드
+///
+/// 형태에 `(선택)`이 표시되지 않은 필드는 필수 필드가 된다.
+/// 단, null이 될 수 있는 필드는 `--prune-null` 옵션으로 생략될 수 있으므로 필수 필드에서 제외한다.
+fn object_schema<'a>(fields: impl IntoIterator<Item = &'a FieldDoc>) -> Value {
+    let mut properties = Map::new();
+    let mut required = Vec::new();
+    for field in fields {
+        let mut schema = kind_schema(field.kind);
+        schema["description"] = json!(field.description);
+        properties.insert(field.name.to_string(), schema);
+        let (kind, optional) = strip_optional(field.kind);
+        if !optional && !kind.split(" | ").any(|kind| kind == "null") {
+            required.push(field.name);
+        }
+    }
+    json!({"type": "object", "properties": properties, "required": required})
+}
+
+/// 결과 DB의 구조를 검증할 수 있는 JSON Schema(draft 2020-12)를 만드는 메서드
+///
+/// 일반 모드의 DB는 `departments`와 `estbLectDtaiList`를, 불완전한 DB(quick 모드)는 `departments_quick`과
+/// `estbLectDtaiList_quick`을 가져야 하며, 두 모드의 키가 섞인 DB는 스키마를 만족하지 않는다.
+///
+/// ## Examples
+/// ```
+/// use suwon_mate_admin_tool::schema::json_schema;
+/// let schema = json_schema();
+/// assert_eq!(schema["properties"]["departments_quick"]["type"], "object");
+/// assert_eq!(schema["$defs"]["subject"]["properties"]["point"]["type"][0], "number");
+/// assert!(schema["$defs"]["subject"]["required"].as_array().unwrap().iter().all(|name| name != "rating"));
+/// assert_eq!(schema["oneOf"][1]["required"][0], "departments_quick");
+/// assert_eq!(schema["oneOf"][1]["properties"]["departments"], false);
+/// // 참조하는 모든 정의가 $defs에 존재해야 한다.
+/// let text = schema.to_string();
+/// for reference in text.split("\"#/$defs/").skip(1) {
+///     let name = &reference[..reference.find('"').unwrap()];
+///     assert!(schema["$defs"].get(name).is_some(), "{}", name);
+/// }
+/// ```
+pub fn json_schema() -> Value {
+    let mut schema = object_schema(&SECTION_DOCS);
+    let quick_mode_keys = [
+        ("departments", "departments_quick"),
+        ("estbLectDtaiList", "estbLectDtaiList_quick"),
+    ];
+    for (key, quick_key) in quick_mode_keys {
+        let mut property = schema["properties"][key].clone();
+        property["description"] = json!(format!("불완전한 DB의 {}", key));
+        schema["properties"][quick_key] = property;
+    }
+    if let Some(required) = schema["required"].as_array_mut() {
+        required.retain(|name| quick_mode_keys.iter().all(|(key, _)| name != key));
+    }
+    schema["$schema"] = json!("https://json-schema.org/draft/2020-12/schema");
+    schema["title"] = json!("수원 메이트 DB");
+    let normal_keys = quick_mode_keys.map(|(key, _)| key);
+    let quick_keys = quick_mode_keys.map(|(_, quick_key)| quick_key);
+    let mode = |required: [&str; 2], forbidden: [&str; 2]| {
+        let forbidden: Map<String, Value> = forbidden
+            .iter()
+            .map(|key| (key.to_string(), json!(false)))
+            .collect();
+        json!({"required": required, "properties": forbidden})
+    };
+    schema["oneOf"] = json!([mode(normal_keys, quick_keys), mode(quick_keys, normal_keys)]);
+    schema["$defs"] = json!({
+        "subject": object_schema(SUBJECT_FIELD_DOCS.iter().chain(&OPTIONAL_SUBJECT_FIELD_DOCS)),
+        "contact": object_schema(&CONTACT_FIELD_DOCS),
+        "version": object_schema(&VERSION_FIELD_DOCS),
+        "event": object_schema(&EVENT_FIELD_DOCS),
+        "office": object_schema(&OFFICE_FIELD_DOCS),
+    });
+    schema
+}
+
+/// 값이 JSON Schema의 `type` 표기와 일치하는지 확인하는 메서드
+fn type_matches(instance: &Value, kind: &str) -> bool {
+    match kind {
+        "string" => instance.is_string(),
+        "number" => instance.is_number(),
+        "integer" => instance.is_i64() || instance.is_u64(),
+        "boolean" => instance.is_boolean(),
+        "object" => instance.is_object(),
+        "array" => instance.is_array(),
+        "null" => instance.is_null(),
+        _ => false,
+    }
+}
+
+/// `path` 위치의 값 `instance`를 `schema`로 검증하여 위반 사항을 `errors`에 추가하는 메서드
+fn validate_at(
+    instance: &Value,
+    schema: &Value,
+    root: &Value,
+    path: &str,
+    errors: &mut Vec<String>,
+) {
+    let location = if path.is_empty() { "/" } else { path };
+    match schema {
+        Value::Bool(true) => return,
+        Value::Bool(false) => {
+            errors.push(format!("{}: 허용되지 않는 항목입니다.", location));
+            return;
+        }
+        _ => {}
+    }
+    if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+        match reference
+            .strip_prefix('#')
+            .and_then(|pointer| root.pointer(pointer))
+        {
+            Some(target) => validate_at(instance, target, root, path, errors),
+            None => errors.push(format!(
+                "{}: 참조를 찾을 수 없습니다. ({})",
+                location, reference
+            )),
+        }
+    }
+    if let Some(kind) = schema.get("type") {
+        let kinds: Vec<&str> = match kind {
+            Value::Array(kinds) => kinds.iter().filter_map(Value::as_str).collect(),
+            kind => kind.as_str().into_iter().collect(),
+        };
+        if !kinds.iter().any(|kind| type_matches(instance, kind)) {
+            errors.push(format!(
+                "{}: {} 형태여야 합니다.",
+                location,
+                kinds.join(" | ")
+            ));
+            return;
+        }
+    }
+    if let Some(object) = instance.as_object() {
+        for name in schema
+            .get("required")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .filter_map(Value::as_str)
+        {
+            if !object.contains_key(name) {
+                errors.push(format!("{}: 필수 항목 {}이(가) 없습니다.", location, name));
+            }
+        }
+        let properties = schema.get("properties").and_then(Value::as_object);
+        for (key, value) in object {
+            let child = format!("{}/{}", path, key);
+            match properties.and_then(|properties| properties.get(key)) {
+                Some(property) => validate_at(value, property, root, &child, errors),
+                None => {
+                    if let Some(additional) = schema.get("additionalProperties") {
+                        validate_at(value, additional, root, &child, errors);
+                    }
+                }
+            }
+        }
+    }
+    if let (Some(items), Some(array)) = (schema.get("items"), instance.as_array()) {
+        for (index, item) in array.iter().enumerate() {
+            validate_at(item, items, root, &format!("{}/{}", path, index), errors);
+        }
+    }
+    let matching = |variants: &Vec<Value>| {
+        variants
+            .iter()
+            .filter(|variant| {
+                let mut variant_errors = vec![];
+                validate_at(instance, variant, root, path, &mut variant_errors);
+                variant_errors.is_empty()
+            })
+            .count()
+    };
+    if let Some(variants) = schema.get("anyOf").and_then(Value::as_array) {
+        if matching(variants) == 0 {
+            errors.push(format!(
+                "{}: 허용되는 형태 중 어느 것과도 일치하지 않습니다.",
+                location
+            ));
+        }
+    }
+    if let Some(variants) = schema.get("oneOf").and_then(Value::as_array) {
+        let count = matching(variants);
+        if count != 1 {
+            errors.push(format!(
+                "{}: 허용되는 형태 중 정확히 하나와 일치해야 합니다. ({}개 일치)",
+                location, count
+            ));
+        }
+    }
+}
+
+/// `instance`를 JSON Schema `schema`로 검증하여 위반 사항 목록을 만드는 메서드
+///
+/// [json_schema]가 만드는 스키마에 사용된 키워드(`type`, `properties`, `required`, `additionalProperties`, `items`,
+/// `anyOf`, `oneOf`, 문서 내부의 `$ref`)만 검사하며, 그 외의 키워드는 무시한다.
+/// 각 위반 사항은 JSON Pointer 형태의 위치와 함께 기록된다.
+///
+/// ## Examples
+/// ```
+/// use serde_json::json;
+/// use suwon_mate_admin_tool::schema::{json_schema, validate_schema};
+/// let schema = json_schema();
+/// let db = json!({
+///     "departments": {"경영학부": ["경영학과"]},
+///     "estbLectDtaiList": {"경영학부": []},
+///     "contacts": {},
+///     "version": {"app_ver": "1.0", "db_ver": "1.0", "legacy_app_ver": "1.0", "built_at": "2024-03-01T09:00:00+09:00"}
+/// });
+/// assert_eq!(validate_schema(&db, &schema), Vec::<String>::new());
+///
+/// let mut broken = db.clone();
+/// broken["departments"]["경영학부"] = json!("경영학과");
+/// broken["departments_quick"] = json!({});
+/// let errors = validate_schema(&broken, &schema);
+/// assert!(errors.iter().any(|error| error.starts_with("/departments/경영학부:")));
+/// assert!(errors.iter().any(|error| error.contains("정확히 하나")));
+/// ```
+pub fn validate_schema(instance: &Value, schema: &Value) -> Vec<String> {
+    let mut errors = vec![];
+    validate_at(instance, schema, schema, "", &mut errors);
+    errors
+}
@@ -0,0 +1,141 @@
+//! # 결과 DB 검색
+//!
+//! 검수 과정에서 완성된 DB의 과목을 빠르게 찾아볼 수 있도록 과목명 또는 교수명의 부분 일치로 과목을 검색한다.
+use clap::ValueEnum;
+use serde_json::Value;
+
+use crate::output::subject_lists;
+use crate::subject_key;
+
+/// 검색할 필드
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default, ValueEnum)]
+pub enum SearchField {
+    /// 과목명과 교수명
+    #[default]
+    All,
+    /// 과목명(subjtNm)
+    Subject,
+    /// 교수명(ltrPrfsNm)
+    Professor,
+}
+
+impl SearchField {
+    /// 검색할 필드의 키 목록
+    fn keys(&self) -> &'static [&'static str] {
+        match self {
+            SearchField::All => &["subjtNm", "ltrPrfsNm"],
+            SearchField::Subject => &["subjtNm"],
+            SearchField::Professor => &["ltrPrfsNm"],
+        }
+    }
+}
+
+/// 검색 결과로 찾은 과목
+#[derive(Clone, PartialEq, Debug)]
+pub struct SearchMatch<'db> {
+    /// 과목이 기록된 학부명
+    pub department: &'db str,
+    /// 과목 정보
+    pub subject: &'db Value,
+}
+
+impl SearchMatch<'_> {
+    /// 검색 결과를 한 줄로 나타내는 메서드
+    ///
+    /// 학부, 과목 키, 과목명, 교수명, 시간표를 `|`로 구분하여 나열한다.
+    ///
+    /// ## Examples
+    /// ```
+    /// use serde_json::json;
+    /// use suwon_mate_admin_tool::search::SearchMatch;
+    /// let subject = json!({"subjtCd": "1", "diclNo": "001", "subjtNm": "자료구조", "ltrPrfsNm": "홍길동", "timtSmryCn": "월1,2"});
+    /// let found = SearchMatch { department: "컴퓨터학부", subject: &subject };
+    /// assert_eq!(found.line(), "컴퓨터학부 | 1-001 | 자료구조 | 홍길동 | 월1,2");
+    /// ```
+    pub fn line(&self) -> String {
+        [
+            self.department.to_string(),
+            subject_key(self.subject),
+            self.subject["subjtNm"].as_str().unwrap_or("-").to_string(),
+            self.subject["ltrPrfsNm"]
+                .as_str()
+                .unwrap_or("-")
+                .to_string(),
+            self.subject["timtSmryCn"]
+                .as_str()
+                .unwrap_or("-")
+                .to_string(),
+        ]
+        .join(" | ")
+    }
+}
+
+/// DB에서 `field`의 값에 `pattern`이 포함된 과목을 찾는 메서드
+///
+/// 학부 순서와 학부 내 과목 순서는 DB에 기록된 순서를 따른다.
+///
+/// ## Arguments
+///
+/// * `db` - 검색할 DB의 내용
+/// * `pattern` - 찾을 문자열
+/// * `field` - 검색할 필드
+/// * `ignore_case` - 영문 대소문자를 구분하지 않을지 여부
+///
+/// ## Examples
+/// ```
+/// use serde_json::json;
+/// use suwon_mate_admin_tool::search::{search_subjects, SearchField};
+/// let db = json!({"estbLectDtaiList": {
+///     "컴퓨터학부": [
+///         {"subjtCd": "1", "diclNo": "001", "subjtNm": "자료구조", "ltrPrfsNm": "홍길동"},
+///         {"subjtCd": "2", "diclNo": "001", "subjtNm": "Data Structures", "ltrPrfsNm": "김자료"}
+///     ],
+///     "경영학부": [{"subjtCd": "3", "diclNo": "001", "subjtNm": "회계원리", "ltrPrfsNm": null}]
+/// }});
+/// assert_eq!(search_subjects(&db, "자료", SearchField::All, false).len(), 2);
+/// assert_eq!(search_subjects(&db, "자료", SearchField::Subject, false).len(), 1);
+/// assert_eq!(search_subjects(&db, "data", SearchField::All, false).len(), 0);
+/// let found = search_subjects(&db, "data", SearchField::All, true);
+/// assert_eq!(found[0].department, "컴퓨터학부");
+/// assert_eq!(found[0].subject["subjtCd"], "2");
+/// ```
+pub fn search_subjects<'db>(
+    db: &'db Value,
+    pattern: &str,
+    field: SearchField,
+    ignore_case: bool,
+) -> Vec<SearchMatch<'db>> {
+    let pattern = if ignore_case {
+        pattern.to_lowercase()
+    } else {
+        pattern.to_string()
+    };
+    let matches = |text: &str| {
+        if ignore_case {
+            text.to_lowercase().contains(&pattern)
+        } else {
+            text.contains(&pattern)
+        }
+    };
+    subject_lists(db)
+        .into_iter()
+        .flatten()
+        .flat_map(|(department, subjects)| {
+            subjects
+                .as_array()
+                .into_iter()
+                .flatten()
+                .map(move |subject| SearchMatch {
+                    department: department.as_str(),
+                    subject,
+                })
+        })
+        .filter(|found| {
+            field
+                .keys()
+                .iter()
+                .filter_map(|key| found.subject[*key].as_str())
+                .any(matches)
+        })
+        .collect()
+}
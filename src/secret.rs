@@ -0,0 +1,55 @@
+//! # 민감 정보 입력
+//!
+//! 서명 키와 같은 민감한 값을 명령줄 인자로 넘기지 않도록 숨김 프롬프트 입력과 OS 키링(keyring) 연동을 제공한다.
+//! 키링에 저장된 값은 [KEYRING_SERVICE] 서비스 아래에 항목 이름별로 저장된다.
+use std::error::Error;
+
+use keyring::Entry;
+
+/// 키링에 값을 저장할 때 사용하는 서비스 이름
+pub const KEYRING_SERVICE: &str = "suwon_mate_admin_tool";
+
+/// 서명에 사용하는 ed25519 개인키가 저장되는 키링 항목 이름
+pub const SIGNING_KEY_ENTRY: &str = "signing-key";
+
+/// 입력 내용이 화면에 표시되지 않는 프롬프트로 값을 입력받는 메서드
+///
+/// ## Errors
+/// 터미널에서 값을 읽을 수 없는 경우 오류가 발생한다.
+pub fn prompt_secret(prompt: &str) -> Result<String, Box<dyn Error>> {
+    Ok(rpassword::prompt_password(prompt)?)
+}
+
+/// 키링에 저장된 값을 읽는 메서드
+///
+/// ## Examples
+/// ```no_run
+/// use suwon_mate_admin_tool::secret::{keyring_secret, SIGNING_KEY_ENTRY};
+/// let key = keyring_secret(SIGNING_KEY_ENTRY).expect("키링에 서명 키가 없습니다.");
+/// ```
+///
+/// ## Errors
+/// 키링을 사용할 수 없거나 `name` 항목이 존재하지 않는 경우 오류가 발생한다.
+pub fn keyring_secret(name: &str) -> Result<String, Box<dyn Error>> {
+    Entry::new(KEYRING_SERVICE, name)?
+        .get_password()
+        .map_err(|error| format!("키링에서 {} 항목을 읽을 수 없습니다: {}", name, error).into())
+}
+
+/// 키링에 값을 저장하는 메서드
+///
+/// 같은 이름의 항목이 이미 존재하는 경우 덮어쓴다.
+///
+/// ## Errors
+/// 키링을 사용할 수 없는 경우 오류가 발생한다.
+pub fn store_keyring_secret(name: &str, secret: &str) -> Result<(), Box<dyn Error>> {
+    Ok(Entry::new(KEYRING_SERVICE, name)?.set_password(secret)?)
+}
+
+/// 키링에 저장된 값을 지우는 메서드
+///
+/// ## Errors
+/// 키링을 사용할 수 없거나 `name` 항목이 존재하지 않는 경우 오류가 발생한다.
+pub fn delete_keyring_secret(name: &str) -> Result<(), Box<dyn Error>> {
+    Ok(Entry::new(KEYRING_SERVICE, name)?.delete_credential()?)
+}
@@ -0,0 +1,301 @@
+//! # 병렬 이중 빌드
+//!
+//! 리팩터링 과정의 회귀를 막기 위해 기존 `Value` 기반 구현([crate::build_db_value])과
+//! 구조체 기반 구현([typed_db_content])을 동시에 실행하고 결과를 비교하는 임시 기능을 제공한다.
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::error::Error;
+use std::thread;
+
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::{json, Value};
+
+use crate::fuzzy::closest_department;
+use crate::{build_db_value, BuildOptions, BuildSummary};
+
+/// 문자열이 아닌 값을 `None`으로 해독하는 메서드
+///
+/// 기존 구현에서 `as_str()`의 결과가 `None`인 값을 빈 문자열 또는 누락으로 처리하는 것과 같은 동작을 한다.
+fn lenient_string<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<String>, D::Error> {
+    Ok(Value::deserialize(deserializer)?
+        .as_str()
+        .map(str::to_string))
+}
+
+/// 과목 목록 파일의 최상위 구조
+#[derive(Deserialize)]
+struct SubjectList<T> {
+    #[serde(rename = "estbLectDtaiList")]
+    subjects: Vec<T>,
+}
+
+/// 강의 계획서 DB 파일의 과목 정보
+#[derive(Deserialize)]
+struct TodoSubject {
+    #[serde(rename = "subjtCd", default, deserialize_with = "lenient_string")]
+    subject_code: Option<String>,
+    #[serde(rename = "diclNo", default, deserialize_with = "lenient_string")]
+    dicl_number: Option<String>,
+    #[serde(rename = "estbDpmjNm", default, deserialize_with = "lenient_string")]
+    department: Option<String>,
+    #[serde(rename = "estbMjorNm", default, deserialize_with = "lenient_string")]
+    major: Option<String>,
+    #[serde(default)]
+    email: Value,
+    #[serde(rename = "mpno", default)]
+    phone: Value,
+}
+
+/// 개설 강좌 조회 DB 파일의 과목 정보 중 DB에 기록되는 항목
+#[derive(Deserialize, Serialize, Clone)]
+struct OpenSubject {
+    #[serde(rename = "trgtGrdeCd", default)]
+    target_grade_code: Value,
+    #[serde(rename = "subjtNm", default)]
+    subject_name: Value,
+    #[serde(rename = "ltrPrfsNm", default)]
+    professor: Value,
+    #[serde(rename = "deptNm", default)]
+    department_name: Value,
+    #[serde(rename = "facDvnm", default)]
+    division: Value,
+    #[serde(rename = "timtSmryCn", default)]
+    timetable: Value,
+    #[serde(rename = "lssnLangNm", default)]
+    language: Value,
+    #[serde(rename = "subjtCd", default)]
+    subject_code: Value,
+    #[serde(rename = "diclNo", default)]
+    dicl_number: Value,
+    #[serde(rename = "subjtEstbYear", default)]
+    year: Value,
+    #[serde(default)]
+    point: Value,
+    #[serde(rename = "cltTerrNm", default)]
+    culture_area: Value,
+    #[serde(rename = "sexCdNm", default)]
+    sex: Value,
+    #[serde(rename = "hffcStatNm", default)]
+    status: Value,
+    #[serde(rename = "clsfNm", default)]
+    classification: Value,
+    #[serde(rename = "capprTypeNm", default)]
+    approval_type: Value,
+    #[serde(default, skip_serializing_if = "Value::is_null")]
+    campus: Value,
+}
+
+/// DB에 기록되는 과목 정보
+#[derive(Serialize)]
+struct SubjectRecord {
+    #[serde(flatten)]
+    subject: OpenSubject,
+    #[serde(rename = "estbDpmjNm")]
+    department: Option<String>,
+    #[serde(rename = "estbMjorNm")]
+    major: Option<String>,
+}
+
+/// 교수 연락처 정보
+#[derive(Serialize)]
+struct Contact {
+    email: Value,
+    mpno: Value,
+}
+
+/// 구조체 기반으로 DB의 내용물을 만드는 메서드
+///
+/// [build_db_value]와 같은 결과를 만드는 것을 목표로 하는 구현이다.
+/// 강의 계획서의 과목은 과목 코드와 분반 코드로 색인하여 찾으며, 같은 키가 여럿인 경우 처음 나온 과목을 사용한다.
+///
+/// ## Examples
+/// ```
+/// use suwon_mate_admin_tool::shadow::typed_db_content;
+/// use suwon_mate_admin_tool::BuildOptions;
+/// let sample = std::fs::read_to_string("sample/sample_todo_class.json").unwrap();
+/// let sample: serde_json::Value = serde_json::from_str(&sample).unwrap();
+/// let options = BuildOptions { quick_mode: true, ..Default::default() };
+/// let db = typed_db_content(&sample, &sample, &options).unwrap();
+/// assert_eq!(db["estbLectDtaiList_quick"]["경영학부"].as_array().unwrap().len(), 5);
+/// ```
+///
+/// ## Errors
+/// 제공된 파일의 내용이 예상한 구조가 아닌 경우 오류가 발생한다.
+pub fn typed_db_content(
+    open_class_data: &Value,
+    class_todo_data: &Value,
+    options: &BuildOptions,
+) -> Result<Value, Box<dyn Error>> {
+    let open_subjects = SubjectList::<OpenSubject>::deserialize(open_class_data)?;
+    let todo_subjects = SubjectList::<TodoSubject>::deserialize(class_todo_data)?;
+
+    let mut todo_index: HashMap<(&str, &str), &TodoSubject> = HashMap::new();
+    for todo in todo_subjects.subjects.iter() {
+        todo_index
+            .entry((
+                todo.subject_code.as_deref().unwrap_or(""),
+                todo.dicl_number.as_deref().unwrap_or(""),
+            ))
+            .or_insert(todo);
+    }
+    let mut departments_map: BTreeMap<&str, BTreeSet<&str>> = BTreeMap::new();
+    let mut subject_map: BTreeMap<&str, Vec<SubjectRecord>> = BTreeMap::new();
+    let mut contact_map: BTreeMap<&str, BTreeMap<&str, Contact>> = BTreeMap::new();
+    for todo in todo_subjects.subjects.iter() {
+        let department = todo.department.as_deref().unwrap_or("");
+        subject_map.entry(department).or_default();
+        contact_map.entry(department).or_default();
+    }
+
+    for subject in open_subjects.subjects.iter() {
+        let todo = todo_index.get(&(
+            subject.subject_code.as_str().unwrap_or(""),
+            subject.dicl_number.as_str().unwrap_or(""),
+        ));
+        let department = match todo.and_then(|todo| todo.department.as_deref()) {
+            Some(department)
+                if options.auto_fuzzy_merge && !subject_map.contains_key(department) =>
+            {
+                closest_department(department, subject_map.keys().copied()).or(Some(department))
+            }
+            department => department,
+        };
+        let major = todo.and_then(|todo| todo.major.as_deref());
+        if let (Some(department), Some(major)) = (department, major) {
+            departments_map.entry(department).or_default().insert(major);
+        }
+        let Some(department) = department else {
+            continue;
+        };
+        if let Some(records) = subject_map.get_mut(department) {
+            records.push(SubjectRecord {
+                subject: subject.clone(),
+                department: Some(department.to_string()),
+                major: major.map(str::to_string),
+            });
+        }
+        if let (Some(contacts), Some(name), Some(todo)) = (
+            contact_map.get_mut(department),
+            subject.professor.as_str(),
+            todo,
+        ) {
+            contacts.insert(
+                name,
+                Contact {
+                    email: todo.email.clone(),
+                    mpno: todo.phone.clone(),
+                },
+            );
+        }
+    }
+    Ok(json!({
+        if options.quick_mode {"departments_quick"} else {"departments"}: departments_map,
+        if options.quick_mode {"estbLectDtaiList_quick"} else {"estbLectDtaiList"}: subject_map,
+        "contacts": contact_map,
+        "version": {
+            "app_ver": options.latest_app_version,
+            "db_ver": options.db_version,
+            "legacy_app_ver": options.legacy_app_version
+        }
+    }))
+}
+
+/// 두 DB의 내용이 다른 위치를 JSON Pointer 형태로 모으는 메서드
+///
+/// 학과 목록은 순서가 정해져 있지 않으므로 정렬한 뒤 비교한다.
+///
+/// ## Examples
+/// ```
+/// use serde_json::json;
+/// use suwon_mate_admin_tool::shadow::diff_db;
+/// let left = json!({"departments": {"경영학부": ["경영학과", "회계학과"]}, "version": {"db_ver": "1"}});
+/// let right = json!({"departments": {"경영학부": ["회계학과", "경영학과"]}, "version": {"db_ver": "2"}});
+/// assert_eq!(diff_db(&left, &right), vec!["/version/db_ver"]);
+/// ```
+pub fn diff_db(left: &Value, right: &Value) -> Vec<String> {
+    let mut differences = vec![];
+    diff_value(
+        &normalize_departments(left),
+        &normalize_departments(right),
+        String::new(),
+        &mut differences,
+    );
+    differences
+}
+
+/// 학과 목록을 정렬한 사본을 만드는 메서드
+fn normalize_departments(db: &Value) -> Value {
+    let mut db = db.clone();
+    for key in ["departments", "departments_quick"] {
+        for majors in db[key]
+            .as_object_mut()
+            .into_iter()
+            .flat_map(|map| map.values_mut())
+        {
+            if let Some(majors) = majors.as_array_mut() {
+                majors.sort_by_key(|major| major.to_string());
+            }
+        }
+    }
+    db
+}
+
+/// 두 값을 재귀적으로 비교하는 메서드
+fn diff_value(left: &Value, right: &Value, path: String, differences: &mut Vec<String>) {
+    match (left, right) {
+        (Value::Object(left), Value::Object(right)) => {
+            let keys: BTreeSet<&String> = left.keys().chain(right.keys()).collect();
+            for key in keys {
+                diff_value(
+                    left.get(key).unwrap_or(&Value::Null),
+                    right.get(key).unwrap_or(&Value::Null),
+                    format!("{}/{}", path, key.replace('~', "~0").replace('/', "~1")),
+                    differences,
+                );
+            }
+        }
+        (Value::Array(left), Value::Array(right)) if left.len() == right.len() => {
+            for (index, (left, right)) in left.iter().zip(right).enumerate() {
+                diff_value(left, right, format!("{}/{}", path, index), differences);
+            }
+        }
+        (left, right) if left != right => differences.push(path),
+        _ => {}
+    }
+}
+
+/// 두 구현을 동시에 실행하고 결과를 비교하는 메서드
+///
+/// [build_db_value]와 같은 인자를 받으며, 두 결과가 같은 경우 기존 구현의 결과를 반환한다.
+///
+/// ## Errors
+/// * 어느 한 구현이라도 실패하거나 비정상 종료된 경우
+/// * 두 구현의 결과가 다른 경우 (다른 위치 목록이 오류 메세지에 포함된다)
+pub fn shadow_build(
+    open_class_data: &Value,
+    class_todo_data: &Value,
+    options: &BuildOptions,
+) -> Result<(Value, BuildSummary), Box<dyn Error>> {
+    let (legacy, typed) = thread::scope(|scope| {
+        let legacy = scope.spawn(|| {
+            build_db_value(open_class_data, class_todo_data, options)
+                .map_err(|error| error.to_string())
+        });
+        let typed = scope.spawn(|| {
+            typed_db_content(open_class_data, class_todo_data, options)
+                .map_err(|error| error.to_string())
+        });
+        (legacy.join(), typed.join())
+    });
+    let legacy = legacy.map_err(|_| "기존 구현이 비정상 종료되었습니다.")??;
+    let typed = typed.map_err(|_| "구조체 기반 구현이 비정상 종료되었습니다.")??;
+    let differences = diff_db(&legacy.0, &typed);
+    if !differences.is_empty() {
+        return Err(format!(
+            "두 구현의 결과가 {}곳에서 다릅니다: {}",
+            differences.len(),
+            differences.join(", ")
+        )
+        .into());
+    }
+    Ok(legacy)
+}
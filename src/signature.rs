@@ -0,0 +1,158 @@
+//! # DB 서명
+//!
+//! ed25519 키를 이용하여 결과 DB에 서명하고, 서명을 통해 DB의 위변조 여부를 확인하는 기능을 제공한다.
+//!
+//! 서명 대상은 `version.signature` 항목을 제외한 DB 전체를 [RFC 8785](https://www.rfc-editor.org/rfc/rfc8785)에 따라
+//! 직렬화한 내용([canonical_json])의 SHA-256 해시이며, 서명 결과는 `version.signature` 항목에 기록된다.
+//! 직렬화 형태가 키 순서나 공백과 관계없이 정해지므로, 다른 도구로 다시 저장한 DB도 검증할 수 있고 앱에서도 같은 해시를 계산할 수 있다.
+use std::error::Error;
+use std::fs;
+use std::path::Path;
+
+use ed25519_dalek::{Signature, Signer, SigningKey, Verifier, VerifyingKey};
+use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
+
+use crate::canonical::canonical_json;
+
+/// 바이트 배열을 소문자 16진수 문자열로 바꾸는 메서드
+///
+/// ## Examples
+/// ```
+/// use suwon_mate_admin_tool::signature::to_hex;
+/// assert_eq!(to_hex(&[0x0a, 0xff]), "0aff");
+/// ```
+pub fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+/// 16진수 문자열을 바이트 배열로 바꾸는 메서드
+///
+/// ## Errors
+/// 문자열의 길이가 홀수이거나 16진수가 아닌 문자가 포함된 경우 오류가 발생한다.
+///
+/// ## Examples
+/// ```
+/// use suwon_mate_admin_tool::signature::from_hex;
+/// assert_eq!(from_hex("0aFF").unwrap(), vec![0x0a, 0xff]);
+/// assert!(from_hex("0g").is_err());
+/// ```
+pub fn from_hex(text: &str) -> Result<Vec<u8>, Box<dyn Error>> {
+    if !text.len().is_multiple_of(2) {
+        return Err("16진수 문자열의 길이가 올바르지 않습니다.".into());
+    }
+    (0..text.len())
+        .step_by(2)
+        .map(|index| {
+            text.get(index..index + 2)
+                .and_then(|byte| u8::from_str_radix(byte, 16).ok())
+                .ok_or_else(|| "16진수가 아닌 문자가 포함되어 있습니다.".into())
+        })
+        .collect()
+}
+
+/// 16진수로 기록된 32바이트 키를 해독하는 메서드
+///
+/// 개인키의 경우 ed25519 시드(seed) 32바이트를, 공개키의 경우 공개키 32바이트를 16진수로 기록한 문자열을 사용한다.
+/// 앞뒤의 공백은 무시된다.
+///
+/// ## Examples
+/// ```
+/// use suwon_mate_admin_tool::signature::parse_key;
+/// assert_eq!(parse_key(&format!("{}\n", "07".repeat(32))).unwrap(), [7; 32]);
+/// assert!(parse_key("07").is_err());
+/// ```
+///
+/// ## Errors
+/// 내용이 32바이트의 16진수 문자열이 아닌 경우 오류가 발생한다.
+pub fn parse_key(text: &str) -> Result<[u8; 32], Box<dyn Error>> {
+    let key = from_hex(text.trim())?;
+    key.try_into()
+        .map_err(|_| "키는 32바이트 길이의 16진수 문자열이어야 합니다.".into())
+}
+
+/// 16진수로 기록된 32바이트 키 파일을 읽는 메서드
+///
+/// 파일 형식은 [parse_key]를 참고한다.
+///
+/// ## Errors
+/// * 파일이 존재하지 않는 경우
+/// * 파일 내용이 32바이트의 16진수 문자열이 아닌 경우
+pub fn read_key_file(path: &Path) -> Result<[u8; 32], Box<dyn Error>> {
+    parse_key(&fs::read_to_string(path)?)
+}
+
+/// DB에서 서명 대상이 되는 내용의 SHA-256 해시를 구하는 메서드
+///
+/// `version.signature` 항목은 해시 계산에서 제외되므로 서명 전후의 해시 값은 같다.
+/// 해시는 [canonical_json]으로 직렬화한 내용으로 계산하므로 키 순서가 달라도 같다.
+///
+/// ## Examples
+/// ```
+/// use serde_json::json;
+/// use suwon_mate_admin_tool::signature::db_digest;
+/// let unsigned = json!({"version": {"db_ver": "1.0"}, "a": 1});
+/// let signed = json!({"a": 1, "version": {"signature": {"value": "00"}, "db_ver": "1.0"}});
+/// assert_eq!(db_digest(&unsigned), db_digest(&signed));
+/// ```
+pub fn db_digest(db: &Value) -> [u8; 32] {
+    let mut payload = db.clone();
+    if let Some(version) = payload["version"].as_object_mut() {
+        version.remove("signature");
+    }
+    Sha256::digest(canonical_json(&payload).as_bytes()).into()
+}
+
+/// DB에 서명하는 메서드
+///
+/// 서명 결과와 검증에 필요한 공개키를 `version.signature` 항목에 기록한다. 기존 서명은 덮어쓴다.
+///
+/// ## Arguments
+///
+/// * `db` - 서명할 DB의 내용
+/// * `key` - ed25519 개인키
+///
+/// ## Examples
+/// ```
+/// use ed25519_dalek::SigningKey;
+/// use serde_json::json;
+/// use suwon_mate_admin_tool::signature::{sign_db, verify_db};
+/// let key = SigningKey::from_bytes(&[7; 32]);
+/// let mut db = json!({"version": {"db_ver": "1.0"}});
+/// sign_db(&mut db, &key);
+/// assert!(verify_db(&db, &key.verifying_key()).is_ok());
+/// db["version"]["db_ver"] = json!("2.0");
+/// assert!(verify_db(&db, &key.verifying_key()).is_err());
+/// ```
+pub fn sign_db(db: &mut Value, key: &SigningKey) {
+    let digest = db_digest(db);
+    let signature = key.sign(&digest);
+    db["version"]["signature"] = json!({
+        "algorithm": "ed25519",
+        "sha256": to_hex(&digest),
+        "public_key": to_hex(key.verifying_key().as_bytes()),
+        "value": to_hex(&signature.to_bytes()),
+    });
+}
+
+/// DB의 서명을 검증하는 메서드
+///
+/// ## Arguments
+///
+/// * `db` - 검증할 DB의 내용
+/// * `key` - 신뢰할 수 있는 경로로 전달받은 ed25519 공개키
+///
+/// ## Errors
+/// * DB에 서명이 존재하지 않거나 형식이 올바르지 않은 경우
+/// * DB의 내용이 서명 이후 변경된 경우
+/// * 다른 키로 서명된 경우
+pub fn verify_db(db: &Value, key: &VerifyingKey) -> Result<(), Box<dyn Error>> {
+    let signature = &db["version"]["signature"];
+    let value = signature["value"]
+        .as_str()
+        .ok_or("DB에 서명이 존재하지 않습니다.")?;
+    let signature = Signature::from_slice(&from_hex(value)?)?;
+    let digest = db_digest(db);
+    key.verify(&digest, &signature)
+        .map_err(|_| "서명이 일치하지 않습니다. DB가 변조되었거나 다른 키로 서명되었습니다.".into())
+}
@@ -0,0 +1,148 @@
+//! # 출력 크기 프로파일
+//!
+//! 결과 JSON에서 어떤 섹션과 과목 필드가 용량을 많이 차지하는지 분석하여 Markdown 표로 정리한다.
+//! 크기는 공백 없이 직렬화한 JSON 기준의 바이트 수이며, 필드명(키)과 값의 기여도를 나누어 보여주므로
+//! `--prune-null`이나 `--format columnar`와 같은 경량화 방법의 효과를 가늠하는 데 사용할 수 있다.
+use std::collections::HashMap;
+
+use serde_json::Value;
+
+use crate::output::subjects;
+use crate::report::escape_cell;
+
+/// 섹션 또는 필드 하나가 차지하는 크기
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct SizeEntry {
+    /// 섹션 또는 필드 이름
+    pub name: String,
+    /// 키(따옴표와 `:` 포함)가 차지하는 바이트 수
+    pub key_bytes: usize,
+    /// 값이 차지하는 바이트 수
+    pub value_bytes: usize,
+    /// 등장 횟수
+    pub count: usize,
+}
+
+impl SizeEntry {
+    /// 키와 값을 합친 바이트 수
+    pub fn total(&self) -> usize {
+        self.key_bytes + self.value_bytes
+    }
+}
+
+/// `(키, 값)` 목록의 크기를 이름별로 합산하여 큰 순서로 정렬하는 메서드
+fn collect_sizes<'a>(pairs: impl Iterator<Item = (&'a String, &'a Value)>) -> Vec<SizeEntry> {
+    let mut sizes: HashMap<&str, SizeEntry> = HashMap::new();
+    for (key, value) in pairs {
+        let entry = sizes.entry(key).or_insert_with(|| SizeEntry {
+            name: key.clone(),
+            key_bytes: 0,
+            value_bytes: 0,
+            count: 0,
+        });
+        entry.key_bytes += Value::String(key.clone()).to_string().len() + 1;
+        entry.value_bytes += value.to_string().len();
+        entry.count += 1;
+    }
+    let mut sizes: Vec<SizeEntry> = sizes.into_values().collect();
+    sizes.sort_by(|left, right| {
+        right
+            .total()
+            .cmp(&left.total())
+            .then_with(|| left.name.cmp(&right.name))
+    });
+    sizes
+}
+
+/// DB의 최상위 섹션별 크기를 구하는 메서드
+///
+/// ## Examples
+/// ```
+/// use serde_json::json;
+/// use suwon_mate_admin_tool::size_profile::section_sizes;
+/// let db = json!({"version": {"db_ver": "1.0"}, "contacts": {}});
+/// let sizes = section_sizes(&db);
+/// assert_eq!(sizes[0].name, "version");
+/// assert_eq!(sizes[0].key_bytes, "\"version\":".len());
+/// assert_eq!(sizes[0].value_bytes, "{\"db_ver\":\"1.0\"}".len());
+/// ```
+pub fn section_sizes(db: &Value) -> Vec<SizeEntry> {
+    collect_sizes(db.as_object().into_iter().flatten())
+}
+
+/// 모든 과목에 걸친 필드별 크기를 구하는 메서드
+///
+/// ## Examples
+/// ```
+/// use serde_json::json;
+/// use suwon_mate_admin_tool::size_profile::field_sizes;
+/// let db = json!({"estbLectDtaiList": {"경영학부": [
+///     {"subjtNm": "회계원리", "point": 3},
+///     {"subjtNm": "재무관리", "point": null}
+/// ]}});
+/// let sizes = field_sizes(&db);
+/// assert_eq!(sizes[0].name, "subjtNm");
+/// assert_eq!(sizes[0].count, 2);
+/// assert_eq!(sizes[1].value_bytes, "3".len() + "null".len());
+/// ```
+pub fn field_sizes(db: &Value) -> Vec<SizeEntry> {
+    collect_sizes(
+        subjects(db)
+            .filter_map(Value::as_object)
+            .flat_map(|subject| subject.iter()),
+    )
+}
+
+/// 크기 목록을 Markdown 표로 만드는 메서드
+fn size_table(title: &str, sizes: &[SizeEntry], total: usize) -> String {
+    let mut table = format!(
+        "\n## {}\n\n| 이름 | 개수 | 키(바이트) | 값(바이트) | 합계(바이트) | 비율 |\n\
+         | --- | ---: | ---: | ---: | ---: | ---: |\n",
+        title
+    );
+    for size in sizes {
+        table.push_str(&format!(
+            "| {} | {} | {} | {} | {} | {:.1}% |\n",
+            escape_cell(&size.name),
+            size.count,
+            size.key_bytes,
+            size.value_bytes,
+            size.total(),
+            size.total() as f64 * 100.0 / total.max(1) as f64
+        ));
+    }
+    table
+}
+
+/// DB의 크기 프로파일을 Markdown 문서로 만드는 메서드
+///
+/// 비율은 DB 전체 크기에 대한 비율이다.
+///
+/// ## Examples
+/// ```
+/// use serde_json::json;
+/// use suwon_mate_admin_tool::size_profile::size_profile;
+/// let db = json!({"estbLectDtaiList": {"경영학부": [{"subjtNm": "회계원리"}]}, "version": {"db_ver": "1.0"}});
+/// let profile = size_profile(&db);
+/// assert!(profile.contains(&format!("- 전체 크기: {}바이트", db.to_string().len())));
+/// assert!(profile.contains("| subjtNm | 1 | 10 | 14 | 24 |"));
+/// ```
+pub fn size_profile(db: &Value) -> String {
+    let total = db.to_string().len();
+    let fields = field_sizes(db);
+    let subject_count = subjects(db).count();
+    let key_bytes: usize = fields.iter().map(|size| size.key_bytes).sum();
+    let mut profile = format!(
+        "# 수원 메이트 DB 크기 프로파일\n\n\
+         - 전체 크기: {}바이트\n\
+         - 과목 수: {}\n\
+         - 과목 필드명이 차지하는 크기: {}바이트 ({:.1}%)\n",
+        total,
+        subject_count,
+        key_bytes,
+        key_bytes as f64 * 100.0 / total.max(1) as f64
+    );
+    profile.push_str(&size_table("섹션별 크기", &section_sizes(db), total));
+    profile.push_str(&size_table("과목 필드별 크기", &fields, total));
+    profile
+}
@@ -0,0 +1,140 @@
+//! # 학부별 분할 출력
+//!
+//! 앱의 초기 다운로드 크기를 줄이기 위해 DB를 학부별 과목 파일과 나머지 항목을 담은 색인 파일로 나누어 저장한다.
+//! 각 파일의 SHA-256 해시, 크기, 다운로드 URL은 `manifest.json`에 기록되므로 앱은 매니페스트를 먼저 받은 뒤
+//! 필요한 학부의 파일만 지연 로딩할 수 있다.
+//!
+//! * `index.json` - `estbLectDtaiList`를 제외한 DB의 나머지 항목
+//! * `subjects/{학부명}.json` - 학부 하나의 과목 목록
+//! * `manifest.json` - 위 파일들의 경로, 해시, 크기, URL
+use std::collections::HashSet;
+use std::error::Error;
+use std::fs;
+use std::path::Path;
+
+use serde_json::{json, Map, Value};
+use sha2::{Digest, Sha256};
+
+use crate::output::subject_lists;
+use crate::signature::to_hex;
+
+/// 매니페스트 파일명
+pub const MANIFEST_FILE: &str = "manifest.json";
+/// 색인 파일명
+pub const INDEX_FILE: &str = "index.json";
+/// 학부별 과목 파일을 저장하는 하위 디렉토리
+pub const SUBJECTS_DIR: &str = "subjects";
+
+/// 학부명을 파일명으로 사용할 수 있도록 바꾸는 메서드
+///
+/// 파일명에 사용할 수 없는 문자는 `_`로 바꾸며, 이미 사용된 이름과 겹치면 `_2`, `_3`과 같은 접미사를 붙인다.
+///
+/// ## Examples
+/// ```
+/// use std::collections::HashSet;
+/// use suwon_mate_admin_tool::split::file_stem;
+/// let mut used = HashSet::new();
+/// assert_eq!(file_stem("경영/회계학부", &mut used), "경영_회계학부");
+/// assert_eq!(file_stem("경영:회계학부", &mut used), "경영_회계학부_2");
+/// assert_eq!(file_stem("", &mut used), "_");
+/// ```
+pub fn file_stem(department: &str, used: &mut HashSet<String>) -> String {
+    let mut stem: String = department
+        .chars()
+        .map(|character| match character {
+            '/' | '\\' | ':' | '*' | '?' | '"' | '<' | '>' | '|' => '_',
+            character if character.is_control() => '_',
+            character => character,
+        })
+        .collect();
+    if stem.is_empty() || stem == "." || stem == ".." {
+        stem = format!("_{}", stem);
+    }
+    let mut candidate = stem.clone();
+    let mut suffix = 2;
+    while !used.insert(candidate.clone()) {
+        candidate = format!("{}_{}", stem, suffix);
+        suffix += 1;
+    }
+    candidate
+}
+
+/// 파일을 저장하고 매니페스트 항목을 만드는 메서드
+fn write_entry(
+    directory: &Path,
+    file: &str,
+    content: &[u8],
+    base_url: Option<&str>,
+) -> Result<Value, Box<dyn Error>> {
+    fs::write(directory.join(file), content)?;
+    Ok(json!({
+        "file": file,
+        "sha256": to_hex(&Sha256::digest(content)),
+        "size": content.len(),
+        "url": base_url.map(|base_url| format!("{}/{}", base_url.trim_end_matches('/'), file)),
+    }))
+}
+
+/// DB를 학부별 파일로 나누어 저장하고 매니페스트를 만드는 메서드
+///
+/// 매니페스트의 `url`은 `base_url`이 주어진 경우 `base_url`과 파일 경로를 이어 붙인 값이며, 그렇지 않으면 `null`로 남겨둔다.
+///
+/// ## Arguments
+///
+/// * `db` - 저장할 DB의 내용
+/// * `directory` - 파일을 저장할 디렉토리 (없으면 새로 만든다)
+/// * `base_url` - 파일이 업로드될 위치의 URL
+///
+/// ## Examples
+/// ```
+/// use serde_json::json;
+/// use suwon_mate_admin_tool::split::write_split;
+/// let db = json!({
+///     "estbLectDtaiList": {"경영학부": [{"subjtCd": "1"}]},
+///     "version": {"db_ver": "1.0"}
+/// });
+/// let directory = std::env::temp_dir().join("suwon_mate_write_split_doctest");
+/// let manifest = write_split(&db, &directory, Some("https://example.com/db/")).unwrap();
+/// assert_eq!(manifest["departments"]["경영학부"]["file"], "subjects/경영학부.json");
+/// assert_eq!(manifest["departments"]["경영학부"]["url"], "https://example.com/db/subjects/경영학부.json");
+/// let index: serde_json::Value =
+///     serde_json::from_str(&std::fs::read_to_string(directory.join("index.json")).unwrap()).unwrap();
+/// assert_eq!(index, json!({"version": {"db_ver": "1.0"}}));
+/// ```
+///
+/// ## Errors
+/// 디렉토리나 파일을 만들거나 쓰는 과정에서 문제가 발생한 경우 오류가 발생한다.
+pub fn write_split(
+    db: &Value,
+    directory: &Path,
+    base_url: Option<&str>,
+) -> Result<Value, Box<dyn Error>> {
+    fs::create_dir_all(directory.join(SUBJECTS_DIR))?;
+    let mut index = db.clone();
+    if let Some(index) = index.as_object_mut() {
+        index.remove("estbLectDtaiList");
+        index.remove("estbLectDtaiList_quick");
+    }
+    let index = write_entry(
+        directory,
+        INDEX_FILE,
+        index.to_string().as_bytes(),
+        base_url,
+    )?;
+    let mut departments = Map::new();
+    let mut used = HashSet::new();
+    for (department, subjects) in subject_lists(db).into_iter().flatten() {
+        let file = format!("{}/{}.json", SUBJECTS_DIR, file_stem(department, &mut used));
+        departments.insert(
+            department.clone(),
+            write_entry(directory, &file, subjects.to_string().as_bytes(), base_url)?,
+        );
+    }
+    let manifest = json!({
+        "version": db["version"],
+        "index": index,
+        "departments": departments,
+    });
+    fs::write(directory.join(MANIFEST_FILE), manifest.to_string())?;
+    Ok(manifest)
+}
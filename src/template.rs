@@ -0,0 +1,267 @@
+//! # 결과 파일명 템플릿
+//!
+//! `--output-template` 옵션으로 지정된 `db_{semester}_{db_version}_{date}.json`과 같은 템플릿의
+//! 자리 표시자를 실제 값으로 바꾸어 결과 파일명을 만든다.
+use std::collections::HashMap;
+use std::error::Error;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde_json::Value;
+
+/// 템플릿에서 사용할 수 있는 자리 표시자
+pub const PLACEHOLDERS: [&str; 5] = ["semester", "db_version", "app_version", "date", "format"];
+
+/// 한국 표준시(KST)의 UTC 기준 시차(초)
+pub const KST_OFFSET_SECONDS: i64 = 9 * 60 * 60;
+
+/// 하루의 길이(초)
+const DAY_SECONDS: i64 = 24 * 60 * 60;
+
+/// (연, 월, 일)을 1970년 1월 1일로부터 지난 일수로 바꾸는 메서드
+pub(crate) fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let month = i64::from(month);
+    let day_of_year =
+        (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// 1970년 1월 1일로부터 지난 일수를 (연, 월, 일)로 바꾸는 메서드
+pub(crate) fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * month_index + 2) / 5 + 1) as u32;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    } as u32;
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+/// `KST`, `UTC`, `+09:00`과 같은 시간대 표기를 UTC 기준 시차(초)로 바꾸는 메서드
+///
+/// `KST`와 `UTC`(또는 `Z`) 외에는 `+HH:MM`, `-HH:MM`, `+HH` 형태의 UTC 기준 시차를 사용할 수 있다.
+///
+/// ## Examples
+/// ```
+/// use suwon_mate_admin_tool::template::parse_utc_offset;
+/// assert_eq!(parse_utc_offset("KST").unwrap(), 9 * 60 * 60);
+/// assert_eq!(parse_utc_offset("utc").unwrap(), 0);
+/// assert_eq!(parse_utc_offset("-03:30").unwrap(), -(3 * 60 + 30) * 60);
+/// assert_eq!(parse_utc_offset("+05").unwrap(), 5 * 60 * 60);
+/// assert!(parse_utc_offset("Asia/Seoul").is_err());
+/// assert!(parse_utc_offset("+15:00").is_err());
+/// ```
+///
+/// ## Errors
+/// 알 수 없는 시간대 표기이거나 시차가 ±14시간을 넘는 경우 오류가 발생한다.
+pub fn parse_utc_offset(timezone: &str) -> Result<i64, Box<dyn Error>> {
+    let invalid = || {
+        format!(
+            "알 수 없는 시간대입니다: {} (예: KST, UTC, +09:00)",
+            timezone
+        )
+    };
+    match timezone.to_ascii_uppercase().as_str() {
+        "KST" => return Ok(KST_OFFSET_SECONDS),
+        "UTC" | "Z" => return Ok(0),
+        _ => {}
+    }
+    let (sign, offset) = match timezone.split_at_checked(1) {
+        Some(("+", offset)) => (1, offset),
+        Some(("-", offset)) => (-1, offset),
+        _ => return Err(invalid().into()),
+    };
+    let (hours, minutes) = offset.split_once(':').unwrap_or((offset, "00"));
+    if hours.len() != 2 || minutes.len() != 2 {
+        return Err(invalid().into());
+    }
+    let hours: i64 = hours.parse().map_err(|_| invalid())?;
+    let minutes: i64 = minutes.parse().map_err(|_| invalid())?;
+    if minutes >= 60 || hours * 60 + minutes > 14 * 60 {
+        return Err(invalid().into());
+    }
+    Ok(sign * (hours * 60 + minutes) * 60)
+}
+
+/// UNIX 시각을 주어진 시차의 `YYYYMMDD` 형태의 날짜로 바꾸는 메서드
+///
+/// ## Examples
+/// ```
+/// use suwon_mate_admin_tool::template::local_date;
+/// assert_eq!(local_date(1_709_218_800, 0), "20240229");
+/// assert_eq!(local_date(1_709_218_800, 9 * 60 * 60), "20240301");
+/// ```
+pub fn local_date(unix_seconds: i64, offset_seconds: i64) -> String {
+    let (year, month, day) =
+        civil_from_days((unix_seconds + offset_seconds).div_euclid(DAY_SECONDS));
+    format!("{:04}{:02}{:02}", year, month, day)
+}
+
+/// UNIX 시각을 주어진 시차의 ISO 8601(RFC 3339) 형태의 시각으로 바꾸는 메서드
+///
+/// ## Examples
+/// ```
+/// use suwon_mate_admin_tool::template::timestamp;
+/// assert_eq!(timestamp(1_709_218_800, 9 * 60 * 60), "2024-03-01T00:00:00+09:00");
+/// assert_eq!(timestamp(1_709_218_800, 0), "2024-02-29T15:00:00Z");
+/// assert_eq!(timestamp(0, -(3 * 60 + 30) * 60), "1969-12-31T20:30:00-03:30");
+/// ```
+pub fn timestamp(unix_seconds: i64, offset_seconds: i64) -> String {
+    let seconds = unix_seconds + offset_seconds;
+    let (year, month, day) = civil_from_days(seconds.div_euclid(DAY_SECONDS));
+    let time = seconds.rem_euclid(DAY_SECONDS);
+    let offset = match offset_seconds {
+        0 => String::from("Z"),
+        _ => format!(
+            "{}{:02}:{:02}",
+            if offset_seconds < 0 { '-' } else { '+' },
+            offset_seconds.abs() / 3600,
+            offset_seconds.abs() % 3600 / 60
+        ),
+    };
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}{}",
+        year,
+        month,
+        day,
+        time / 3600,
+        time % 3600 / 60,
+        time % 60,
+        offset
+    )
+}
+
+/// 현재 UNIX 시각을 구하는 메서드
+pub fn now() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs() as i64)
+}
+
+/// UNIX 시각을 한국 표준시 기준 `YYYYMMDD` 형태의 날짜로 바꾸는 메서드
+///
+/// ## Examples
+/// ```
+/// use suwon_mate_admin_tool::template::kst_date;
+/// assert_eq!(kst_date(0), "19700101");
+/// // 2024-02-29 15:00:00 UTC는 한국 표준시로 2024년 3월 1일 0시이다.
+/// assert_eq!(kst_date(1_709_218_800), "20240301");
+/// ```
+pub fn kst_date(unix_seconds: i64) -> String {
+    local_date(unix_seconds, KST_OFFSET_SECONDS)
+}
+
+/// 오늘 날짜를 한국 표준시 기준 `YYYYMMDD` 형태로 구하는 메서드
+pub fn today() -> String {
+    kst_date(now())
+}
+
+/// 강의계획서 DB 파일의 내용으로부터 학기를 구하는 메서드
+///
+/// 개설 연도(`subjtEstbYear`)와 개설 학기(`subjtEstbSmrNm`)가 모두 기록된 첫 과목을 기준으로 `2022-2학기` 형태로 만든다.
+///
+/// ## Examples
+/// ```
+/// use suwon_mate_admin_tool::template::semester;
+/// let sample: serde_json::Value =
+///     serde_json::from_str(&std::fs::read_to_string("sample/sample_todo_class.json").unwrap()).unwrap();
+/// assert_eq!(semester(&sample).unwrap(), "2022-2학기");
+/// ```
+pub fn semester(class_todo: &Value) -> Option<String> {
+    class_todo["estbLectDtaiList"]
+        .as_array()?
+        .iter()
+        .find_map(|subject| {
+            let year = subject["subjtEstbYear"].as_str()?;
+            let term = subject["subjtEstbSmrNm"].as_str()?;
+            Some(format!("{}-{}", year, term))
+        })
+}
+
+/// 템플릿의 자리 표시자를 `values`의 값으로 바꾸는 메서드
+///
+/// 자리 표시자는 `{이름}` 형태로 적으며, `{{`와 `}}`는 각각 `{`와 `}`로 바뀐다.
+///
+/// ## Arguments
+///
+/// * `template` - 결과 파일명 템플릿
+/// * `values` - 자리 표시자 이름과 값
+///
+/// ## Examples
+/// ```
+/// use std::collections::HashMap;
+/// use suwon_mate_admin_tool::template::render;
+/// let values = HashMap::from([("db_version", String::from("1.2")), ("date", String::from("20240301"))]);
+/// assert_eq!(render("db_{db_version}_{date}.json", &values).unwrap(), "db_1.2_20240301.json");
+/// assert!(render("db_{semester}.json", &values).is_err());
+/// assert!(render("db_{db_version.json", &values).is_err());
+/// ```
+///
+/// ## Errors
+/// * 알 수 없거나 값이 없는 자리 표시자가 포함된 경우
+/// * 중괄호의 짝이 맞지 않는 경우
+pub fn render(template: &str, values: &HashMap<&str, String>) -> Result<String, Box<dyn Error>> {
+    let mut result = String::new();
+    let mut rest = template;
+    while let Some(index) = rest.find(['{', '}']) {
+        result.push_str(&rest[..index]);
+        rest = &rest[index..];
+        if rest.starts_with("{{") || rest.starts_with("}}") {
+            result.push_str(&rest[..1]);
+            rest = &rest[2..];
+            continue;
+        }
+        let end = rest
+            .find('}')
+            .filter(|_| rest.starts_with('{'))
+            .ok_or_else(|| format!("템플릿의 중괄호 짝이 맞지 않습니다: {}", template))?;
+        let name = &rest[1..end];
+        let value = values.get(name).ok_or_else(|| {
+            if PLACEHOLDERS.contains(&name) {
+                format!("템플릿의 {{{}}} 값을 정할 수 없습니다.", name)
+            } else {
+                format!(
+                    "알 수 없는 자리 표시자입니다: {{{}}} (사용 가능: {})",
+                    name,
+                    PLACEHOLDERS.join(", ")
+                )
+            }
+        })?;
+        result.push_str(value);
+        rest = &rest[end + 1..];
+    }
+    result.push_str(rest);
+    Ok(result)
+}
+
+/// 결과 파일과 함께 저장하는 부가 파일의 경로를 구하는 메서드
+///
+/// 결과 파일과 같은 디렉토리에 결과 파일명(확장자 제외) 뒤에 `suffix`를 붙인 이름을 사용하므로,
+/// 결과 파일명 템플릿이 다른 빌드끼리 부가 파일을 덮어쓰지 않는다.
+///
+/// ## Examples
+/// ```
+/// use std::path::{Path, PathBuf};
+/// use suwon_mate_admin_tool::template::companion_path;
+/// assert_eq!(
+///     companion_path(Path::new("2024-1/result_1.2.json"), "_legacy.json"),
+///     PathBuf::from("2024-1/result_1.2_legacy.json")
+/// );
+/// ```
+pub fn companion_path(result_path: &Path, suffix: &str) -> PathBuf {
+    let mut name = result_path.file_stem().unwrap_or_default().to_os_string();
+    name.push(suffix);
+    result_path.with_file_name(name)
+}
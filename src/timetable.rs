@@ -0,0 +1,353 @@
+//! # 시간표 해석
+//!
+//! 과목 정보의 시간표 문자열(`timtSmryCn`)을 요일, 교시, 강의실 정보로 해석한다.
+//! `글경602(수6)`처럼 강의실과 함께 표기된 경우뿐만 아니라 `월1,2`, `Mon 1-2`, `토 야간`과 같은 예외 표기도
+//! [TimetableDictionary]에 등록된 표기를 이용하여 해석할 수 있다.
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use crate::output::subjects;
+use crate::subject_key;
+
+/// 요일
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub enum Weekday {
+    /// 월요일
+    Monday,
+    /// 화요일
+    Tuesday,
+    /// 수요일
+    Wednesday,
+    /// 목요일
+    Thursday,
+    /// 금요일
+    Friday,
+    /// 토요일
+    Saturday,
+    /// 일요일
+    Sunday,
+}
+
+impl Weekday {
+    /// 월요일부터 일요일까지의 요일 목록
+    pub const ALL: [Weekday; 7] = [
+        Weekday::Monday,
+        Weekday::Tuesday,
+        Weekday::Wednesday,
+        Weekday::Thursday,
+        Weekday::Friday,
+        Weekday::Saturday,
+        Weekday::Sunday,
+    ];
+
+    /// 요일의 한 글자 한글 표기
+    ///
+    /// ## Examples
+    /// ```
+    /// use suwon_mate_admin_tool::timetable::Weekday;
+    /// assert_eq!(Weekday::Wednesday.korean(), "수");
+    /// ```
+    pub fn korean(&self) -> &'static str {
+        match self {
+            Weekday::Monday => "월",
+            Weekday::Tuesday => "화",
+            Weekday::Wednesday => "수",
+            Weekday::Thursday => "목",
+            Weekday::Friday => "금",
+            Weekday::Saturday => "토",
+            Weekday::Sunday => "일",
+        }
+    }
+
+    /// 한 글자 한글 표기로부터 요일을 찾는 메서드
+    pub fn from_korean(text: &str) -> Option<Weekday> {
+        Weekday::ALL.into_iter().find(|day| day.korean() == text)
+    }
+}
+
+impl Display for Weekday {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.korean())
+    }
+}
+
+/// 특정 요일의 수업 시간
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ClassTime {
+    /// 강의실 (표기되지 않은 경우 `None`)
+    pub room: Option<String>,
+    /// 수업 요일
+    pub day: Weekday,
+    /// 수업 교시 목록
+    pub periods: Vec<u32>,
+    /// 교시 대신 `야간`과 같은 이름으로 표기된 수업 시간
+    pub named_slot: Option<String>,
+}
+
+/// 시간표 예외 표기 사전 파일의 구조
+#[derive(Deserialize, Default)]
+struct DictionaryFile {
+    /// 요일 표기 → 한 글자 한글 요일
+    #[serde(default)]
+    days: HashMap<String, String>,
+    /// 시간 표기 → 이름이 있는 수업 시간
+    #[serde(default)]
+    slots: HashMap<String, String>,
+}
+
+/// 시간표 문자열의 요일 및 시간 표기 사전
+///
+/// 기본 사전에는 한글 요일(`월`, `월요일`), 영문 요일(`Mon`, `Monday`)과 `야간`(`night`), `주간` 표기가 포함되어 있으며,
+/// [TimetableDictionary::extend_from_json]을 이용해 표기를 추가할 수 있다. 영문 표기는 대소문자를 구분하지 않는다.
+pub struct TimetableDictionary {
+    days: Vec<(String, Weekday)>,
+    slots: Vec<(String, String)>,
+}
+
+impl Default for TimetableDictionary {
+    fn default() -> Self {
+        let english = [
+            ("mon", "monday"),
+            ("tue", "tuesday"),
+            ("wed", "wednesday"),
+            ("thu", "thursday"),
+            ("fri", "friday"),
+            ("sat", "saturday"),
+            ("sun", "sunday"),
+        ];
+        let mut dictionary = Self {
+            days: vec![],
+            slots: vec![],
+        };
+        for (day, (short, long)) in Weekday::ALL.into_iter().zip(english) {
+            dictionary.add_day(day.korean(), day);
+            dictionary.add_day(&format!("{}요일", day.korean()), day);
+            dictionary.add_day(short, day);
+            dictionary.add_day(long, day);
+        }
+        for (alias, slot) in [("야간", "야간"), ("night", "야간"), ("주간", "주간")] {
+            dictionary.add_slot(alias, slot);
+        }
+        dictionary
+    }
+}
+
+impl TimetableDictionary {
+    /// 요일 표기를 추가하는 메서드
+    pub fn add_day(&mut self, alias: &str, day: Weekday) {
+        self.days.push((alias.to_lowercase(), day));
+        self.days
+            .sort_by_key(|(alias, _)| std::cmp::Reverse(alias.len()));
+    }
+
+    /// 이름이 있는 수업 시간 표기를 추가하는 메서드
+    pub fn add_slot(&mut self, alias: &str, slot: &str) {
+        self.slots.push((alias.to_lowercase(), slot.to_string()));
+        self.slots
+            .sort_by_key(|(alias, _)| std::cmp::Reverse(alias.len()));
+    }
+
+    /// JSON 형태의 사전 파일 내용으로 표기를 추가하는 메서드
+    ///
+    /// 사전 파일은 `{"days": {"표기": "월"}, "slots": {"표기": "야간"}}` 형태이다.
+    ///
+    /// ## Examples
+    /// ```
+    /// use suwon_mate_admin_tool::timetable::{TimetableDictionary, Weekday};
+    /// let mut dictionary = TimetableDictionary::default();
+    /// dictionary.extend_from_json(r#"{"days": {"月": "월"}, "slots": {"evening": "야간"}}"#).unwrap();
+    /// let times = dictionary.parse("月 evening").unwrap();
+    /// assert_eq!(times[0].day, Weekday::Monday);
+    /// assert_eq!(times[0].named_slot.as_deref(), Some("야간"));
+    /// ```
+    ///
+    /// ## Errors
+    /// * JSON해독이 불가능한 경우
+    /// * 요일이 한 글자 한글 요일이 아닌 경우
+    pub fn extend_from_json(&mut self, content: &str) -> Result<(), Box<dyn Error>> {
+        let file: DictionaryFile = serde_json::from_str(content)?;
+        for (alias, day) in file.days {
+            let day = Weekday::from_korean(&day)
+                .ok_or_else(|| format!("알 수 없는 요일입니다: {}", day))?;
+            self.add_day(&alias, day);
+        }
+        for (alias, slot) in file.slots {
+            self.add_slot(&alias, &slot);
+        }
+        Ok(())
+    }
+
+    /// `text`의 앞부분과 일치하는 가장 긴 표기를 찾는 메서드
+    fn match_prefix<'a, T>(entries: &'a [(String, T)], text: &str) -> Option<(usize, &'a T)> {
+        let lowered = text.to_lowercase();
+        entries
+            .iter()
+            .find(|(alias, _)| lowered.starts_with(alias.as_str()))
+            .map(|(alias, value)| {
+                // 소문자 변환 전후의 글자 수가 같으므로 글자 수를 기준으로 원문에서의 길이를 구한다.
+                let length = text
+                    .char_indices()
+                    .nth(alias.chars().count())
+                    .map_or(text.len(), |(index, _)| index);
+                (length, value)
+            })
+    }
+
+    /// 강의실 표기가 없는 요일 및 교시 표기를 해석하는 메서드
+    fn parse_times(&self, text: &str, room: Option<&str>) -> Result<Vec<ClassTime>, String> {
+        let mut times: Vec<ClassTime> = vec![];
+        let mut rest = text;
+        loop {
+            rest = rest.trim_start_matches(|c: char| c.is_whitespace() || c == ',' || c == '/');
+            if rest.is_empty() {
+                break;
+            }
+            if let Some((length, day)) = Self::match_prefix(&self.days, rest) {
+                times.push(ClassTime {
+                    room: room.map(str::to_string),
+                    day: *day,
+                    periods: vec![],
+                    named_slot: None,
+                });
+                rest = &rest[length..];
+            } else if rest.starts_with(|c: char| c.is_ascii_digit()) {
+                let current = times
+                    .last_mut()
+                    .ok_or_else(|| format!("요일 없이 교시가 표기되었습니다: {}", text))?;
+                let (start, after) = split_number(rest);
+                let after_range = after.trim_start();
+                if let Some(range) = after_range
+                    .strip_prefix('-')
+                    .or_else(|| after_range.strip_prefix('~'))
+                {
+                    let (end, after) = split_number(range.trim_start());
+                    let end =
+                        end.ok_or_else(|| format!("교시 범위가 올바르지 않습니다: {}", text))?;
+                    let start = start.unwrap_or_default();
+                    if end < start {
+                        return Err(format!("교시 범위가 올바르지 않습니다: {}", text));
+                    }
+                    current.periods.extend(start..=end);
+                    rest = after;
+                } else {
+                    current.periods.extend(start);
+                    rest = after;
+                }
+            } else if let Some((length, slot)) = Self::match_prefix(&self.slots, rest) {
+                let current = times
+                    .last_mut()
+                    .ok_or_else(|| format!("요일 없이 시간이 표기되었습니다: {}", text))?;
+                current.named_slot = Some(slot.clone());
+                rest = &rest[length..];
+            } else {
+                return Err(format!("해석할 수 없는 표기입니다: {}", rest));
+            }
+        }
+        if let Some(time) = times
+            .iter()
+            .find(|time| time.periods.is_empty() && time.named_slot.is_none())
+        {
+            return Err(format!(
+                "{}요일의 교시가 누락되었습니다: {}",
+                time.day, text
+            ));
+        }
+        Ok(times)
+    }
+
+    /// 시간표 문자열을 해석하는 메서드
+    ///
+    /// `강의실(요일교시)` 형태의 표기가 여러 개 이어진 경우와 강의실 없이 요일과 교시만 표기된 경우를 모두 해석한다.
+    /// 빈 문자열은 수업 시간이 없는 것으로 해석한다.
+    ///
+    /// ## Arguments
+    ///
+    /// * `text` - 시간표 문자열
+    ///
+    /// ## Examples
+    /// ```
+    /// use suwon_mate_admin_tool::timetable::{TimetableDictionary, Weekday};
+    /// let dictionary = TimetableDictionary::default();
+    /// let times = dictionary.parse("글경602(수6,7) 글경603(금1)").unwrap();
+    /// assert_eq!(times[0].room.as_deref(), Some("글경602"));
+    /// assert_eq!(times[0].periods, vec![6, 7]);
+    /// assert_eq!(times[1].day, Weekday::Friday);
+    /// assert_eq!(dictionary.parse("Mon 1-2").unwrap()[0].periods, vec![1, 2]);
+    /// assert_eq!(dictionary.parse("토 야간").unwrap()[0].named_slot.as_deref(), Some("야간"));
+    /// assert!(dictionary.parse("미정").is_err());
+    /// ```
+    ///
+    /// ## Errors
+    /// 사전에 등록되지 않은 표기가 포함되어 있거나 교시가 누락된 경우 오류 메세지를 반환한다.
+    pub fn parse(&self, text: &str) -> Result<Vec<ClassTime>, String> {
+        if !text.contains('(') {
+            return self.parse_times(text, None);
+        }
+        let mut times = vec![];
+        let mut rest = text;
+        loop {
+            rest = rest.trim_start_matches(|c: char| c.is_whitespace() || c == ',' || c == '/');
+            if rest.is_empty() {
+                break;
+            }
+            let (room, after) = rest
+                .split_once('(')
+                .ok_or_else(|| format!("강의실 표기가 올바르지 않습니다: {}", text))?;
+            let (spec, after) = after
+                .split_once(')')
+                .ok_or_else(|| format!("괄호가 닫히지 않았습니다: {}", text))?;
+            let room = room.trim();
+            times.extend(self.parse_times(spec, (!room.is_empty()).then_some(room))?);
+            rest = after;
+        }
+        Ok(times)
+    }
+}
+
+/// 문자열 앞부분의 숫자와 나머지 문자열을 나누는 메서드
+fn split_number(text: &str) -> (Option<u32>, &str) {
+    let end = text
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(text.len());
+    (text[..end].parse().ok(), &text[end..])
+}
+
+/// DB에 포함된 모든 과목의 시간표 문자열을 해석하여 실패한 목록을 만드는 메서드
+///
+/// 시간표 문자열이 없는(`null`) 과목은 검사하지 않는다.
+///
+/// ## Arguments
+///
+/// * `db` - 검사할 DB의 내용
+/// * `dictionary` - 시간표 표기 사전
+///
+/// ## Examples
+/// ```
+/// use serde_json::json;
+/// use suwon_mate_admin_tool::timetable::{timetable_failures, TimetableDictionary};
+/// let db = json!({"estbLectDtaiList": {"경영학부": [
+///     {"subjtCd": "1", "diclNo": "001", "timtSmryCn": "글경602(수6)"},
+///     {"subjtCd": "2", "diclNo": "001", "timtSmryCn": "미정"}
+/// ]}});
+/// let failures = timetable_failures(&db, &TimetableDictionary::default());
+/// assert_eq!(failures.len(), 1);
+/// assert_eq!(failures[0]["key"], "2-001");
+/// ```
+pub fn timetable_failures(db: &Value, dictionary: &TimetableDictionary) -> Vec<Value> {
+    subjects(db)
+        .filter_map(|subject| {
+            let text = subject["timtSmryCn"].as_str()?;
+            let error = dictionary.parse(text).err()?;
+            Some(json!({
+                "key": subject_key(subject),
+                "subjtNm": subject["subjtNm"],
+                "timtSmryCn": text,
+                "error": error,
+            }))
+        })
+        .collect()
+}
@@ -0,0 +1,140 @@
+//! # 학기별 추세 통계
+//!
+//! 버전 순서대로 나열된 DB들로부터 과목 수, 교양 과목 비율, 영어 강의 비율의 추세를 계산한다.
+//! 결과는 표 형태의 CSV 또는 차트 라이브러리에 바로 넣을 수 있는 JSON 형태로 저장할 수 있다.
+use std::error::Error;
+
+use clap::ValueEnum;
+use serde_json::{json, Value};
+
+use crate::output::subjects;
+
+/// 추세 통계의 출력 형식
+#[derive(Copy, Clone, PartialEq, Eq, Debug, ValueEnum)]
+pub enum TrendsFormat {
+    /// 버전 하나당 한 행인 CSV 파일
+    Csv,
+    /// `labels`와 항목별 `datasets`로 이루어진 차트용 JSON 파일
+    Json,
+}
+
+/// DB 하나의 통계
+#[derive(Clone, PartialEq, Debug)]
+pub struct TrendPoint {
+    /// DB 버전
+    pub db_ver: String,
+    /// 과목 수
+    pub subject_count: usize,
+    /// 교양 과목 비율 (0~1)
+    pub culture_ratio: f64,
+    /// 영어 강의 비율 (0~1)
+    pub english_ratio: f64,
+}
+
+/// 교양 과목인지 확인하는 메서드
+///
+/// 이수 구분(`facDvnm`)이 `교`로 시작하거나 교양 영역(`cltTerrNm`)이 기록된 과목을 교양 과목으로 본다.
+fn is_culture(subject: &Value) -> bool {
+    subject["facDvnm"]
+        .as_str()
+        .is_some_and(|division| division.starts_with('교'))
+        || subject["cltTerrNm"]
+            .as_str()
+            .is_some_and(|area| !area.trim().is_empty())
+}
+
+/// 영어 강의인지 확인하는 메서드
+fn is_english(subject: &Value) -> bool {
+    subject["lssnLangNm"]
+        .as_str()
+        .is_some_and(|language| language.contains("영어"))
+}
+
+/// DB 하나의 통계를 구하는 메서드
+///
+/// ## Examples
+/// ```
+/// use serde_json::json;
+/// use suwon_mate_admin_tool::trends::trend_point;
+/// let db = json!({
+///     "estbLectDtaiList": {"경영학부": [
+///         {"facDvnm": "교양", "lssnLangNm": "영어"},
+///         {"facDvnm": "전선", "lssnLangNm": "한국어"}
+///     ]},
+///     "version": {"db_ver": "1.0"}
+/// });
+/// let point = trend_point(&db);
+/// assert_eq!(point.subject_count, 2);
+/// assert_eq!(point.culture_ratio, 0.5);
+/// assert_eq!(point.english_ratio, 0.5);
+/// ```
+pub fn trend_point(db: &Value) -> TrendPoint {
+    let (mut subject_count, mut culture_count, mut english_count) = (0, 0, 0);
+    for subject in subjects(db) {
+        subject_count += 1;
+        culture_count += usize::from(is_culture(subject));
+        english_count += usize::from(is_english(subject));
+    }
+    let ratio = |count: usize| {
+        if subject_count == 0 {
+            0.0
+        } else {
+            count as f64 / subject_count as f64
+        }
+    };
+    TrendPoint {
+        db_ver: db["version"]["db_ver"]
+            .as_str()
+            .unwrap_or_default()
+            .to_string(),
+        subject_count,
+        culture_ratio: ratio(culture_count),
+        english_ratio: ratio(english_count),
+    }
+}
+
+/// 추세 통계를 CSV 형태로 만드는 메서드
+///
+/// ## Examples
+/// ```
+/// use suwon_mate_admin_tool::trends::{trends_csv, TrendPoint};
+/// let points = [TrendPoint { db_ver: String::from("1.0"), subject_count: 2, culture_ratio: 0.5, english_ratio: 0.0 }];
+/// assert_eq!(trends_csv(&points).unwrap(), "db_ver,subject_count,culture_ratio,english_ratio\n1.0,2,0.5000,0.0000\n");
+/// ```
+///
+/// ## Errors
+/// CSV를 쓰는 과정에서 문제가 발생한 경우 오류가 발생한다.
+pub fn trends_csv(points: &[TrendPoint]) -> Result<String, Box<dyn Error>> {
+    let mut writer = csv::Writer::from_writer(vec![]);
+    writer.write_record(["db_ver", "subject_count", "culture_ratio", "english_ratio"])?;
+    for point in points {
+        writer.write_record([
+            point.db_ver.clone(),
+            point.subject_count.to_string(),
+            format!("{:.4}", point.culture_ratio),
+            format!("{:.4}", point.english_ratio),
+        ])?;
+    }
+    Ok(String::from_utf8(writer.into_inner()?)?)
+}
+
+/// 추세 통계를 차트용 JSON 형태로 만드는 메서드
+///
+/// ## Examples
+/// ```
+/// use suwon_mate_admin_tool::trends::{trends_chart, TrendPoint};
+/// let points = [TrendPoint { db_ver: String::from("1.0"), subject_count: 2, culture_ratio: 0.5, english_ratio: 0.0 }];
+/// let chart = trends_chart(&points);
+/// assert_eq!(chart["labels"][0], "1.0");
+/// assert_eq!(chart["datasets"]["subject_count"][0], 2);
+/// ```
+pub fn trends_chart(points: &[TrendPoint]) -> Value {
+    json!({
+        "labels": points.iter().map(|point| &point.db_ver).collect::<Vec<_>>(),
+        "datasets": {
+            "subject_count": points.iter().map(|point| point.subject_count).collect::<Vec<_>>(),
+            "culture_ratio": points.iter().map(|point| point.culture_ratio).collect::<Vec<_>>(),
+            "english_ratio": points.iter().map(|point| point.english_ratio).collect::<Vec<_>>(),
+        }
+    })
+}
@@ -0,0 +1,143 @@
+//! # 보관 DB 일괄 검증
+//!
+//! 보관 중인 여러 버전의 결과 DB 파일을 한 번에 검사하여 손상되었거나 스키마를 위반한 파일을 찾는다.
+//!
+//! 파일마다 다음 항목을 검사한다.
+//!
+//! * 사이드카 파일(`result_x.json.sha256`)이 있으면 파일의 해시가 일치하는지
+//! * JSON 해독이 가능한지
+//! * `version.content_sha256`이 기록되어 있으면 DB 내용의 해시가 일치하는지
+//! * [json_schema]의 스키마를 만족하는지
+use std::error::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+
+use crate::checksum::{content_checksum, sidecar_path};
+use crate::schema::{json_schema, validate_schema};
+use crate::signature::to_hex;
+
+/// 검증할 파일명의 기본 패턴
+pub const DEFAULT_PATTERN: &str = "result_*.json";
+/// 패턴과 일치하더라도 결과 DB와 구조가 달라 검증하지 않는 파일명 접미사 (구 스키마, 열 지향, Firestore 출력)
+pub const EXCLUDED_SUFFIXES: [&str; 3] = ["_legacy.json", ".columnar.json", ".firestore.json"];
+/// 파일 하나에 대해 보고할 스키마 위반 사항의 최대 개수
+pub const MAX_REPORTED_ERRORS: usize = 20;
+
+/// 검증한 파일과 그 파일의 문제 목록
+pub type FileProblems = (PathBuf, Vec<String>);
+
+/// 결과 DB 파일 하나를 검증하여 문제 목록을 만드는 메서드
+///
+/// 문제가 없으면 빈 목록을 반환한다. JSON 해독이 불가능한 경우 이후의 검사는 진행하지 않는다.
+///
+/// ## Arguments
+///
+/// * `path` - 검증할 DB 파일
+/// * `schema` - DB가 만족해야 하는 JSON Schema
+///
+/// ## Examples
+/// ```
+/// use serde_json::json;
+/// use suwon_mate_admin_tool::schema::json_schema;
+/// use suwon_mate_admin_tool::validate::validate_db_file;
+/// let path = std::env::temp_dir().join("suwon_mate_validate_doctest.json");
+/// std::fs::write(&path, "{\"version\": ").unwrap();
+/// assert!(validate_db_file(&path, &json_schema())[0].contains("JSON"));
+///
+/// let db = json!({
+///     "departments": {},
+///     "estbLectDtaiList": {},
+///     "contacts": {},
+///     "version": {"app_ver": "1.0", "db_ver": "1.0", "legacy_app_ver": "1.0", "built_at": "2024-03-01T09:00:00+09:00", "content_sha256": "0"}
+/// });
+/// std::fs::write(&path, db.to_string()).unwrap();
+/// let problems = validate_db_file(&path, &json_schema());
+/// assert_eq!(problems.len(), 1);
+/// assert!(problems[0].contains("content_sha256"));
+/// ```
+pub fn validate_db_file(path: &Path, schema: &Value) -> Vec<String> {
+    let content = match fs::read(path) {
+        Ok(content) => content,
+        Err(error) => return vec![format!("파일을 읽을 수 없습니다: {}", error)],
+    };
+    let mut problems = vec![];
+    if let Ok(sidecar) = fs::read_to_string(sidecar_path(path)) {
+        let expected = sidecar.split_whitespace().next().unwrap_or_default();
+        if !expected.eq_ignore_ascii_case(&to_hex(&Sha256::digest(&content))) {
+            problems.push(String::from(
+                "파일의 해시가 체크섬 파일과 일치하지 않습니다. 파일이 손상되었을 수 있습니다.",
+            ));
+        }
+    }
+    let db: Value = match serde_json::from_slice(&content) {
+        Ok(db) => db,
+        Err(error) => {
+            problems.push(format!(
+                "JSON 해독에 실패하였습니다. 파일이 손상되었을 수 있습니다: {}",
+                error
+            ));
+            return problems;
+        }
+    };
+    if let Some(checksum) = db["version"]["content_sha256"].as_str() {
+        if checksum != content_checksum(&db) {
+            problems.push(String::from(
+                "DB 내용의 해시가 version.content_sha256과 일치하지 않습니다.",
+            ));
+        }
+    }
+    let errors = validate_schema(&db, schema);
+    let hidden = errors.len().saturating_sub(MAX_REPORTED_ERRORS);
+    problems.extend(
+        errors
+            .into_iter()
+            .take(MAX_REPORTED_ERRORS)
+            .map(|error| format!("스키마 위반: {}", error)),
+    );
+    if hidden > 0 {
+        problems.push(format!("스키마 위반 {}개가 더 있습니다.", hidden));
+    }
+    problems
+}
+
+/// `directory`에서 `pattern`과 일치하는 모든 결과 DB 파일을 검증하는 메서드
+///
+/// [EXCLUDED_SUFFIXES]로 끝나는 파일은 제외하며, 파일명 순서대로 파일과 문제 목록의 쌍을 반환한다.
+///
+/// ## Examples
+/// ```
+/// use suwon_mate_admin_tool::validate::bulk_validate;
+/// let directory = std::env::temp_dir().join("suwon_mate_bulk_validate_doctest");
+/// std::fs::create_dir_all(&directory).unwrap();
+/// std::fs::write(directory.join("result_1.0.json"), "{}").unwrap();
+/// std::fs::write(directory.join("result_1.0_legacy.json"), "{}").unwrap();
+/// let results = bulk_validate(&directory, "result_*.json").unwrap();
+/// assert_eq!(results.len(), 1);
+/// assert!(!results[0].1.is_empty());
+/// ```
+///
+/// ## Errors
+/// 패턴이 올바르지 않거나 디렉토리를 읽을 수 없는 경우 오류가 발생한다.
+pub fn bulk_validate(directory: &Path, pattern: &str) -> Result<Vec<FileProblems>, Box<dyn Error>> {
+    let pattern = directory.join(pattern);
+    let mut files = glob::glob(&pattern.to_string_lossy())?.collect::<Result<Vec<_>, _>>()?;
+    files.retain(|file| {
+        let name = file.to_string_lossy();
+        file.is_file()
+            && !EXCLUDED_SUFFIXES
+                .iter()
+                .any(|suffix| name.ends_with(suffix))
+    });
+    files.sort();
+    let schema = json_schema();
+    Ok(files
+        .into_iter()
+        .map(|file| {
+            let problems = validate_db_file(&file, &schema);
+            (file, problems)
+        })
+        .collect())
+}
@@ -0,0 +1,56 @@
+//! # 버전 정보 파일
+//!
+//! 앱이 전체 DB를 내려받기 전에 버전만 먼저 확인할 수 있도록 DB의 `version` 항목과 결과 파일의 체크섬을 담은 작은 JSON 파일을 만든다.
+use std::error::Error;
+use std::fs;
+use std::path::Path;
+
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+
+use crate::signature::to_hex;
+
+/// 버전 정보 파일의 기본 파일명
+pub const VERSION_FILE: &str = "version.json";
+
+/// DB의 버전 정보와 결과 파일의 체크섬으로 버전 정보 파일의 내용을 만드는 메서드
+///
+/// `version` 항목의 값(서명 정보 등 포함)에 결과 파일의 이름(`file`), SHA-256 해시(`sha256`), 크기(`size`)를 더한다.
+/// 해시와 크기는 압축된 경우 압축된 파일을 기준으로 한다.
+///
+/// ## Arguments
+///
+/// * `db` - 결과 DB의 내용
+/// * `result_path` - 결과 파일의 경로
+///
+/// ## Examples
+/// ```
+/// use serde_json::json;
+/// use suwon_mate_admin_tool::version_info::version_info;
+/// let path = std::env::temp_dir().join("suwon_mate_version_info_doctest.json");
+/// std::fs::write(&path, "abc").unwrap();
+/// let db = json!({"version": {"app_ver": "1.0", "db_ver": "2.0", "legacy_app_ver": "0.0"}});
+/// let info = version_info(&db, &path).unwrap();
+/// assert_eq!(info["db_ver"], "2.0");
+/// assert_eq!(info["file"], "suwon_mate_version_info_doctest.json");
+/// assert_eq!(info["sha256"], "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad");
+/// assert_eq!(info["size"], 3);
+/// ```
+///
+/// ## Errors
+/// 결과 파일을 읽을 수 없는 경우 오류가 발생한다.
+pub fn version_info(db: &Value, result_path: &Path) -> Result<Value, Box<dyn Error>> {
+    let content = fs::read(result_path)?;
+    let mut info = db["version"].clone();
+    if !info.is_object() {
+        info = Value::Object(Default::default());
+    }
+    info["file"] = result_path
+        .file_name()
+        .map(|name| name.to_string_lossy())
+        .unwrap_or_default()
+        .into();
+    info["sha256"] = to_hex(&Sha256::digest(&content)).into();
+    info["size"] = content.len().into();
+    Ok(info)
+}